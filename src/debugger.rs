@@ -0,0 +1,197 @@
+use crate::inbuilt::Inbuilt;
+use crate::interpreter::Interpreter;
+use crate::parser::ParsedNode;
+
+#[derive(Default)]
+pub struct Debugger {
+    //The lines of the code being run, so the debugger can show where it stopped
+    pub source: Vec<String>,
+    pub breakpoints: Vec<usize>,
+    //How deep in function calls the debugger still stops; None runs on until a breakpoint
+    pub stepping: Option<usize>,
+    pub depth: usize
+}
+
+const HELP: &str = "Amarrada:
+  t, tallaabo          Fuli sadarka xiga, adigoo gelaya qaybaha la wacay
+  x, xiga              Fuli sadarka xiga, adigoon gelin qaybaha la wacay
+  w, wad               Sii wad ilaa bartaanbaar kale
+  j, joogsi [SADAR]    Bartaanbaar dhig sadarka, ama tus kuwa jira
+  tir SADAR            Ka saar bartaanbaarka sadarka
+  d, doorsoomayaal     Tus doorsoomayaasha iyo qiimahooda
+  s, sadar             Tus sadarka hadda iyo kuwa ku xeeran
+  q, qiimee TIBAAX     Qiimee tibaax oo tus natiijada
+  c, caawimaad         Tus amarradan
+  ba, bax              Jooji fulinta
+";
+
+impl Debugger {
+    pub fn source(&mut self, code: &str) {
+        self.source = code.lines().map(|line| line.to_owned()).collect();
+    }
+
+    pub fn start(&mut self) {
+        self.stepping = Some(usize::MAX);
+    }
+
+    pub fn enter(&mut self) {
+        self.depth += 1;
+    }
+
+    pub fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+pub fn line(interpreter: &mut Interpreter, line_num: usize) {
+    let debugger = &interpreter.debugger;
+    let stepping = debugger.stepping.is_some() && debugger.depth <= debugger.stepping.unwrap();
+    if !stepping && !debugger.breakpoints.contains(&line_num) {
+        return;
+    }
+
+    show(interpreter, line_num, 0);
+    prompt(interpreter, line_num);
+}
+
+fn show(interpreter: &Interpreter, line_num: usize, context: usize) {
+    let lines = &interpreter.debugger.source;
+    let first = if line_num > context { line_num - context } else { 1 };
+
+    for num in first..(line_num + context + 1) {
+        if num > lines.len() {
+            break;
+        }
+
+        let marker = if num == line_num { "->" } else { "  " };
+        Inbuilt::write_out(&format!("{} {:<4} {}\n", marker, num, lines[num - 1]));
+    }
+}
+
+fn prompt(interpreter: &mut Interpreter, line_num: usize) {
+    loop {
+        Inbuilt::write_out("(bartaanbaar) ");
+        let input = Inbuilt::read_line();
+
+        //Running out of input lets the program finish without the debugger
+        if input.is_none() {
+            Inbuilt::write_out("\n");
+            interpreter.debugger.stepping = None;
+            interpreter.debugger.breakpoints.clear();
+            return;
+        }
+
+        let input = input.unwrap();
+        let text = input.trim();
+        let (command, rest) = match text.find(' ') {
+            Some(index) => (&text[..index], text[index..].trim()),
+            None => (text, "")
+        };
+
+        match command {
+            "" => { }
+            "t" | "tallaabo" => {
+                interpreter.debugger.stepping = Some(usize::MAX);
+                return;
+            }
+            "x" | "xiga" => {
+                interpreter.debugger.stepping = Some(interpreter.debugger.depth);
+                return;
+            }
+            "w" | "wad" => {
+                interpreter.debugger.stepping = None;
+                return;
+            }
+            "j" | "joogsi" => {
+                if rest.is_empty() {
+                    let breakpoints = interpreter.debugger.breakpoints.clone();
+                    if breakpoints.is_empty() {
+                        Inbuilt::write_out("Bartaanbaar ma jiro\n");
+                    }
+
+                    for num in breakpoints {
+                        Inbuilt::write_out(&format!("Sadarka {}\n", num));
+                    }
+                } else {
+                    let num = parse_line(rest);
+                    if let Some(num) = num {
+                        if !interpreter.debugger.breakpoints.contains(&num) {
+                            interpreter.debugger.breakpoints.push(num);
+                        }
+
+                        Inbuilt::write_out(&format!("Bartaanbaar waxaa la dhigay sadarka {}\n", num));
+                    }
+                }
+            }
+            "tir" => {
+                let num = parse_line(rest);
+                if let Some(num) = num {
+                    if interpreter.debugger.breakpoints.contains(&num) {
+                        interpreter.debugger.breakpoints.retain(|&point| point != num);
+                        Inbuilt::write_out(&format!("Bartaanbaarka sadarka {} waa la tiray\n", num));
+                    } else {
+                        Inbuilt::write_out(&format!("Sadarka {} bartaanbaar ma laha\n", num));
+                    }
+                }
+            }
+            "d" | "doorsoomayaal" => {
+                for (name, value) in interpreter.scope() {
+                    Inbuilt::write_out(&format!("{} = {}\n", name, Inbuilt::repr(&interpreter.resolve(value))));
+                }
+            }
+            "s" | "sadar" => {
+                show(interpreter, line_num, 2);
+            }
+            "q" | "qiimee" => {
+                if rest.is_empty() {
+                    Inbuilt::write_out("Tibaax baa loo baahan yahay\n");
+                } else {
+                    evaluate(interpreter, rest);
+                }
+            }
+            "c" | "caawimaad" => {
+                Inbuilt::write_out(HELP);
+            }
+            "ba" | "bax" => {
+                interpreter.debugger.stepping = None;
+                interpreter.debugger.breakpoints.clear();
+                //A silent error stops the program the same way an uncaught one would
+                interpreter.error = Some(ParsedNode::Error {
+                    line_num,
+                    line: "".to_owned(),
+                    arrow: "".to_owned(),
+                    error: "".to_owned()
+                });
+                return;
+            }
+            _ => {
+                Inbuilt::write_out(&format!("Amarka '{}' lama yaqaan, isticmaal 'c' si aad u aragto amarrada\n", command));
+            }
+        }
+    }
+}
+
+fn parse_line(text: &str) -> Option<usize> {
+    match text.parse::<usize>() {
+        Ok(num) if num > 0 => {
+            return Some(num);
+        }
+        _ => {
+            Inbuilt::write_out(&format!("'{}' ma aha sadar\n", text));
+        }
+    }
+
+    return None;
+}
+
+fn evaluate(interpreter: &mut Interpreter, expression: &str) {
+    let code = ParsedNode::Str { val: format!("\"{}\"", expression) };
+    let result = Inbuilt::run_code(interpreter, "qiimee", vec![code]);
+    if let Some(result) = result {
+        interpreter.print(result);
+    }
+
+    //A mistake typed into the debugger shouldn't end the program being debugged
+    interpreter.report();
+    interpreter.error = None;
+}
@@ -0,0 +1,191 @@
+//Somali documentation shown by caawimaad, as (name, signature, description)
+pub const BUILTINS: &[(&str, &str, &str)] = &[
+    ("qor", "qor(*shayo, kala=\" \", dhammaad=\"\\n\", kayd=Waxba, faaruqi=Been)", "Wuxuu daabacaa shayada, isagoo u dhexeeya kala, kadibna dhammaad. Haddii kayd la siiyo, halkaas ayuu wax ku qoraa."),
+    ("labaale", "labaale(tiro)", "Wuxuu tirodhan u beddelaa qoraal labaale ah, sida '0b101'."),
+    ("siddeedid", "siddeedid(tiro)", "Wuxuu tirodhan u beddelaa qoraal siddeedid ah, sida '0o17'."),
+    ("lixyatobaneyn", "lixyatobaneyn(tiro)", "Wuxuu tirodhan u beddelaa qoraal lix iyo tobanle ah, sida '0xff'."),
+    ("qaybiyobaaq", "qaybiyobaaq(a, b)", "Wuxuu celiyaa uruur ka kooban qaybinta dhabta ah iyo baaqiga a iyo b."),
+    ("faraq", "faraq(bilow, dhammaad)", "Wuxuu celiyaa tirooyinka bilow ilaa dhammaad, dhammaadka lagama mid aha."),
+    ("tir", "tir(*magacyo)", "Wuxuu tirtiraa doorsoomayaasha la magacaabay."),
+    ("bax", "bax()", "Wuxuu joojiyaa barnaamijka."),
+    ("tirodhan", "tirodhan(shay=0, saldhig=10)", "Wuxuu shay u beddelaa tirodhan. Qoraalka waxaa lagu akhriyaa saldhigga la siiyay; KhaladQiimeyn ayaa dhaca haddii qoraalku khaldan yahay."),
+    ("tobanle", "tobanle(shay=0)", "Wuxuu shay u beddelaa tobanle."),
+    ("qoraal", "qoraal(shay=\"\")", "Wuxuu shay u beddelaa qoraal sidii loo daabici lahaa."),
+    ("bool", "bool(shay=Been)", "Wuxuu celiyaa Run haddii shaygu run yahay, haddii kale Been."),
+    ("muuqaal", "muuqaal(shay)", "Wuxuu celiyaa qoraal muujinaya shayga sida barnaamij ahaan loo qori lahaa."),
+    ("qoraalkadhig", "qoraalkadhig(shay)", "Sida muuqaal, laakiin xarfaha aan ASCII ahayn waa la baxsadaa."),
+    ("qoraalmid", "qoraalmid(tiro)", "Wuxuu celiyaa xarafka lambarkiisu yahay tiro."),
+    ("lambarkadhig", "lambarkadhig(xaraf)", "Wuxuu celiyaa lambarka xarafka."),
+    ("qiimahasugan", "qiimahasugan(tiro)", "Wuxuu celiyaa qiimaha sugan ee tirada."),
+    ("dhufocelcelis", "dhufocelcelis(saldhig, dhufo, qaybiye=Waxba)", "Wuxuu celiyaa saldhig oo la dhufo-celceliyay, haddii qaybiye la siiyana baaqiga."),
+    ("tirobuuxin", "tirobuuxin(tiro, meelo=Waxba)", "Wuxuu tirada u soo koobaa meelaha tobanlaha ah ee la siiyay."),
+    ("ugubadnaan", "ugubadnaan(taxane, fure=Waxba, haddiiwaxbaan=Waxba)", "Wuxuu celiyaa shayga ugu weyn."),
+    ("uguyaraan", "uguyaraan(taxane, fure=Waxba, haddiiwaxbaan=Waxba)", "Wuxuu celiyaa shayga ugu yar."),
+    ("iskudar", "iskudar(taxane, bilow=0)", "Wuxuu isku daraa shayada taxanaha iyo bilow."),
+    ("dherer", "dherer(shay)", "Wuxuu celiyaa tirada shayada ku jira."),
+    ("soocan", "soocan(taxane, fure=Waxba, rogan=Been)", "Wuxuu celiyaa aruur cusub oo soocan."),
+    ("rogan", "rogan(taxane)", "Wuxuu celiyaa celceliye taxanaha gadaal uga bilaabaya."),
+    ("tiri", "tiri(taxane, bilow=0)", "Wuxuu celiyaa celceliye uruurro (tirinta, shayga) ah."),
+    ("iskuxer", "iskuxer(*taxaneyaal)", "Wuxuu isku xiraa taxaneyaal, isagoo celinaya uruurro."),
+    ("kushaqee", "kushaqee(qayb, *taxaneyaal)", "Wuxuu qaybta ku shaqeeyaa shay kasta, kadibna celiyaa natiijooyinka."),
+    ("kasooc", "kasooc(qayb, taxane)", "Wuxuu celiyaa shayada qaybtu Run u celiso. Haddii qaybtu Waxba tahay, shayada run ah ayaa la celiyaa."),
+    ("kulli", "kulli(taxane)", "Wuxuu celiyaa Run haddii shay kasta run yahay."),
+    ("midkasta", "midkasta(taxane)", "Wuxuu celiyaa Run haddii ugu yaraan hal shay run yahay."),
+    ("qiimee", "qiimee(qoraal, caalami=Waxba, deegaan=Waxba)", "Wuxuu qiimeeyaa hal tibaax oo ku qoran qoraal ama koodh, kadibna celiyaa qiimaheeda."),
+    ("samee", "samee(qoraal, caalami=Waxba, deegaan=Waxba)", "Wuxuu fuliyaa koodhka ku qoran qoraal ama koodh."),
+    ("dhis", "dhis(qoraal, magac=\"<qoraal>\", hab=\"samee\")", "Wuxuu qoraal u dhisaa koodh dib loo isticmaali karo. Habku waa 'qiimee' ama 'samee'."),
+    ("dhibco", "dhibco(shay=b'', habxarfeed=Waxba)", "Wuxuu celiyaa dhibco aan la beddeli karin. Qoraal waxaa lagu beddelaa habxarfeedka la siiyay; tirodhan wuxuu sameeyaa eberro."),
+    ("kooxdhibco", "kooxdhibco(shay=b'', habxarfeed=Waxba)", "Sida dhibco, laakiin dhibcaha waa la beddeli karaa, sida k[0] = 65."),
+    ("xasuusaragti", "xasuusaragti(shay)", "Wuxuu celiyaa aragti dhibcaha shayga ah, oo leh dhibcokadhig iyo aruurkadhig."),
+    ("kakan", "kakan(dhab=0, male=0)", "Wuxuu celiyaa tiro kakan oo ah dhab + male*j, ama mid laga akhriyay qoraal sida '1+2j'. Waxay leedahay .dhab, .male iyo .lammaane()."),
+    ("fur", "fur(magac, hab=\"r\")", "Wuxuu furaa kayd, kadibna celiyaa shay leh akhri, akhrisadar, akhrisadarro, qor, raadi iyo xir."),
+    ("weydii", "weydii(su'aal=\"\")", "Wuxuu daabacaa su'aasha, kadibna akhriyaa hal sadar oo la qoray."),
+    ("nooc", "nooc(shay)", "Wuxuu celiyaa nooca shayga."),
+    ("sifosheeg", "sifosheeg(shay=Waxba)", "Wuxuu celiyaa magacyada sifooyinka shayga, ama magacyada la qeexay haddii aan shay la siin."),
+    ("lambarlagugarto", "lambarlagugarto(shay)", "Wuxuu celiyaa lambar lagu garto shayga."),
+    ("lambarugaar", "lambarugaar(shay)", "Wuxuu celiyaa lambarka ugaarka ah ee shayga."),
+    ("mashaquuqabtaa", "mashaquuqabtaa(shay)", "Wuxuu celiyaa Run haddii shayga la wici karo."),
+    ("caalamiyaasha", "caalamiyaasha()", "Wuxuu celiyaa qaamuuska doorsoomayaasha caalamiga ah. Wixii lagu beddelo waxay gaaraan barnaamijka."),
+    ("xeradaan", "xeradaan()", "Wuxuu celiyaa qaamuus ka kooban doorsoomayaasha xeradan. Qayb gudaheeda waa doorsoomayaasha qaybta."),
+    ("doorsoomayaal", "doorsoomayaal(shay=Waxba)", "Wuxuu celiyaa sifooyinka shayga oo qaamuus ah. Haddii aan shay la siin, waa sida xeradaan()."),
+    ("sifokeen", "sifokeen(shay, magac, haddiiwaxbaan)", "Wuxuu celiyaa sifada shayga ee magacan leh."),
+    ("sifobadal", "sifobadal(shay, magac, qiime)", "Wuxuu sifada shayga siiyaa qiime."),
+    ("sifotir", "sifotir(shay, magac)", "Wuxuu tirtiraa sifada shayga."),
+    ("sifomaleeyahay", "sifomaleeyahay(shay, magac)", "Wuxuu celiyaa Run haddii shaygu leeyahay sifadan."),
+    ("caawimaad", "caawimaad(shay=Waxba)", "Wuxuu daabacaa caawimaad ku saabsan shay, erey muhiim ah ama khalad."),
+    ("wareeg", "wareeg(mardambe)", "Wuxuu bilaabaa wareeg hawleed, wuxuu fuliyaa shayga mardambe ilaa uu dhammaado, kadibna celiyaa natiijadiisa. Hawlaha weli socda waa la joojiyaa."),
+    ("hurdo", "hurdo(ilbiriqsi, qiime=Waxba)", "Wuxuu celiyaa shay mardambe ah oo, marka la sugo, hawsha seexiya ilbiriqsiyada la siiyay, kadibna celiya qiime."),
+    ("hawl", "hawl(mardambe)", "Wuxuu shayga mardambe ka dhigaa hawl wareegga ku socota, isagoon sugin."),
+    ("wadajir", "wadajir(*mardambeyaal)", "Wuxuu hal mar bilaabaa dhammaan, marka la sugana wuxuu celiyaa natiijooyinkooda oo aruur ah."),
+    ("kadib", "kadib(ilbiriqsi, qayb, *shayo)", "Wuxuu qaybta u wacaa shayada marka ilbiriqsiyadu dhammaadaan."),
+    ("kamidmid", "kamidmid(shay)", "Wuxuu celiyaa celceliye mardambe ah. Shay leh sifada kawad sidiisa ayuu u celiyaa; taxane kale waxaa laga dhigaa mid."),
+    ("kawad", "kawad(celceliye, haddiiwaxbaan)", "Wuxuu celiyaa shay mardambe ah oo, marka la sugo, celiya shayga xiga. Marka uu dhammaado JoojiKalaNoqnoqodka ayaa dhaca, haddii aan haddiiwaxbaan la siin."),
+    ("bartaanbaar", "bartaanbaar()", "Wuxuu barnaamijka ku joojiyaa sadarka xiga, kadibna furaa bartaanbaaraha. Ku qor 'c' si aad u aragto amarrada.")
+];
+
+pub const KEYWORDS: &[(&str, &str)] = &[
+    ("iyo", "a iyo b waa run marka labaduba run yihiin."),
+    ("ama", "a ama b waa run marka midkood run yahay. 'ama hadduu' waa shuruud kale."),
+    ("aheyn", "'Run aheyn' waa Been, 'Been aheyn' waa Run."),
+    ("gudub", "Wuxuu u gudbaa wareegga xiga ee kastoo."),
+    ("jooji", "Wuxuu joojiyaa kastoo."),
+    ("Run", "Qiimaha bool ee run ah."),
+    ("Been", "Qiimaha bool ee been ah."),
+    ("Waxba", "Qiimaha muujinaya in aanu jirin wax qiime ah."),
+    ("xaqiiji", "xaqiiji shuruud, fariin: wuxuu dhaliyaa KhaladXaqiijin haddii shuruuddu been tahay."),
+    ("qayb", "qayb magac(a, b=1): wuxuu qeexaa qayb. Qoraalka ugu horreeya ee qaybta waa caawimaadkeeda."),
+    ("celi", "celi qiime: wuxuu qiimaha ka celiyaa qaybta."),
+    ("caalami", "caalami magac: qaybta dhexdeeda, magaca wuxuu tilmaamayaa doorsoomaha caalamiga ah."),
+    ("laamda", "laamda a, b: tibaax: wuxuu sameeyaa qayb aan magac lahayn."),
+    ("tir", "tir(magac): wuxuu tirtiraa doorsoome."),
+    ("hadduu", "hadduu shuruud: wuxuu fuliyaa qaybta haddii shuruuddu run tahay."),
+    ("haddii-kale", "haddii-kale: wuxuu fuliyaa qaybta haddii shuruud kasta oo hore been tahay."),
+    ("kastoo", "x kastoo taxane kujira: wuxuu qaybta u fuliyaa shay kasta."),
+    ("kujira", "Wuxuu kastoo u sheegaa taxanaha laga celcelinayo."),
+    ("dhaaf", "Wax ma qabto; waxaa la dhigaa meel qayb looga baahan yahay."),
+    ("isticmaal", "isticmaal shay sida magac: wuxuu shayga xiraa marka qaybtu dhammaato."),
+    ("sida", "Wuxuu isticmaal iyo qabo u sheegaa magaca shayga la siinayo."),
+    ("tijaabi", "tijaabi: wuxuu fuliyaa qaybta, khaladaadka ka dhacana waxaa qabta qabo."),
+    ("qabo", "qabo NoocKhalad sida e: wuxuu fuliyaa qaybta marka khalad noocaas ah ka dhaco tijaabi."),
+    ("ugu-dambeyn", "ugu-dambeyn: wuxuu fuliyaa qaybta marka tijaabi dhammaado, khalad ha dhaco ama yuu dhicin."),
+    ("mardambe", "mardambe qayb magac(): wuxuu qeexaa qayb celisa shay mardambe ah. 'mardambe x kastoo t kujira:' wuxuu sugaa shay kasta oo celceliye mardambe ah."),
+    ("sug", "sug shay: wuxuu sugaa shay mardambe ama hawl, kadibna celiyaa natiijadiisa. Waxaa lagu isticmaalaa qayb mardambe ah gudaheed."),
+    ("markuu", "markuu shay: wuxuu shayga barbar dhigaa qaabab, kadibna fuliyaa kan ugu horreeya ee ku habboon.")
+];
+
+//(name, parent, description)
+pub const EXCEPTIONS: &[(&str, &str, &str)] = &[
+    ("KhaladAasaasi", "", "Aasaaska khaladaadka oo dhan."),
+    ("Khalad", "KhaladAasaasi", "Aasaaska khaladaadka caadiga ah."),
+    ("KhaladXisaabeed", "Khalad", "Khalad ka dhacay xisaab."),
+    ("KhaladXasuusKuMeelGaar", "Khalad", "Khalad ku saabsan xasuus ku meel gaar ah."),
+    ("KhaladRaadin", "Khalad", "Aasaaska khaladaadka raadinta, sida jagaale ama fure aan jirin."),
+    ("KhaladXaqiijin", "Khalad", "Waxaa dhaliya xaqiiji marka shuruuddiisu been tahay."),
+    ("KhaladSifeed", "Khalad", "Shaygu ma laha sifada la weydiiyay."),
+    ("KhaladQoraalDhamaa", "Khalad", "weydii wuxuu gaaray dhammaadka qoraalka."),
+    ("KhaladTobanle", "KhaladXisaabeed", "Khalad ka dhacay xisaab tobanle."),
+    ("ShaqaaleNoqnoqodBax", "KhaladAasaasi", "Waxaa la dhaliyaa marka noqnoqod la xiro."),
+    ("KhaladKeenid", "Khalad", "keen wuu fashilmay."),
+    ("KhaladKaydDibadeedLamaHelin", "KhaladKeenid", "Kaydka dibadeed ee la keenayay lama helin."),
+    ("KhaladJagaale", "KhaladRaadin", "Jagaalaha waa ka baxsan yahay taxanaha."),
+    ("KhaladFuro", "KhaladRaadin", "Furahu kuma jiro qaamuuska."),
+    ("WaaLaJoojiyey", "KhaladAasaasi", "Isticmaaluhu wuxuu joojiyay barnaamijka."),
+    ("KhaladXasuuseed", "Khalad", "Xasuustii waa dhammaatay."),
+    ("KhaladMagceed", "Khalad", "Magaca lama qeexin."),
+    ("KhaladLamaSameyn", "KhaladGoortaShaqada", "Shaqadan weli lama samayn."),
+    ("KhaladWeynaan", "KhaladXisaabeed", "Natiijada xisaabta waa ka weyn tahay inta la kaydin karo."),
+    ("KhaladCelcelis", "KhaladGoortaShaqada", "Qaybaha is-wacaya waa ka badan yihiin xadka."),
+    ("KhaladTixraac", "Khalad", "Tixraac daciif ah ayaa loo isticmaalay shay aan jirin."),
+    ("KhaladGoortaShaqada", "Khalad", "Khalad dhacay marka barnaamijku shaqaynayo."),
+    ("JoojiNoqnoqodka", "Khalad", "Celceliyaha wax kale ma laha."),
+    ("JoojiKalaNoqnoqodka", "Khalad", "Celceliyaha kala-socda wax kale ma laha."),
+    ("KhaladHabQoraal", "Khalad", "Koodhka si khaldan ayaa loo qoray."),
+    ("KhaladBeegmid", "KhaladHabQoraal", "Bannaanka sadarka bilowgiisa waa khaldan yahay, ama qayb gudaha ah ayaa maqan."),
+    ("KhaladBoodid", "KhaladBeegmid", "Boodid iyo bannaan ayaa lagu qasay bilowga sadarka."),
+    ("KhaladHabdhis", "Khalad", "Khalad ka dhacay habdhiska Geel laftiisa."),
+    ("HawlLaJoojiyey", "KhaladAasaasi", "Hawsha waa la joojiyay, sida marka wareeggu dhammaado iyadoo ay weli socoto."),
+    ("HabdhisBax", "KhaladAasaasi", "Waxaa dhaliya bax si barnaamijku u dhammaado."),
+    ("NoocKhaldan", "Khalad", "Shay nooc khaldan ah ayaa la siiyay."),
+    ("KhaladMaJiro", "KhaladMagceed", "Doorsoome gudaha ah ayaa la isticmaalay ka hor inta aan qiime la siin."),
+    ("KhaladHabxarfeed", "KhaladQiimeyn", "Khalad ku saabsan habxarfeedka."),
+    ("KhaladHabxarfeedUBadal", "KhaladHabxarfeed", "Qoraalka looma beddeli karo dhibco."),
+    ("KhaladHabxarfeedKaBadal", "KhaladHabxarfeed", "Dhibcaha looma beddeli karo qoraal."),
+    ("KhaladTurjumidHabxarfeed", "KhaladHabxarfeed", "Qoraalka lama turjumi karo."),
+    ("KhaladQiimeyn", "Khalad", "Shayga noociisu waa sax, laakiin qiimihiisu waa khaldan yahay."),
+    ("KhaladEberUQeybin", "KhaladXisaabeed", "Eber ayaa wax loo qaybiyay."),
+    ("KhaladDibadeed", "Khalad", "Khalad ka yimid habdhiska dibadda."),
+    ("KhaladGB", "Khalad", "Khalad ka dhacay galin-bixin, sida akhrinta ama qorista kayd."),
+    ("KhaladGBHalHal", "KhaladGB", "Galin-bixintu way xannibantay."),
+    ("KhaladHawlQabashoDhaxlan", "KhaladGB", "Khalad ka dhacay hawl-qabasho dhaxlan."),
+    ("KhaladXiriir", "KhaladGB", "Aasaaska khaladaadka xiriirka."),
+    ("KhaladTuubboJaban", "KhaladXiriir", "Tuubbada lagu qorayay waa la xiray."),
+    ("KhaladXiriirLaGoo", "KhaladXiriir", "Xiriirka waa la gooyay."),
+    ("KhaladXiriirLaDiid", "KhaladXiriir", "Xiriirka waa la diiday."),
+    ("KhaladDibUXiriir", "KhaladXiriir", "Xiriirka waa la bilaabay mar kale."),
+    ("KhaladKaydWuuJiraa", "KhaladGB", "Kaydka horay ayuu u jiray."),
+    ("KhaladKaydLamaHelin", "KhaladGB", "Kaydka ama galka lama helin."),
+    ("KhaladLaGoo", "KhaladGB", "Hawsha waa la gooyay."),
+    ("KhaladWaaGal", "KhaladGB", "Kayd ayaa la filayay, laakiin waa gal."),
+    ("KhaladGalMaaha", "KhaladGB", "Gal ayaa la filayay, laakiin ma aha gal."),
+    ("KhaladOgolaansho", "KhaladGB", "Ogolaansho looma hayo hawshan."),
+    ("KhaladHawlQabashoRaadin", "KhaladGB", "Hawl-qabashada lama helin."),
+    ("KhaladWaqtigaaKaDhamaaday", "KhaladGB", "Waqtigii hawsha waa ka dhammaaday."),
+    ("Digniin", "Khalad", "Aasaaska digniinaha."),
+    ("DigniinShaqsi", "Digniin", "Digniin uu sameeyay isticmaaluhu."),
+    ("DigniinWaaDuug", "Digniin", "Shaygan waa duug, waana la tuuri doonaa."),
+    ("DigniinDuugBuuNoqon", "Digniin", "Shaygan wuu duugoobi doonaa."),
+    ("DigniinHabQoraal", "Digniin", "Habqoraal laga shakiyay."),
+    ("DigniinGoortaShaqada", "Digniin", "Dhaqan laga shakiyay marka barnaamijku shaqaynayo."),
+    ("DigniinMustaqbal", "Digniin", "Dhaqankani wuu isbeddeli doonaa mustaqbalka."),
+    ("DigniinKeenid", "Digniin", "Digniin ku saabsan keenidda."),
+    ("DigniinHabxarfeed", "Digniin", "Digniin ku saabsan habxarfeedka."),
+    ("DigniinBadalid", "Digniin", "Digniin ku saabsan beddelidda qoraalka."),
+    ("DigniinDhibco", "Digniin", "Digniin ku saabsan dhibcaha."),
+    ("DigniinHanti", "Digniin", "Digniin ku saabsan hanti aan la xirin.")
+];
+
+pub fn find(name: &str) -> Option<String> {
+    for (builtin, signature, description) in BUILTINS {
+        if builtin == &name {
+            return Some(format!("{}\n    {}", signature, description));
+        }
+    }
+
+    for (keyword, description) in KEYWORDS {
+        if keyword == &name {
+            return Some(format!("{}\n    {}", keyword, description));
+        }
+    }
+
+    for (exception, parent, description) in EXCEPTIONS {
+        if exception == &name {
+            if parent.is_empty() {
+                return Some(format!("{}\n    {}", exception, description));
+            }
+
+            return Some(format!("{}({})\n    {}", exception, parent, description));
+        }
+    }
+
+    return None;
+}
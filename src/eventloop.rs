@@ -0,0 +1,613 @@
+use crate::inbuilt::Inbuilt;
+use crate::interpreter::{Frame, Interpreter, Outcome};
+use crate::parser::{ParsedNode, Task};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::mem;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+//The error a pausing task unwinds with, which stops everything between the pause and the loop
+//the same way an error would, but which no qabo catches and no cleanup runs for
+const PAUSED: &str = "HawlHakatay";
+
+//What a mardambe value does once it's awaited
+#[derive(Debug)]
+#[derive(Clone)]
+pub enum Awaitable {
+    Call {
+        function: ParsedNode,
+        args: Vec<ParsedNode>
+    },
+    Sleep {
+        seconds: f64,
+        value: ParsedNode
+    },
+    Gather {
+        items: Vec<ParsedNode>
+    },
+    Next {
+        iterator: ParsedNode,
+        default: Option<ParsedNode>
+    }
+}
+
+#[derive(Debug)]
+pub struct TaskState {
+    pub awaitable: Awaitable,
+    //A hawl runs on the loop by itself, anything else only runs when it's awaited
+    pub scheduled: bool,
+    pub awaited: bool,
+    pub cancelled: bool,
+    pub result: Option<Result<ParsedNode, ParsedNode>>,
+    //The tasks to pick back up once this one is done
+    pub waiters: Vec<Task>,
+    //Where the task was when it last paused, empty if it hasn't started
+    pub resume: Vec<Resume>
+}
+
+//What a task leaves behind when it pauses, one for each thing it was in the middle of, innermost first.
+//Picking the task back up runs it from the top again, and each of these takes it straight back to
+//where it was without running anything a second time
+#[derive(Debug)]
+pub enum Resume {
+    //The statement of a block the task paused in, with what the statement had already worked out
+    Block {
+        index: usize,
+        journal: Journal,
+        line: usize
+    },
+    Call {
+        frame: Frame,
+        line: usize
+    },
+    //A loop's iterator as it was, and how far its statement had counted once the iterator was made
+    Loop {
+        iterator: ParsedNode,
+        count: usize
+    },
+    //A mardambe kastoo loop, which paused either waiting on its next item or in its body
+    AsyncLoop {
+        iterator: ParsedNode,
+        step: Option<ParsedNode>,
+        count: usize
+    },
+    //The values the managers of an isticmaal gave, for the ones already entered
+    With {
+        bound: Vec<ParsedNode>
+    },
+    Handler {
+        index: usize
+    },
+    Finally {
+        outcome: Outcome
+    },
+    Gather {
+        results: Vec<ParsedNode>
+    },
+    Next {
+        step: ParsedNode
+    },
+    //A coroutine being awaited, which has already been marked as awaited
+    Awaiting,
+    //Where the task actually paused, on a timer or another task
+    Paused
+}
+
+//What a statement of a mardambe qayb has worked out, by the order it asked for each value. A statement
+//the task paused in is run again when it's picked back up, and gets these back rather than working
+//them out twice, so nothing it called before pausing is called again
+#[derive(Debug)]
+#[derive(Default)]
+pub struct Journal {
+    pub count: usize,
+    //What each value came to, along with the count once it was worked out, which is where a
+    //statement getting it back carries on counting from
+    pub values: HashMap<usize, (Option<ParsedNode>, usize)>
+}
+
+pub enum TimerAction {
+    Wake(Task),
+    Call(ParsedNode, Vec<ParsedNode>)
+}
+
+pub struct Timer {
+    pub due: f64,
+    pub order: usize,
+    pub action: TimerAction
+}
+
+//Only one task runs at a time, until it finishes or pauses. A task pauses by unwinding back to the
+//loop, leaving a trail of Resume behind it, and carries on by following the trail back in
+#[derive(Default)]
+pub struct EventLoop {
+    pub running: bool,
+    pub current: Option<Task>,
+    pub ready: VecDeque<Task>,
+    pub timers: Vec<Timer>,
+    pub tasks: Vec<Task>,
+    pub start: Option<Instant>,
+    pub order: usize,
+    //The running task's trail, which it builds while pausing and uses up while picking back up
+    pub resume: Vec<Resume>,
+    pub journals: Vec<Journal>
+}
+
+impl EventLoop {
+    pub fn time(&self) -> f64 {
+        return self.start.map(|start| start.elapsed().as_secs_f64()).unwrap_or(0.0);
+    }
+
+    pub fn add_timer(&mut self, seconds: f64, action: TimerAction) {
+        let due = self.time() + seconds.max(0.0);
+        self.order += 1;
+        self.timers.push(Timer {
+            due,
+            order: self.order,
+            action
+        });
+    }
+}
+
+pub fn coroutine(awaitable: Awaitable) -> ParsedNode {
+    return ParsedNode::Coroutine {
+        task: Task(Rc::new(RefCell::new(TaskState {
+            awaitable,
+            scheduled: false,
+            awaited: false,
+            cancelled: false,
+            result: None,
+            waiters: vec![],
+            resume: vec![]
+        })))
+    };
+}
+
+pub fn schedule(interpreter: &mut Interpreter, task: &Task) {
+    task.0.borrow_mut().scheduled = true;
+    interpreter.events.ready.push_back(task.clone());
+    interpreter.events.tasks.push(task.clone());
+}
+
+//Runs the loop until main is done, then stops whatever else is still going
+pub fn run(interpreter: &mut Interpreter, main: Task) -> Option<ParsedNode> {
+    interpreter.events = EventLoop {
+        running: true,
+        start: Some(Instant::now()),
+        ..EventLoop::default()
+    };
+
+    schedule(interpreter, &main);
+    while main.0.borrow().result.is_none() && interpreter.error.is_none() {
+        let task = interpreter.events.ready.pop_front();
+        if let Some(task) = task {
+            step(interpreter, task);
+            continue;
+        }
+
+        if interpreter.events.timers.is_empty() {
+            interpreter.fail("KhaladGoortaShaqada: hawlaha la sugayo midkoodna ma dhammaan karo".to_owned());
+            break;
+        }
+
+        fire(interpreter);
+    }
+
+    let error = interpreter.error.take();
+    cancel(interpreter);
+    interpreter.events = EventLoop::default();
+    if let Some(error) = error {
+        interpreter.raise(error);
+        return None;
+    }
+
+    let result = main.0.borrow_mut().result.take().unwrap();
+    match result {
+        Ok(value) => {
+            return Some(value);
+        }
+        Err(error) => {
+            interpreter.raise(error);
+        }
+    }
+
+    return None;
+}
+
+//Waits out the earliest timer, then wakes its task or calls its function
+fn fire(interpreter: &mut Interpreter) {
+    let timers = &interpreter.events.timers;
+    let index = (0..timers.len()).min_by(|a, b| {
+        timers[*a].due.partial_cmp(&timers[*b].due).unwrap().then(timers[*a].order.cmp(&timers[*b].order))
+    }).unwrap();
+
+    let timer = interpreter.events.timers.remove(index);
+    let now = interpreter.events.time();
+    if timer.due > now {
+        thread::sleep(Duration::from_secs_f64(timer.due - now));
+    }
+
+    match timer.action {
+        TimerAction::Wake(task) => {
+            interpreter.events.ready.push_back(task);
+        }
+        TimerAction::Call(function, args) => {
+            interpreter.call_value(&function, args);
+        }
+    }
+}
+
+//Runs a task until it pauses or finishes
+fn step(interpreter: &mut Interpreter, task: Task) {
+    if task.0.borrow().result.is_some() {
+        return;
+    }
+
+    let line = interpreter.line;
+    let depth = interpreter.debugger.depth;
+    interpreter.events.current = Some(task.clone());
+    interpreter.events.resume = mem::take(&mut task.0.borrow_mut().resume);
+
+    finish(interpreter, task);
+
+    interpreter.events.current = None;
+    interpreter.events.resume.clear();
+    interpreter.line = line;
+    interpreter.debugger.depth = depth;
+}
+
+//Runs a task to the end and wakes whatever was waiting on it, unless it pauses first
+fn finish(interpreter: &mut Interpreter, task: Task) {
+    let awaitable = task.0.borrow().awaitable.clone();
+    let value = perform(interpreter, awaitable);
+    if suspended(interpreter) {
+        interpreter.error = None;
+        task.0.borrow_mut().resume = mem::take(&mut interpreter.events.resume);
+        return;
+    }
+
+    let error = interpreter.error.take();
+    let mut state = task.0.borrow_mut();
+    state.result = Some(match error {
+        Some(error) => Err(error),
+        None => Ok(value.unwrap_or(ParsedNode::Null))
+    });
+
+    let waiters = mem::take(&mut state.waiters);
+    interpreter.events.ready.extend(waiters);
+}
+
+//Stops the tasks main left running: each one gets HawlLaJoojiyey where it's paused
+fn cancel(interpreter: &mut Interpreter) {
+    let tasks = interpreter.events.tasks.clone();
+    interpreter.events.ready.clear();
+    interpreter.events.timers.clear();
+
+    for task in tasks {
+        if task.0.borrow().result.is_some() {
+            continue;
+        }
+
+        task.0.borrow_mut().cancelled = true;
+        if task.0.borrow().resume.is_empty() {
+            task.0.borrow_mut().result = Some(Err(ParsedNode::Error {
+                line_num: 0,
+                line: "".to_owned(),
+                arrow: "".to_owned(),
+                error: "HawlLaJoojiyey".to_owned()
+            }));
+            continue;
+        }
+
+        step(interpreter, task);
+        interpreter.error = None;
+    }
+}
+
+//Whether the running task is unwinding to pause
+pub fn suspended(interpreter: &Interpreter) -> bool {
+    return matches!(&interpreter.error, Some(ParsedNode::Error { error, .. }) if error == PAUSED);
+}
+
+//Only the body of a mardambe qayb can pause, so that's the only place anything is noted for picking back up
+pub fn asynchronous(interpreter: &Interpreter) -> bool {
+    return interpreter.frames.last().is_some_and(|frame| frame.asynchronous);
+}
+
+//The next step of the trail, if the task is picking back up and it's the kind asked for
+pub fn resumed(interpreter: &mut Interpreter, kind: fn(&Resume) -> bool) -> Option<Resume> {
+    if interpreter.events.resume.last().is_some_and(kind) {
+        return interpreter.events.resume.pop();
+    }
+
+    return None;
+}
+
+//Whether the task is picking back up at this statement of the block being run
+pub fn paused_at(interpreter: &Interpreter, statement: usize) -> bool {
+    return asynchronous(interpreter) && matches!(interpreter.events.resume.last(), Some(Resume::Block { index, .. }) if *index == statement);
+}
+
+//The statement a block picks back up at, and what that statement had worked out
+pub fn resume_block(interpreter: &mut Interpreter) -> (usize, Option<Journal>) {
+    if !asynchronous(interpreter) {
+        return (0, None);
+    }
+
+    if let Some(Resume::Block { index, journal, line }) = resumed(interpreter, |record| matches!(record, Resume::Block { .. })) {
+        interpreter.line = line;
+        return (index, Some(journal));
+    }
+
+    return (0, None);
+}
+
+//Starts noting what a statement works out, true if it's in a mardambe qayb and so could pause
+pub fn begin(interpreter: &mut Interpreter, journal: Option<Journal>) -> bool {
+    if !asynchronous(interpreter) {
+        return false;
+    }
+
+    let mut journal = journal.unwrap_or_default();
+    journal.count = 0;
+    interpreter.events.journals.push(journal);
+    return true;
+}
+
+pub fn end(interpreter: &mut Interpreter, statement: usize, journaled: bool) {
+    if !journaled {
+        return;
+    }
+
+    let journal = interpreter.events.journals.pop().unwrap();
+    if suspended(interpreter) {
+        interpreter.events.resume.push(Resume::Block {
+            index: statement,
+            journal,
+            line: interpreter.line
+        });
+    }
+}
+
+//Ok is a value the statement worked out before it paused, Err the slot to note a new one in, if any
+pub fn recall(interpreter: &mut Interpreter) -> Result<Option<ParsedNode>, Option<usize>> {
+    if !asynchronous(interpreter) {
+        return Err(None);
+    }
+
+    let journal = interpreter.events.journals.last_mut();
+    if journal.is_none() {
+        return Err(None);
+    }
+
+    let journal = journal.unwrap();
+    let slot = journal.count;
+    journal.count += 1;
+    if let Some((value, end)) = journal.values.get(&slot) {
+        journal.count = *end;
+        return Ok(value.clone());
+    }
+
+    return Err(Some(slot));
+}
+
+//How far the statement being run has counted, see Journal
+pub fn counted(interpreter: &Interpreter) -> usize {
+    return interpreter.events.journals.last().map_or(0, |journal| journal.count);
+}
+
+//Skips the values a statement worked out before a part of it that's picked back up another way,
+//so the ones after it still come back in the right places
+pub fn skip_to(interpreter: &mut Interpreter, count: usize) {
+    if let (true, Some(journal)) = (asynchronous(interpreter), interpreter.events.journals.last_mut()) {
+        journal.count = count;
+    }
+}
+
+pub fn note(interpreter: &mut Interpreter, slot: Option<usize>, value: Option<&ParsedNode>) {
+    if slot.is_none() || interpreter.error.is_some() {
+        return;
+    }
+
+    if let Some(journal) = interpreter.events.journals.last_mut() {
+        let end = journal.count;
+        journal.values.insert(slot.unwrap(), (value.cloned(), end));
+    }
+}
+
+//Unwinds the running task back to the loop, unless it has been stopped
+fn pause(interpreter: &mut Interpreter) {
+    let task = interpreter.events.current.clone().unwrap();
+    if task.0.borrow().cancelled {
+        interpreter.fail("HawlLaJoojiyey".to_owned());
+        return;
+    }
+
+    interpreter.events.resume.push(Resume::Paused);
+    interpreter.raise(ParsedNode::Error {
+        line_num: 0,
+        line: "".to_owned(),
+        arrow: "".to_owned(),
+        error: PAUSED.to_owned()
+    });
+}
+
+//Some when the task is picking back up from a pause here: true to carry on, false if it was stopped meanwhile
+fn woken(interpreter: &mut Interpreter) -> Option<bool> {
+    resumed(interpreter, |record| matches!(record, Resume::Paused))?;
+    let task = interpreter.events.current.clone().unwrap();
+    if task.0.borrow().cancelled {
+        interpreter.fail("HawlLaJoojiyey".to_owned());
+        return Some(false);
+    }
+
+    return Some(true);
+}
+
+//sug: only a mardambe qayb can wait, since it's the only thing a task can pause in
+pub fn wait(interpreter: &mut Interpreter, value: ParsedNode) -> Option<ParsedNode> {
+    if interpreter.events.current.is_none() || !asynchronous(interpreter) {
+        interpreter.fail("KhaladHabQoraal: sug waxaa lagu isticmaali karaa qayb mardambe ah gudaheed oo keliya".to_owned());
+        return None;
+    }
+
+    return settle(interpreter, value);
+}
+
+//Runs a coroutine to the end, or waits for a hawl to finish
+fn settle(interpreter: &mut Interpreter, value: ParsedNode) -> Option<ParsedNode> {
+    let task = match &value {
+        ParsedNode::Coroutine { task } => task.clone(),
+        other => {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama sugi karo", Inbuilt::type_name(other)));
+            return None;
+        }
+    };
+
+    if task.0.borrow().scheduled {
+        return join(interpreter, &task);
+    }
+
+    let resumed = resumed(interpreter, |record| matches!(record, Resume::Awaiting)).is_some();
+    if !resumed && task.0.borrow().awaited {
+        interpreter.fail("KhaladGoortaShaqada: shaygan mardambe horay ayaa loo sugay".to_owned());
+        return None;
+    }
+
+    task.0.borrow_mut().awaited = true;
+    let awaitable = task.0.borrow().awaitable.clone();
+    let value = perform(interpreter, awaitable);
+    if suspended(interpreter) {
+        interpreter.events.resume.push(Resume::Awaiting);
+    }
+
+    return value;
+}
+
+//Waits for a hawl, then gives back what it gave or raises what it raised
+fn join(interpreter: &mut Interpreter, task: &Task) -> Option<ParsedNode> {
+    if woken(interpreter) == Some(false) {
+        return None;
+    }
+
+    if task.0.borrow().result.is_none() {
+        let current = interpreter.events.current.clone().unwrap();
+        if &current == task {
+            interpreter.fail("KhaladGoortaShaqada: hawl ma sugi karto nafteeda".to_owned());
+            return None;
+        }
+
+        task.0.borrow_mut().waiters.push(current);
+        pause(interpreter);
+        return None;
+    }
+
+    let result = task.0.borrow().result.clone().unwrap();
+    match result {
+        Ok(value) => {
+            return Some(value);
+        }
+        Err(error) => {
+            interpreter.raise(error);
+        }
+    }
+
+    return None;
+}
+
+fn perform(interpreter: &mut Interpreter, awaitable: Awaitable) -> Option<ParsedNode> {
+    match awaitable {
+        Awaitable::Call { function, args } => {
+            if let ParsedNode::Function { name, params, body, closure, .. } = function {
+                return interpreter.call_function(&name, &params, &body, &closure, args, true);
+            }
+        }
+        Awaitable::Sleep { seconds, value } => {
+            match woken(interpreter) {
+                Some(true) => {
+                    return Some(value);
+                }
+                Some(false) => {
+                    return None;
+                }
+                None => { }
+            }
+
+            let current = interpreter.events.current.clone().unwrap();
+            interpreter.events.add_timer(seconds, TimerAction::Wake(current));
+            pause(interpreter);
+        }
+        Awaitable::Gather { items } => {
+            //The hawls already done when the gather paused are still done, so only the rest are waited on
+            let mut results = match resumed(interpreter, |record| matches!(record, Resume::Gather { .. })) {
+                Some(Resume::Gather { results }) => results,
+                _ => vec![]
+            };
+
+            for item in items.into_iter().skip(results.len()) {
+                if let ParsedNode::Coroutine { task } = item {
+                    let value = join(interpreter, &task);
+                    if value.is_none() {
+                        if suspended(interpreter) {
+                            interpreter.events.resume.push(Resume::Gather { results });
+                        }
+
+                        return None;
+                    }
+
+                    results.push(value.unwrap());
+                }
+            }
+
+            return Some(ParsedNode::List { items: results });
+        }
+        Awaitable::Next { iterator, default } => {
+            let item = next(interpreter, iterator);
+            if default.is_some() && interpreter.error_kind() == "JoojiKalaNoqnoqodka" {
+                interpreter.error = None;
+                return default;
+            }
+
+            return item;
+        }
+    }
+
+    return None;
+}
+
+//kawad: a kamidmid over a plain sequence hands out its items, anything else is asked with its kawad hook
+fn next(interpreter: &mut Interpreter, iterator: ParsedNode) -> Option<ParsedNode> {
+    match iterator {
+        ParsedNode::Iterator { kind, mut sources, .. } if kind == "kamidmid" => {
+            let item = Inbuilt::next_item(interpreter, &mut sources[0]);
+            if item.is_none() && interpreter.error.is_none() {
+                interpreter.fail("JoojiKalaNoqnoqodka".to_owned());
+            }
+
+            return item;
+        }
+        other => {
+            //The hook has already been asked if the task paused waiting on its answer
+            let step = match resumed(interpreter, |record| matches!(record, Resume::Next { .. })) {
+                Some(Resume::Next { step }) => step,
+                _ => {
+                    let hook = Inbuilt::attribute(&other, "kawad").unwrap();
+                    let step = interpreter.call_value(&hook, vec![])?;
+                    if interpreter.error.is_some() {
+                        return None;
+                    }
+
+                    step
+                }
+            };
+
+            let item = settle(interpreter, step.clone());
+            if suspended(interpreter) {
+                interpreter.events.resume.push(Resume::Next { step });
+            }
+
+            return item;
+        }
+    }
+}
@@ -1,13 +1,31 @@
-use crate::parser::ParsedNode;
-use crate::interpreter::VARIABLE_DICT;
+use crate::parser::{ParsedNode, Parser};
+use crate::lexer::{Lexer, Token};
+use crate::docs;
+use crate::interpreter::Interpreter;
+use crate::eventloop::{self, Awaitable, TimerAction};
+use crate::parser::{Attributes, Buffer, FileHandle, Position, Task, Variables};
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::io::{Read, Seek, SeekFrom};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled};
+use std::io::{self, BufRead, Write};
+use std::fs::File;
 use std::process;
+use std::convert::TryFrom;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+//Every builtin takes the interpreter and its arguments and hands back its results
+pub type Method<'a> = &'a dyn Fn(&mut Interpreter, Vec<ParsedNode>) -> Vec<ParsedNode>;
 
 pub struct Inbuilt<'a> {
-    pub methods: Vec<(String, &'a dyn Fn(Vec<ParsedNode>) -> Vec<ParsedNode>)>
+    pub methods: Vec<(String, Method<'a>)>
 }
 
 impl Inbuilt<'_> {
-    pub fn stub(_params: Vec<ParsedNode>) -> Vec<ParsedNode> { return vec![]; }
+    pub fn stub(_interpreter: &mut Interpreter, _params: Vec<ParsedNode>) -> Vec<ParsedNode> { return vec![]; }
 
     pub fn new() -> Self {
         Self {
@@ -39,6 +57,222 @@ impl Inbuilt<'_> {
                 (
                     "tirodhan".to_string(),
                     &Self::tirodhan
+                ),
+                (
+                    "qiimahasugan".to_string(),
+                    &Self::qiimahasugan
+                ),
+                (
+                    "dhufocelcelis".to_string(),
+                    &Self::dhufocelcelis
+                ),
+                (
+                    "tirobuuxin".to_string(),
+                    &Self::tirobuuxin
+                ),
+                (
+                    "ugubadnaan".to_string(),
+                    &Self::ugubadnaan
+                ),
+                (
+                    "uguyaraan".to_string(),
+                    &Self::uguyaraan
+                ),
+                (
+                    "iskudar".to_string(),
+                    &Self::iskudar
+                ),
+                (
+                    "dherer".to_string(),
+                    &Self::dherer
+                ),
+                (
+                    "soocan".to_string(),
+                    &Self::soocan
+                ),
+                (
+                    "rogan".to_string(),
+                    &Self::rogan
+                ),
+                (
+                    "tiri".to_string(),
+                    &Self::tiri
+                ),
+                (
+                    "iskuxer".to_string(),
+                    &Self::iskuxer
+                ),
+                (
+                    "kushaqee".to_string(),
+                    &Self::kushaqee
+                ),
+                (
+                    "kasooc".to_string(),
+                    &Self::kasooc
+                ),
+                (
+                    "kulli".to_string(),
+                    &Self::kulli
+                ),
+                (
+                    "midkasta".to_string(),
+                    &Self::midkasta
+                ),
+                (
+                    "qoraal".to_string(),
+                    &Self::qoraal
+                ),
+                (
+                    "muuqaal".to_string(),
+                    &Self::muuqaal
+                ),
+                (
+                    "qoraalkadhig".to_string(),
+                    &Self::qoraalkadhig
+                ),
+                (
+                    "tobanle".to_string(),
+                    &Self::tobanle
+                ),
+                (
+                    "bool".to_string(),
+                    &Self::bool
+                ),
+                (
+                    "qoraalmid".to_string(),
+                    &Self::qoraalmid
+                ),
+                (
+                    "lambarkadhig".to_string(),
+                    &Self::lambarkadhig
+                ),
+                (
+                    "lixyatobaneyn".to_string(),
+                    &Self::lixyatobaneyn
+                ),
+                (
+                    "siddeedid".to_string(),
+                    &Self::siddeedid
+                ),
+                (
+                    "qiimee".to_string(),
+                    &Self::qiimee
+                ),
+                (
+                    "samee".to_string(),
+                    &Self::samee
+                ),
+                (
+                    "dhis".to_string(),
+                    &Self::dhis
+                ),
+                (
+                    "caawimaad".to_string(),
+                    &Self::caawimaad
+                ),
+                (
+                    "bartaanbaar".to_string(),
+                    &Self::bartaanbaar
+                ),
+                (
+                    "fur".to_string(),
+                    &Self::fur
+                ),
+                (
+                    "weydii".to_string(),
+                    &Self::weydii
+                ),
+                (
+                    "nooc".to_string(),
+                    &Self::nooc
+                ),
+                (
+                    "sifosheeg".to_string(),
+                    &Self::sifosheeg
+                ),
+                (
+                    "dhibco".to_string(),
+                    &Self::dhibco
+                ),
+                (
+                    "kooxdhibco".to_string(),
+                    &Self::kooxdhibco
+                ),
+                (
+                    "xasuusaragti".to_string(),
+                    &Self::xasuusaragti
+                ),
+                (
+                    "kakan".to_string(),
+                    &Self::kakan
+                ),
+                (
+                    "caalamiyaasha".to_string(),
+                    &Self::caalamiyaasha
+                ),
+                (
+                    "xeradaan".to_string(),
+                    &Self::xeradaan
+                ),
+                (
+                    "doorsoomayaal".to_string(),
+                    &Self::doorsoomayaal
+                ),
+                (
+                    "lambarlagugarto".to_string(),
+                    &Self::lambarlagugarto
+                ),
+                (
+                    "lambarugaar".to_string(),
+                    &Self::lambarugaar
+                ),
+                (
+                    "mashaquuqabtaa".to_string(),
+                    &Self::mashaquuqabtaa
+                ),
+                (
+                    "sifokeen".to_string(),
+                    &Self::sifokeen
+                ),
+                (
+                    "sifobadal".to_string(),
+                    &Self::sifobadal
+                ),
+                (
+                    "sifotir".to_string(),
+                    &Self::sifotir
+                ),
+                (
+                    "sifomaleeyahay".to_string(),
+                    &Self::sifomaleeyahay
+                ),
+                (
+                    "wareeg".to_string(),
+                    &Self::wareeg
+                ),
+                (
+                    "hurdo".to_string(),
+                    &Self::hurdo
+                ),
+                (
+                    "hawl".to_string(),
+                    &Self::hawl
+                ),
+                (
+                    "wadajir".to_string(),
+                    &Self::wadajir
+                ),
+                (
+                    "kadib".to_string(),
+                    &Self::kadib
+                ),
+                (
+                    "kamidmid".to_string(),
+                    &Self::kamidmid
+                ),
+                (
+                    "kawad".to_string(),
+                    &Self::kawad
                 )
             ]
         }
@@ -47,8 +281,8 @@ impl Inbuilt<'_> {
     pub fn get_method(
         &mut self,
         name: String
-    ) -> &'_ dyn Fn(Vec<ParsedNode>) -> Vec<ParsedNode> {
-        let mut func: &'_ dyn Fn(Vec<ParsedNode>) -> Vec<ParsedNode> = &Self::stub;
+    ) -> &'_ dyn Fn(&mut Interpreter, Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let mut func: &'_ dyn Fn(&mut Interpreter, Vec<ParsedNode>) -> Vec<ParsedNode> = &Self::stub;
         self.methods.iter().for_each(|method| {
             if method.0 == name {
                 func = method.1;
@@ -58,37 +292,79 @@ impl Inbuilt<'_> {
         return func;
     }
 
-    pub fn qor(params: Vec<ParsedNode>) -> Vec<ParsedNode> { 
-        let mut output: String = "".to_owned();
-        params.iter().for_each(|arg| {
-            match arg {
-                ParsedNode::Variable { name, .. } => {
-                    unsafe {
-                        let var = VARIABLE_DICT.iter().position(|(v, _)| v == name);
-                        if var.is_some() {
-                            let loc = var.unwrap();
-                            let val = &VARIABLE_DICT[loc];
-                            output.push_str(
-                                &Self::parsed_string(val.1.as_ref().clone())
-                            );
-                        }
-                    }
-                },
-                x => { output.push_str(&Self::parsed_string(x.clone())) }
+    pub fn has_method(&mut self, name: &str) -> bool {
+        return self.methods.iter().any(|method| method.0 == name);
+    }
+
+    pub fn qor(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "qor", &kwargs, &["kala", "dhammaad", "kayd", "faaruqi"]) {
+            return vec![];
+        }
+
+        let mut parts: Vec<String> = vec![" ".to_owned(), "\n".to_owned()];
+        for (index, name) in ["kala", "dhammaad"].iter().enumerate() {
+            match Self::kwarg(&kwargs, name) {
+                Some(ParsedNode::Str { val }) => {
+                    parts[index] = Self::unquote(&val);
+                }
+                Some(ParsedNode::Null) | None => { }
+                Some(other) => {
+                    interpreter.fail(format!("NoocKhaldan: {} waa inuu qoraal ama Waxba ahaadaa, ma aha '{}'", name, Self::type_name(&other)));
+                    return vec![];
+                }
             }
-        });
+        }
 
-        let finalised = output.trim();
-        if finalised.len() > 0 && ['"', '\''].contains(&finalised.chars().nth(0).unwrap()) && finalised.chars().nth(finalised.len() - 1) == finalised.chars().nth(0) {
-            let to_print: String = finalised.chars().enumerate().filter(|(x, _)| x != &(0 as usize) && x != &(finalised.len() - 1)).map(|(_, v)| v).collect();
-            print!("{}\r\n", to_print.trim());
-        } else {
-            print!("{}\r\n", output.trim());
+        let mut texts: Vec<String> = vec![];
+        for arg in &args {
+            let text = interpreter.text(arg);
+            if text.is_none() {
+                return vec![];
+            }
+
+            texts.push(text.unwrap());
+        }
+
+        let output: String = texts.join(&parts[0]) + &parts[1];
+        let file = Self::kwarg(&kwargs, "kayd").unwrap_or(ParsedNode::Null);
+        match file {
+            ParsedNode::File { .. } => {
+                Self::file_method(interpreter, &file, "qor", vec![ParsedNode::Str { val: format!("\"{}\"", output) }]);
+            }
+            ParsedNode::Null => {
+                Self::write_out(&output);
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: kayd waa inuu kayd furan ahaadaa, ma aha '{}'", Self::type_name(&other)));
+                return vec![];
+            }
+        }
+
+        let flush = Self::kwarg(&kwargs, "faaruqi");
+        if flush.is_some() && Self::truthy(flush.as_ref().unwrap()) {
+            io::stdout().flush().unwrap();
         }
 
         return vec![];
     }
 
+    pub fn write_out(text: &str) {
+        //Raw mode doesn't return the cursor on a newline, so the REPL needs \r\n
+        let mut output = text.to_owned();
+        if is_raw_mode_enabled().unwrap_or(false) {
+            output = output.replace("\r\n", "\n").replace("\n", "\r\n");
+        }
+
+        //A closed pipe, e.g. from `geel -k x.gl | head`, just ends the program
+        match io::stdout().write_all(output.as_bytes()) {
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {
+                process::exit(1);
+            }
+            _ => { }
+        }
+    }
+
     pub fn parsed_string(
         value: ParsedNode
     ) -> String {
@@ -99,193 +375,3580 @@ impl Inbuilt<'_> {
                 ParsedNode::Int { val } | ParsedNode::Float { val } => {
                     return val.iter().collect::<String>();
                 }
+                ParsedNode::Complex { real, imag } => {
+                    return format!("{}+{}j", real, imag);
+                }
                 _ => { }
         }
 
         return "".to_owned();
     }
 
-    pub fn labaale(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        let arg: ParsedNode = (&params[0]).to_owned();
-        match arg {
-            ParsedNode::Int { val } => {
-                let string: String = val.into_iter().collect::<String>();
-                let int: i32 = string.parse::<i32>().unwrap();
-                return vec![ParsedNode::Str { val: format!("0b{:b}", int) }];
-            },
-            _ => { }
+    pub fn display(value: &ParsedNode) -> String {
+        if let ParsedNode::Str { val } = value {
+            return Self::unquote(val);
         }
 
-        return vec![];
+        return Self::repr(value);
     }
 
-    pub fn qaybiyobaaq(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        if params.len() == 2 {
-            let arg_one = (&params[0]).to_owned();
-            let arg_two = (&params[1]).to_owned();
+    pub fn repr(value: &ParsedNode) -> String {
+        let join = |items: &Vec<ParsedNode>| items.iter().map(Self::repr).collect::<Vec<String>>().join(", ");
+        match value {
+            ParsedNode::Str { val } => {
+                let text = Self::unquote(val);
+                let quote = if text.contains('\'') && !text.contains('"') { '"' } else { '\'' };
+                let escaped: String = text.chars().map(|c| match c {
+                    '\\' => "\\\\".to_owned(),
+                    '\n' => "\\n".to_owned(),
+                    '\r' => "\\r".to_owned(),
+                    '\t' => "\\t".to_owned(),
+                    c if c == quote => format!("\\{}", c),
+                    c if c.is_control() => format!("\\x{:02x}", c as u32),
+                    c => c.to_string()
+                }).collect();
 
-            let mut arg_is_float: bool = false;
-            let arg_one_val: Vec<char>;
-            let arg_two_val: Vec<char>;
+                return format!("{}{}{}", quote, escaped, quote);
+            }
+            ParsedNode::Int { val } => {
+                return val.iter().collect::<String>();
+            }
+            ParsedNode::Float { .. } => {
+                let num = Self::to_number(value).unwrap_or(f64::NAN);
+                if num.is_nan() {
+                    return "nan".to_owned();
+                } else if num.is_infinite() {
+                    return (if num > 0.0 { "inf" } else { "-inf" }).to_owned();
+                }
 
-            match arg_one {
-                ParsedNode::Float { val } => {
-                    arg_is_float = true;
-                    arg_one_val = val;
-                },
-                ParsedNode::Int { val } => {
-                    arg_one_val = val;
-                },
-                _ => { return vec![]; }
+                let text = num.to_string();
+                if text.contains('.') {
+                    return text;
+                }
+
+                return format!("{}.0", text);
+            }
+            ParsedNode::Bool { val } => {
+                return (if *val { "Run" } else { "Been" }).to_owned();
             }
+            ParsedNode::Complex { real, imag } => {
+                let imag_text = format!("{}j", Self::complex_part(*imag));
+                if *real == 0.0 && real.is_sign_positive() {
+                    return imag_text;
+                }
 
-            match arg_two {
-                ParsedNode::Float { val } => {
-                    arg_is_float = true;
-                    arg_two_val = val;
-                },
-                ParsedNode::Int { val } => {
-                    arg_two_val = val;
-                },
-                _ => { return vec![]; }
+                let sign = if imag_text.starts_with('-') { "" } else { "+" };
+                return format!("({}{}{})", Self::complex_part(*real), sign, imag_text);
             }
+            ParsedNode::Null => {
+                return "Waxba".to_owned();
+            }
+            ParsedNode::List { items } => {
+                return format!("[{}]", join(items));
+            }
+            ParsedNode::Tuple { items } => {
+                if items.len() == 1 {
+                    return format!("({},)", join(items));
+                }
 
-            let param_one: String = arg_one_val.into_iter().collect::<String>();
-            let param_two: String = arg_two_val.into_iter().collect::<String>();
+                return format!("({})", join(items));
+            }
+            ParsedNode::Set { items } => {
+                if items.is_empty() {
+                    return "urur()".to_owned();
+                }
 
-            if arg_is_float {
-                let float_one: f64 = param_one.parse::<f64>().unwrap();
-                let float_two: f64 = param_two.parse::<f64>().unwrap();
-                let quotient: f64 = float_one / float_two;
-                let remainder: f64 = float_one % float_two;
+                return format!("{{{}}}", join(items));
+            }
+            ParsedNode::Dict { items } => {
+                let pairs = items.iter().map(|(key, item)| format!("{}: {}", Self::repr(key), Self::repr(item))).collect::<Vec<String>>();
+                return format!("{{{}}}", pairs.join(", "));
+            }
+            ParsedNode::Namespace { variables } => {
+                //The globals usually hold a handle to themselves, which would never finish printing
+                let pairs = variables.0.borrow().iter().map(|(name, item)| match item.as_ref() {
+                    ParsedNode::Namespace { .. } => format!("'{}': {{...}}", name),
+                    item => format!("'{}': {}", name, Self::repr(item))
+                }).collect::<Vec<String>>();
+                return format!("{{{}}}", pairs.join(", "));
+            }
+            ParsedNode::Bytes { val } => {
+                return Self::byte_string(val);
+            }
+            ParsedNode::ByteArray { val } => {
+                return format!("kooxdhibco({})", Self::byte_string(&val.0.borrow()));
+            }
+            ParsedNode::MemoryView { val } => {
+                return format!("<xasuusaragti {}>", Self::byte_string(&val.0.borrow()));
+            }
+            ParsedNode::File { name, mode, .. } => {
+                return format!("<kayd '{}' hab='{}'>", name, mode);
+            }
+            ParsedNode::Code { name, .. } => {
+                return format!("<koodh '{}'>", name);
+            }
+            ParsedNode::Function { name, .. } => {
+                return format!("<qayb {}>", name);
+            }
+            ParsedNode::Type { name } => {
+                return format!("<nooc '{}'>", name);
+            }
+            other => {
+                return format!("<{} shay>", Self::type_name(other));
+            }
+        }
+    }
 
-                return vec![
-                    ParsedNode::Tuple {
-                        items: vec![
-                            ParsedNode::Float { val: quotient.to_string().chars().collect() },
-                            ParsedNode::Float { val: remainder.to_string().chars().collect() }
-                        ]
-                    }
-                ];
-            } else {
-                let int_one: i32 = param_one.parse::<i32>().unwrap();
-                let int_two: i32 = param_two.parse::<i32>().unwrap();
-                let quotient: i32 = int_one / int_two;
-                let remainder: i32 = int_one % int_two;
+    pub fn byte_string(val: &[u8]) -> String {
+        let quote = if val.contains(&b'\'') && !val.contains(&b'"') { b'"' } else { b'\'' };
+        let escaped = val.iter().map(|byte| match byte {
+            b'\n' => "\\n".to_owned(),
+            b'\r' => "\\r".to_owned(),
+            b'\t' => "\\t".to_owned(),
+            b'\\' => "\\\\".to_owned(),
+            byte if *byte == quote => format!("\\{}", quote as char),
+            32..=126 => (*byte as char).to_string(),
+            _ => format!("\\x{:02x}", byte)
+        }).collect::<String>();
 
-                return vec![
-                    ParsedNode::Tuple {
-                        items: vec![
-                            ParsedNode::Int { val: quotient.to_string().chars().collect() },
-                            ParsedNode::Int { val: remainder.to_string().chars().collect() }
-                        ]
+        return format!("b{}{}{}", quote as char, escaped, quote as char);
+    }
+
+    pub fn byte_literal(val: &str) -> Vec<u8> {
+        let chars: Vec<char> = val.chars().collect();
+        let inner: Vec<char> = if chars.len() > 1 { chars[1..chars.len() - 1].to_vec() } else { vec![] };
+        let mut bytes: Vec<u8> = vec![];
+        let mut position = 0;
+
+        while position < inner.len() {
+            let c = inner[position];
+            position += 1;
+            if c != '\\' || position >= inner.len() {
+                let mut buffer = [0u8; 4];
+                bytes.extend(c.encode_utf8(&mut buffer).as_bytes());
+                continue;
+            }
+
+            let escaped = inner[position];
+            position += 1;
+            match escaped {
+                'n' => { bytes.push(b'\n'); }
+                't' => { bytes.push(b'\t'); }
+                'r' => { bytes.push(b'\r'); }
+                '0' => { bytes.push(0); }
+                '\\' | '\'' | '"' => { bytes.push(escaped as u8); }
+                'x' if position + 2 <= inner.len() => {
+                    let hex: String = inner[position..position + 2].iter().collect();
+                    match u8::from_str_radix(&hex, 16) {
+                        Ok(byte) => {
+                            bytes.push(byte);
+                            position += 2;
+                        }
+                        Err(_) => {
+                            bytes.push(b'\\');
+                            bytes.push(b'x');
+                        }
                     }
-                ];
+                }
+                other => {
+                    bytes.push(b'\\');
+                    let mut buffer = [0u8; 4];
+                    bytes.extend(other.encode_utf8(&mut buffer).as_bytes());
+                }
             }
         }
 
-        return vec![];
+        return bytes;
     }
 
-    pub fn faraq(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        if params.len() != 2 {
-            println!("NoocKhaldan: faraq() 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len());
-        } else {
-            let mut one: i32 = 0;
-            let two: i32;
+    //Decodes the escapes in a string literal once, when it is parsed, keeping the quotes around it
+    pub fn string_literal(val: &str) -> String {
+        let chars: Vec<char> = val.chars().collect();
+        if chars.len() < 2 {
+            return val.to_owned();
+        }
 
-            let param_one = (&params[0]).to_owned();
-            let mut one_is_int: bool = false;
-            match param_one {
-                ParsedNode::Int { val } => {
-                    let string_one = val.into_iter().collect::<String>();
-                    one = string_one.parse::<i32>().unwrap();
-                    one_is_int = true;
-                },
-                _ => { }
+        let mut output = String::new();
+        output.push(chars[0]);
+        let mut inner = chars[1..chars.len() - 1].iter();
+        while let Some(&c) = inner.next() {
+            if c != '\\' {
+                output.push(c);
+                continue;
             }
 
-            if one_is_int {
-                let param_two = (&params[1]).to_owned();
-                match param_two {
-                    ParsedNode::Int { val } => {
-                        let string_two = val.into_iter().collect::<String>();
-                        two = string_two.parse::<i32>().unwrap();
-                        let mut range: Vec<ParsedNode> = vec![];
-                        (one..two).for_each(|item| {
-                            range.push(
-                                ParsedNode::Int {
-                                    val: item.to_string().chars().collect()
-                                }
-                            );
-                        });
-
-                        return vec![
-                            ParsedNode::List { items: range }
-                        ];
-                    },
-                    _ => { }
+            match inner.next() {
+                Some('n') => { output.push('\n'); }
+                Some('t') => { output.push('\t'); }
+                Some('r') => { output.push('\r'); }
+                Some('0') => { output.push('\0'); }
+                Some(&other) if ['\\', '\'', '"'].contains(&other) => { output.push(other); }
+                Some(&other) => {
+                    output.push('\\');
+                    output.push(other);
                 }
+                None => { output.push('\\'); }
             }
         }
+        output.push(chars[chars.len() - 1]);
 
-        return vec![];
+        return output;
     }
 
-    pub fn tir(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        for param in params {
-            match param {
-                ParsedNode::Variable { name, .. } => {
-                    unsafe {
-                        let pos = VARIABLE_DICT.iter().position(|(var, _)| var == &name);
-                        if pos.is_some() {
-                            VARIABLE_DICT.remove(pos.unwrap());
-                        }
-                    }
-                }
-                _ => { }
+    pub fn is_value(value: &ParsedNode) -> bool {
+        match value {
+            ParsedNode::Ignore | ParsedNode::Null | ParsedNode::Pass | ParsedNode::Break |
+            ParsedNode::Continue | ParsedNode::Error { .. } | ParsedNode::Line { .. } => {
+                return false;
             }
+            _ => { }
         }
 
-        return vec![];
+        return true;
     }
 
-    pub fn bax(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        process::exit(0);
-        return vec![];
+    pub fn unquote(val: &str) -> String {
+        let chars: Vec<char> = val.chars().collect();
+        if chars.len() > 1 && ['"', '\''].contains(&chars[0]) && chars[chars.len() - 1] == chars[0] {
+            return chars[1..chars.len() - 1].iter().collect::<String>();
+        }
+
+        return val.to_owned();
     }
 
-    pub fn tirodhan(params: Vec<ParsedNode>) -> Vec<ParsedNode> {
-        if params.len() > 0 {
-            match params[0].clone() {
-                ParsedNode::Str { val } => {
-                    let val_vec: Vec<char> = val.chars().collect();
-                    if val.contains(".") {
-                        return vec![
-                            ParsedNode::Float {
-                                val: val_vec
-                            }
-                        ];
-                    } else {
-                        return vec![
-                            ParsedNode::Int {
-                                val: val_vec
+    pub fn type_name(value: &ParsedNode) -> String {
+        let name = match value {
+            ParsedNode::Int { .. } => "tirodhan",
+            ParsedNode::Float { .. } => "tobanle",
+            ParsedNode::Str { .. } => "qoraal",
+            ParsedNode::Bool { .. } => "bool",
+            ParsedNode::List { .. } => "aruur",
+            ParsedNode::Tuple { .. } => "uruur",
+            ParsedNode::Dict { .. } | ParsedNode::Namespace { .. } => "qaamuus",
+            ParsedNode::Set { .. } => "urur",
+            ParsedNode::Function { .. } => "qayb",
+            ParsedNode::File { .. } => "kayd",
+            ParsedNode::Bytes { .. } => "dhibco",
+            ParsedNode::Complex { .. } => "kakan",
+            ParsedNode::ByteArray { .. } => "kooxdhibco",
+            ParsedNode::MemoryView { .. } => "xasuusaragti",
+            ParsedNode::Code { .. } => "koodh",
+            ParsedNode::Type { .. } => "nooc",
+            ParsedNode::Iterator { kind, .. } => kind.as_str(),
+            ParsedNode::Coroutine { task } => if task.0.borrow().scheduled { "hawl" } else { "mardambe" },
+            ParsedNode::Null => "Waxba",
+            _ => "wax"
+        };
+
+        return name.to_owned();
+    }
+
+    pub fn is_type_name(name: &str) -> bool {
+        let types = [
+            "tirodhan", "tobanle", "qoraal", "bool", "aruur",
+            "uruur", "qaamuus", "urur", "qayb", "tiri", "iskuxer",
+            "rogan", "kushaqee", "kasooc", "midmid", "dhibco", "kooxdhibco",
+            "xasuusaragti", "kakan", "mardambe", "hawl", "nooc"
+        ];
+
+        return types.contains(&name);
+    }
+
+    //Whether `qabo parent` catches an error of the given kind
+    pub fn catches(parent: &str, kind: &str) -> bool {
+        let mut kind = kind.to_owned();
+        loop {
+            if kind == parent {
+                return true;
+            }
+
+            if kind == "KhaladAasaasi" {
+                return false;
+            }
+
+            //Anything the docs don't list falls under Khalad
+            let found = docs::EXCEPTIONS.iter().find(|(name, _, _)| name == &kind);
+            kind = found.map(|(_, parent, _)| parent.to_string()).unwrap_or_else(|| "Khalad".to_owned());
+        }
+    }
+
+    pub fn to_number(value: &ParsedNode) -> Option<f64> {
+        match value {
+            ParsedNode::Int { val } | ParsedNode::Float { val } => {
+                return val.iter().collect::<String>().parse::<f64>().ok();
+            }
+            ParsedNode::Bool { val } => {
+                return Some(if *val { 1.0 } else { 0.0 });
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn from_number(num: f64, is_float: bool) -> ParsedNode {
+        if !is_float {
+            return ParsedNode::Int {
+                val: (num as i64).to_string().chars().collect()
+            };
+        }
+
+        let mut string = num.to_string();
+        if num.is_finite() && !string.contains('.') {
+            string += ".0";
+        }
+
+        return ParsedNode::Float {
+            val: string.chars().collect()
+        };
+    }
+
+    //Whole numbers are worked out exactly, since a float loses digits past 2^53
+    pub fn to_integer(value: &ParsedNode) -> Option<i128> {
+        match value {
+            ParsedNode::Int { val } => {
+                return val.iter().collect::<String>().parse::<i128>().ok();
+            }
+            ParsedNode::Bool { val } => {
+                return Some(if *val { 1 } else { 0 });
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn from_integer(num: i128) -> ParsedNode {
+        return ParsedNode::Int {
+            val: num.to_string().chars().collect()
+        };
+    }
+
+    pub fn is_float(value: &ParsedNode) -> bool {
+        return matches!(value, ParsedNode::Float { .. });
+    }
+
+    pub fn split_kwargs(
+        params: Vec<ParsedNode>
+    ) -> (Vec<ParsedNode>, Vec<(String, ParsedNode)>) {
+        let mut args: Vec<ParsedNode> = vec![];
+        let mut kwargs: Vec<(String, ParsedNode)> = vec![];
+        for param in params {
+            match param {
+                ParsedNode::Variable { name, exists: false, value: Some(value), .. } => {
+                    kwargs.push((name, value.as_ref().clone()));
+                }
+                param => {
+                    args.push(param);
+                }
+            }
+        }
+
+        return (args, kwargs);
+    }
+
+    //A keyword the builtin doesn't take is an error rather than something quietly dropped
+    pub fn known_kwargs(
+        interpreter: &mut Interpreter,
+        func: &str,
+        kwargs: &[(String, ParsedNode)],
+        known: &[&str]
+    ) -> bool {
+        let unknown = kwargs.iter().find(|(key, _)| !known.contains(&key.as_str()));
+        if let Some((key, _)) = unknown {
+            interpreter.fail(format!("NoocKhaldan: {}() ma yaqaan shayga '{}'", func, key));
+            return false;
+        }
+
+        return true;
+    }
+
+    pub fn kwarg(
+        kwargs: &[(String, ParsedNode)],
+        name: &str
+    ) -> Option<ParsedNode> {
+        let pos = kwargs.iter().position(|(key, _)| key == name);
+        if let Some(pos) = pos {
+            return Some(kwargs[pos].1.clone());
+        }
+
+        return None;
+    }
+
+    pub fn items(interpreter: &mut Interpreter, value: ParsedNode) -> Option<Vec<ParsedNode>> {
+        if Self::attribute(&value, "celceli").is_some() {
+            let items = Self::celceli(interpreter, &value)?;
+            return Self::items(interpreter, items);
+        }
+
+        match Self::snapshot(&value) {
+            ParsedNode::List { items } | ParsedNode::Tuple { items } | ParsedNode::Set { items } => {
+                return Some(items);
+            }
+            ParsedNode::Str { val } => {
+                return Some(
+                    Self::unquote(&val).chars().map(|c| ParsedNode::Str {
+                        val: format!("\"{}\"", c)
+                    }).collect()
+                );
+            }
+            ParsedNode::Dict { items } => {
+                return Some(items.into_iter().map(|(key, _)| key).collect());
+            }
+            ParsedNode::Bytes { .. } | ParsedNode::ByteArray { .. } | ParsedNode::MemoryView { .. } => {
+                return Some(Self::byte_data(&value).unwrap().into_iter().map(|byte| Self::from_integer(byte as i128)).collect());
+            }
+            ParsedNode::Iterator { .. } | ParsedNode::File { .. } => {
+                let mut iterator = Self::iter(interpreter, value)?;
+                let mut items: Vec<ParsedNode> = vec![];
+                loop {
+                    let item = Self::next_item(interpreter, &mut iterator);
+                    if item.is_none() {
+                        break;
+                    }
+
+                    items.push(item.unwrap());
+                }
+
+                return Some(items);
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn iter(interpreter: &mut Interpreter, value: ParsedNode) -> Option<ParsedNode> {
+        if Self::attribute(&value, "celceli").is_some() {
+            let items = Self::celceli(interpreter, &value)?;
+            return Self::iter(interpreter, items);
+        }
+
+        match value {
+            //Its items have to be awaited, so only mardambe kastoo can go through them
+            ParsedNode::Iterator { ref kind, .. } if kind == "kamidmid" => {
+                return None;
+            }
+            ParsedNode::Iterator { .. } => {
+                return Some(value);
+            }
+            ParsedNode::File { .. } => {
+                //Lines are read from the file as the loop asks for them
+                return Some(ParsedNode::Iterator {
+                    kind: "fur".to_owned(),
+                    sources: vec![value],
+                    position: Position::default(),
+                    start: 0
+                });
+            }
+            value => {
+                let items = Self::items(interpreter, value);
+                if let Some(items) = items {
+                    return Some(ParsedNode::Iterator {
+                        kind: "midmid".to_owned(),
+                        sources: items,
+                        position: Position::default(),
+                        start: 0
+                    });
+                }
+            }
+        }
+
+        return None;
+    }
+
+    //What a value with a celceli hook, set with sifobadal, gives to go through in its place
+    fn celceli(interpreter: &mut Interpreter, value: &ParsedNode) -> Option<ParsedNode> {
+        let hook = Self::attribute(value, "celceli").unwrap();
+        let items = interpreter.call_value(&hook, vec![])?;
+        if interpreter.error.is_some() {
+            return None;
+        }
+
+        //Handing back something with its own celceli would never end
+        if Self::attribute(&items, "celceli").is_some() {
+            interpreter.fail(format!("NoocKhaldan: celceli waa inuu celiyaa wax la celcelin karo, ma aha '{}'", Self::type_name(&items)));
+            return None;
+        }
+
+        return Some(items);
+    }
+
+    pub fn next_item(interpreter: &mut Interpreter, iterator: &mut ParsedNode) -> Option<ParsedNode> {
+        if let ParsedNode::Iterator { kind, sources, position, start } = iterator {
+            let count = position.0.get();
+
+            //Items are only built, and their sources only moved along, once they're asked for
+            let item = match kind.as_str() {
+                "tiri" => {
+                    let item = Self::next_item(interpreter, &mut sources[0])?;
+                    ParsedNode::Tuple {
+                        items: vec![Self::from_integer(*start as i128 + count as i128), item]
+                    }
+                }
+                "iskuxer" => {
+                    if sources.is_empty() {
+                        return None;
+                    }
+
+                    let mut items: Vec<ParsedNode> = vec![];
+                    for source in sources.iter_mut() {
+                        items.push(Self::next_item(interpreter, source)?);
+                    }
+
+                    ParsedNode::Tuple { items }
+                }
+                "fur" => {
+                    let line = Self::readline(interpreter, &sources[0]);
+                    if line.is_none() || Self::is_empty(line.as_ref().unwrap()) {
+                        return None;
+                    }
+
+                    line.unwrap()
+                }
+                "rogan" => {
+                    if count >= sources.len() {
+                        return None;
+                    }
+
+                    sources[sources.len() - count - 1].clone()
+                }
+                _ => sources.get(count)?.clone()
+            };
+
+            position.0.set(count + 1);
+            return Some(item);
+        }
+
+        return None;
+    }
+
+    pub fn dherer(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: dherer() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        //A value with a dherer hook, set with sifobadal, gives its own length
+        if let Some(hook) = Self::attribute(&params[0], "dherer") {
+            let out = interpreter.call_value(&hook, vec![]);
+            match out {
+                Some(ParsedNode::Int { .. }) => {
+                    return vec![out.unwrap()];
+                }
+                Some(other) if interpreter.error.is_none() => {
+                    interpreter.fail(format!("NoocKhaldan: dherer waa inuu celiyaa tirodhan, ma aha '{}'", Self::type_name(&other)));
+                }
+                _ => { }
+            }
+
+            return vec![];
+        }
+
+        let length = match &params[0] {
+            ParsedNode::Str { val } => Self::unquote(val).chars().count(),
+            ParsedNode::List { items } | ParsedNode::Tuple { items } | ParsedNode::Set { items } => items.len(),
+            ParsedNode::Dict { items } => items.len(),
+            ParsedNode::Namespace { variables } => variables.0.borrow().len(),
+            ParsedNode::Bytes { .. } | ParsedNode::ByteArray { .. } | ParsedNode::MemoryView { .. } => Self::byte_data(&params[0]).unwrap().len(),
+            other => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' dherer ma leh", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        return vec![Self::from_number(length as f64, false)];
+    }
+
+    pub fn soocan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "soocan", &kwargs, &["fure", "rogan"]) {
+            return vec![];
+        }
+
+        if args.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: soocan() 1 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let items = Self::items(interpreter, args[0].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&args[0])));
+            return vec![];
+        }
+
+        let key = Self::kwarg(&kwargs, "fure");
+        let reverse = Self::kwarg(&kwargs, "rogan") == Some(ParsedNode::Bool { val: true });
+
+        let mut keyed: Vec<(ParsedNode, ParsedNode)> = vec![];
+        for item in items.unwrap() {
+            let mut compared = item.clone();
+            if let Some(key) = &key {
+                let out = interpreter.call_value(key, vec![item.clone()]);
+                if out.is_none() {
+                    return vec![];
+                }
+
+                compared = out.unwrap();
+            }
+
+            keyed.push((compared, item));
+        }
+
+        let mut failed: Option<(String, String)> = None;
+        //sort_by is stable, and comparing the other way round keeps it stable when reversed
+        keyed.sort_by(|(one, _), (two, _)| {
+            let order = if reverse { Self::compare_values(two, one) } else { Self::compare_values(one, two) };
+            if order.is_none() && failed.is_none() {
+                failed = Some((Self::type_name(one), Self::type_name(two)));
+            }
+
+            return order.unwrap_or(std::cmp::Ordering::Equal);
+        });
+
+        if let Some(failed) = failed {
+            let (one, two) = failed;
+            interpreter.fail(format!("NoocKhaldan: '{}' iyo '{}' lama isbarbardhigi karo", one, two));
+            return vec![];
+        }
+
+        return vec![ParsedNode::List {
+            items: keyed.into_iter().map(|(_, item)| item).collect()
+        }];
+    }
+
+    pub fn rogan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: rogan() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        match &params[0] {
+            ParsedNode::Str { .. } | ParsedNode::List { .. } | ParsedNode::Tuple { .. } | ParsedNode::Dict { .. } | ParsedNode::Namespace { .. } => {
+                return vec![ParsedNode::Iterator {
+                    kind: "rogan".to_owned(),
+                    sources: Self::items(interpreter, params[0].clone()).unwrap(),
+                    position: Position::default(),
+                    start: 0
+                }];
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' lama rogi karo", Self::type_name(other)));
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn tiri(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "tiri", &kwargs, &["bilow"]) {
+            return vec![];
+        }
+
+        if args.len() != 1 && args.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: tiri() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let mut start = Self::kwarg(&kwargs, "bilow");
+        if args.len() == 2 {
+            start = Some(args[1].clone());
+        }
+
+        let mut first: i64 = 0;
+        if let Some(start) = start {
+            match start {
+                ParsedNode::Int { val } => {
+                    match val.iter().collect::<String>().parse::<i64>() {
+                        Ok(num) => {
+                            first = num;
+                        }
+                        Err(_) => {
+                            interpreter.fail("KhaladWeynaan: tiri() bilow aad buu u weyn yahay".to_owned());
+                            return vec![];
+                        }
+                    }
+                }
+                other => {
+                    interpreter.fail(format!("NoocKhaldan: tiri() bilow waa inuu tirodhan noqdaa, ma aha '{}'", Self::type_name(&other)));
+                    return vec![];
+                }
+            }
+        }
+
+        let source = Self::iter(interpreter, args[0].clone());
+        if source.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&args[0])));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Iterator {
+            kind: "tiri".to_owned(),
+            sources: vec![source.unwrap()],
+            position: Position::default(),
+            start: first
+        }];
+    }
+
+    pub fn iskuxer(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let mut sources: Vec<ParsedNode> = vec![];
+        for param in params {
+            let source = Self::iter(interpreter, param.clone());
+            if source.is_none() {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&param)));
+                return vec![];
+            }
+
+            sources.push(source.unwrap());
+        }
+
+        return vec![ParsedNode::Iterator {
+            kind: "iskuxer".to_owned(),
+            sources,
+            position: Position::default(),
+            start: 0
+        }];
+    }
+
+    pub fn truthy(value: &ParsedNode) -> bool {
+        match value {
+            ParsedNode::Bool { val } => {
+                return *val;
+            }
+            ParsedNode::Null => {
+                return false;
+            }
+            ParsedNode::Str { val } => {
+                return !Self::unquote(val).is_empty();
+            }
+            ParsedNode::List { items } | ParsedNode::Tuple { items } | ParsedNode::Set { items } => {
+                return !items.is_empty();
+            }
+            ParsedNode::Dict { items } => {
+                return !items.is_empty();
+            }
+            ParsedNode::Namespace { variables } => {
+                return !variables.0.borrow().is_empty();
+            }
+            ParsedNode::Bytes { .. } | ParsedNode::ByteArray { .. } | ParsedNode::MemoryView { .. } => {
+                return !Self::byte_data(value).unwrap().is_empty();
+            }
+            ParsedNode::Complex { real, imag } => {
+                return *real != 0.0 || *imag != 0.0;
+            }
+            _ => { }
+        }
+
+        let num = Self::to_number(value);
+        if let Some(num) = num {
+            return num != 0.0;
+        }
+
+        return true;
+    }
+
+    pub fn kushaqee(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() < 2 {
+            interpreter.fail(format!("NoocKhaldan: kushaqee() ugu yaraan 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let zipped = Self::iskuxer(interpreter, params[1..].to_vec());
+        if zipped.is_empty() {
+            return vec![];
+        }
+
+        let mut iterator = zipped[0].clone();
+        let mut results: Vec<ParsedNode> = vec![];
+        loop {
+            let item = Self::next_item(interpreter, &mut iterator);
+            if item.is_none() {
+                break;
+            }
+
+            let args = match item.unwrap() {
+                ParsedNode::Tuple { items } => items,
+                other => vec![other]
+            };
+
+            let out = interpreter.call_value(&params[0], args);
+            if out.is_none() {
+                return vec![];
+            }
+
+            results.push(out.unwrap());
+        }
+
+        return vec![ParsedNode::Iterator {
+            kind: "kushaqee".to_owned(),
+            sources: results,
+            position: Position::default(),
+            start: 0
+        }];
+    }
+
+    pub fn kasooc(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: kasooc() 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let items = Self::items(interpreter, params[1].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&params[1])));
+            return vec![];
+        }
+
+        let mut results: Vec<ParsedNode> = vec![];
+        for item in items.unwrap() {
+            //Waxba keeps the items that are already true
+            let mut kept = item.clone();
+            if params[0] != ParsedNode::Null {
+                let out = interpreter.call_value(&params[0], vec![item.clone()]);
+                if out.is_none() {
+                    return vec![];
+                }
+
+                kept = out.unwrap();
+            }
+
+            if interpreter.truthy(&kept) {
+                results.push(item);
+            }
+
+            if interpreter.error.is_some() {
+                return vec![];
+            }
+        }
+
+        return vec![ParsedNode::Iterator {
+            kind: "kasooc".to_owned(),
+            sources: results,
+            position: Position::default(),
+            start: 0
+        }];
+    }
+
+    pub fn kulli(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: kulli() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let items = Self::items(interpreter, params[0].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        //Like iyo and ama, it stops at the first item that settles the answer
+        let mut truth = true;
+        for item in items.unwrap() {
+            if !interpreter.truthy(&item) {
+                truth = false;
+                break;
+            }
+        }
+
+        if interpreter.error.is_some() {
+            return vec![];
+        }
+
+        return vec![ParsedNode::Bool { val: truth }];
+    }
+
+    pub fn midkasta(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: midkasta() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let items = Self::items(interpreter, params[0].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        //Like iyo and ama, it stops at the first item that settles the answer
+        let mut truth = false;
+        for item in items.unwrap() {
+            if interpreter.truthy(&item) {
+                truth = true;
+                break;
+            }
+        }
+
+        if interpreter.error.is_some() {
+            return vec![];
+        }
+
+        return vec![ParsedNode::Bool { val: truth }];
+    }
+
+    pub fn qiimahasugan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: qiimahasugan() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if let ParsedNode::Complex { real, imag } = &params[0] {
+            return vec![Self::from_number(real.hypot(*imag), true)];
+        }
+
+        let integer = Self::to_integer(&params[0]);
+        if let Some(integer) = integer {
+            return vec![Self::from_integer(integer.abs())];
+        }
+
+        let num = Self::to_number(&params[0]);
+        if num.is_none() {
+            interpreter.fail(format!("NoocKhaldan: qiimahasugan() kuma shaqeeyo '{}'", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        return vec![Self::from_number(num.unwrap().abs(), Self::is_float(&params[0]))];
+    }
+
+    pub fn dhufocelcelis(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 && params.len() != 3 {
+            interpreter.fail(format!("NoocKhaldan: dhufocelcelis() 2 ama 3 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let mut nums: Vec<f64> = vec![];
+        for param in &params {
+            let num = Self::to_number(param);
+            if num.is_none() {
+                interpreter.fail(format!("NoocKhaldan: dhufocelcelis() kuma shaqeeyo '{}'", Self::type_name(param)));
+                return vec![];
+            }
+
+            nums.push(num.unwrap());
+        }
+
+        let any_float = params.iter().any(Self::is_float);
+        if params.len() == 3 {
+            if any_float {
+                interpreter.fail("NoocKhaldan: dhufocelcelis() 3 shay markii la siiyo dhammaan waa inay tirodhan noqdaan".to_owned());
+                return vec![];
+            }
+
+            let (base, exp, modulus) = (
+                Self::to_integer(&params[0]).unwrap(),
+                Self::to_integer(&params[1]).unwrap(),
+                Self::to_integer(&params[2]).unwrap()
+            );
+            if modulus == 0 {
+                interpreter.fail("KhaladQiimeyn: dhufocelcelis() shayga 3aad eber ma noqon karo".to_owned());
+                return vec![];
+            }
+
+            if exp < 0 {
+                interpreter.fail("KhaladQiimeyn: dhufocelcelis() shayga 2aad taban ma noqon karo markii shay 3aad la siiyo".to_owned());
+                return vec![];
+            }
+
+            //Square and multiply, keeping everything below the modulus
+            let mut result: Option<i128> = Some(1);
+            let mut base = Some(base.rem_euclid(modulus));
+            let mut exp = exp;
+            while exp > 0 && result.is_some() && base.is_some() {
+                if exp % 2 == 1 {
+                    result = result.unwrap().checked_mul(base.unwrap()).map(|num| num.rem_euclid(modulus));
+                }
+
+                base = base.unwrap().checked_mul(base.unwrap()).map(|num| num.rem_euclid(modulus));
+                exp /= 2;
+            }
+
+            if result.is_none() {
+                interpreter.fail("KhaladWeynaan: dhufocelcelis() natiijadu aad bay u weyn tahay".to_owned());
+                return vec![];
+            }
+
+            //Python gives the result the sign of the modulus
+            let mut result = result.unwrap();
+            if modulus < 0 && result > 0 {
+                result += modulus;
+            }
+
+            return vec![Self::from_integer(result)];
+        }
+
+        if !any_float && nums[1] >= 0.0 {
+            let exp = Self::to_integer(&params[1]).unwrap();
+            let result = if exp > u32::MAX as i128 { None } else { Self::to_integer(&params[0]).unwrap().checked_pow(exp as u32) };
+            if result.is_none() {
+                interpreter.fail("KhaladWeynaan: dhufocelcelis() natiijadu aad bay u weyn tahay".to_owned());
+                return vec![];
+            }
+
+            return vec![Self::from_integer(result.unwrap())];
+        }
+
+        if nums[0] == 0.0 && nums[1] < 0.0 {
+            interpreter.fail("KhaladEberUQeybin: eber lama dhufocelcelin karo tiro taban".to_owned());
+            return vec![];
+        }
+
+        return vec![Self::from_number(nums[0].powf(nums[1]), true)];
+    }
+
+    pub fn tirobuuxin(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 && params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: tirobuuxin() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let num = Self::to_number(&params[0]);
+        if num.is_none() {
+            interpreter.fail(format!("NoocKhaldan: tirobuuxin() kuma shaqeeyo '{}'", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        //Ties go to the even neighbour, as in Python
+        let integer = Self::to_integer(&params[0]);
+        if let (1, Some(integer)) = (params.len(), integer) {
+            return vec![Self::from_integer(integer)];
+        }
+
+        if params.len() == 1 {
+            return vec![Self::from_number(num.unwrap().round_ties_even(), false)];
+        }
+
+        let digits = match &params[1] {
+            //Past a few hundred places the answer stops changing, so bigger counts are cut down
+            ParsedNode::Int { val } => {
+                let beyond = if val.first() == Some(&'-') { i128::MIN } else { i128::MAX };
+                Self::to_integer(&params[1]).unwrap_or(beyond).clamp(-400, 400) as i32
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: tirobuuxin() shayga 2aad waa inuu tirodhan noqdaa, ma aha '{}'", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        //Formatting rounds the exact binary value, which is what Python does
+        let mut rounded = format!("{:.*}", digits.max(0) as usize, num.unwrap()).parse::<f64>().unwrap();
+        if digits < 0 {
+            let scale = 10f64.powi(-digits);
+            rounded = if scale.is_infinite() { 0.0 * num.unwrap().signum() } else { (num.unwrap() / scale).round_ties_even() * scale };
+        }
+
+        return vec![Self::from_number(rounded, Self::is_float(&params[0]))];
+    }
+
+    pub fn equal(one: &ParsedNode, two: &ParsedNode) -> bool {
+        match (one, two) {
+            //A kakan equals a real number when it has nothing on the j side
+            (ParsedNode::Complex { real, imag }, other) | (other, ParsedNode::Complex { real, imag }) => {
+                match other {
+                    ParsedNode::Complex { real: other_real, imag: other_imag } => {
+                        return real == other_real && imag == other_imag;
+                    }
+                    _ => {
+                        let num = Self::to_number(other);
+                        return num.is_some() && *imag == 0.0 && *real == num.unwrap();
+                    }
+                }
+            }
+            (ParsedNode::Str { val: str_one }, ParsedNode::Str { val: str_two }) => {
+                return Self::unquote(str_one) == Self::unquote(str_two);
+            }
+            (ParsedNode::List { items: list_one }, ParsedNode::List { items: list_two }) |
+            (ParsedNode::Tuple { items: list_one }, ParsedNode::Tuple { items: list_two }) => {
+                return list_one.len() == list_two.len() && list_one.iter().zip(list_two.iter()).all(|(item_one, item_two)| Self::equal(item_one, item_two));
+            }
+            _ => { }
+        }
+
+        let (int_one, int_two) = (Self::to_integer(one), Self::to_integer(two));
+        if let (Some(int_one), Some(int_two)) = (int_one, int_two) {
+            return int_one == int_two;
+        }
+
+        let (num_one, num_two) = (Self::to_number(one), Self::to_number(two));
+        if let (Some(num_one), Some(num_two)) = (num_one, num_two) {
+            return num_one == num_two;
+        }
+
+        return one == two;
+    }
+
+    pub fn compare_values(one: &ParsedNode, two: &ParsedNode) -> Option<std::cmp::Ordering> {
+        let (int_one, int_two) = (Self::to_integer(one), Self::to_integer(two));
+        if let (Some(int_one), Some(int_two)) = (int_one, int_two) {
+            return Some(int_one.cmp(&int_two));
+        }
+
+        let num_one = Self::to_number(one);
+        let num_two = Self::to_number(two);
+        if let (Some(num_one), Some(num_two)) = (num_one, num_two) {
+            return num_one.partial_cmp(&num_two);
+        }
+
+        match (one, two) {
+            (ParsedNode::Str { val: str_one }, ParsedNode::Str { val: str_two }) => {
+                return Some(Self::unquote(str_one).cmp(&Self::unquote(str_two)));
+            }
+            (ParsedNode::List { items: list_one }, ParsedNode::List { items: list_two }) |
+            (ParsedNode::Tuple { items: list_one }, ParsedNode::Tuple { items: list_two }) => {
+                for (item_one, item_two) in list_one.iter().zip(list_two.iter()) {
+                    let order = Self::compare_values(item_one, item_two);
+                    if order != Some(std::cmp::Ordering::Equal) {
+                        return order;
+                    }
+                }
+
+                return Some(list_one.len().cmp(&list_two.len()));
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn extreme(
+        interpreter: &mut Interpreter,
+        name: &str,
+        params: Vec<ParsedNode>,
+        wanted: std::cmp::Ordering
+    ) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, name, &kwargs, &["fure", "haddiiwaxbaan"]) {
+            return vec![];
+        }
+
+        let key = Self::kwarg(&kwargs, "fure");
+
+        if args.is_empty() {
+            interpreter.fail(format!("NoocKhaldan: {}() ugu yaraan 1 shay buu qaataa, laakiin 0 shay baa la siiyay", name));
+            return vec![];
+        }
+
+        let mut candidates: Vec<ParsedNode> = args.clone();
+        if args.len() == 1 {
+            let items = Self::items(interpreter, args[0].clone());
+            if items.is_none() {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&args[0])));
+                return vec![];
+            }
+
+            candidates = items.unwrap();
+        }
+
+        if candidates.is_empty() {
+            let default = Self::kwarg(&kwargs, "haddiiwaxbaan");
+            if let Some(default) = default {
+                return vec![default];
+            }
+
+            interpreter.fail(format!("KhaladQiimeyn: {}() waxaa la siiyay taxane madhan", name));
+            return vec![];
+        }
+
+        let mut best: Option<(ParsedNode, ParsedNode)> = None;
+        for candidate in candidates {
+            let mut compared = candidate.clone();
+            if let Some(key) = &key {
+                let keyed = interpreter.call_value(key, vec![candidate.clone()]);
+                if keyed.is_none() {
+                    return vec![];
+                }
+
+                compared = keyed.unwrap();
+            }
+
+            if best.is_none() {
+                best = Some((candidate, compared));
+                continue;
+            }
+
+            let order = Self::compare_values(&compared, &best.as_ref().unwrap().1);
+            if order.is_none() {
+                interpreter.fail(format!(
+                    "NoocKhaldan: '{}' iyo '{}' lama isbarbardhigi karo",
+                    Self::type_name(&compared),
+                    Self::type_name(&best.as_ref().unwrap().1)
+                ));
+                return vec![];
+            }
+
+            //Only a strictly better item replaces the first one found
+            if order.unwrap() == wanted {
+                best = Some((candidate, compared));
+            }
+        }
+
+        return vec![best.unwrap().0];
+    }
+
+    pub fn ugubadnaan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::extreme(interpreter, "ugubadnaan", params, std::cmp::Ordering::Greater);
+    }
+
+    pub fn uguyaraan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::extreme(interpreter, "uguyaraan", params, std::cmp::Ordering::Less);
+    }
+
+    pub fn iskudar(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "iskudar", &kwargs, &["bilow"]) {
+            return vec![];
+        }
+
+        if args.len() != 1 && args.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: iskudar() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let mut start = ParsedNode::Int { val: vec!['0'] };
+        if args.len() == 2 {
+            start = args[1].clone();
+        } else if Self::kwarg(&kwargs, "bilow").is_some() {
+            start = Self::kwarg(&kwargs, "bilow").unwrap();
+        }
+
+        let items = Self::items(interpreter, args[0].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&args[0])));
+            return vec![];
+        }
+
+        if matches!(start, ParsedNode::Str { .. }) {
+            interpreter.fail("NoocKhaldan: iskudar() qoraal isuma geyn karo".to_owned());
+            return vec![];
+        }
+
+        //Whole numbers are added exactly until a float turns up
+        let mut exact = Self::to_integer(&start);
+        let mut is_float = Self::is_float(&start);
+        let mut total = Self::to_number(&start);
+        if total.is_none() {
+            interpreter.fail(format!("NoocKhaldan: iskudar() kuma shaqeeyo '{}'", Self::type_name(&start)));
+            return vec![];
+        }
+
+        for item in items.unwrap() {
+            let num = Self::to_number(&item);
+            if num.is_none() {
+                interpreter.fail(format!("NoocKhaldan: iskudar() kuma shaqeeyo '{}'", Self::type_name(&item)));
+                return vec![];
+            }
+
+            let integer = Self::to_integer(&item);
+            if let (Some(sum), Some(integer)) = (exact, integer) {
+                exact = sum.checked_add(integer);
+                if exact.is_none() {
+                    interpreter.fail("KhaladWeynaan: iskudar() natiijadu aad bay u weyn tahay".to_owned());
+                    return vec![];
+                }
+            } else {
+                exact = None;
+            }
+
+            is_float = is_float || Self::is_float(&item);
+            total = Some(total.unwrap() + num.unwrap());
+        }
+
+        if let Some(exact) = exact {
+            return vec![Self::from_integer(exact)];
+        }
+
+        return vec![Self::from_number(total.unwrap(), is_float)];
+    }
+
+    pub fn labaale(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::radix(interpreter, "labaale", params, 2);
+    }
+
+    pub fn siddeedid(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::radix(interpreter, "siddeedid", params, 8);
+    }
+
+    pub fn lixyatobaneyn(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::radix(interpreter, "lixyatobaneyn", params, 16);
+    }
+
+    pub fn radix(interpreter: &mut Interpreter, name: &str, params: Vec<ParsedNode>, base: u32) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: {}() 1 shay buu qaataa, laakiin {} shay baa la siiyay", name, params.len()));
+            return vec![];
+        }
+
+        let num = match &params[0] {
+            ParsedNode::Int { .. } | ParsedNode::Bool { .. } => Self::to_number(&params[0]).unwrap() as i64,
+            other => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo tirodhan", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        let digits = match base {
+            2 => format!("0b{:b}", num.unsigned_abs()),
+            8 => format!("0o{:o}", num.unsigned_abs()),
+            _ => format!("0x{:x}", num.unsigned_abs())
+        };
+
+        let sign = if num < 0 { "-" } else { "" };
+        return vec![ParsedNode::Str { val: format!("\"{}{}\"", sign, digits) }];
+    }
+
+    pub fn qaybiyobaaq(_interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() == 2 {
+            let arg_one = params[0].to_owned();
+            let arg_two = params[1].to_owned();
+
+            let mut arg_is_float: bool = false;
+            let arg_one_val: Vec<char>;
+            let arg_two_val: Vec<char>;
+
+            match arg_one {
+                ParsedNode::Float { val } => {
+                    arg_is_float = true;
+                    arg_one_val = val;
+                },
+                ParsedNode::Int { val } => {
+                    arg_one_val = val;
+                },
+                _ => { return vec![]; }
+            }
+
+            match arg_two {
+                ParsedNode::Float { val } => {
+                    arg_is_float = true;
+                    arg_two_val = val;
+                },
+                ParsedNode::Int { val } => {
+                    arg_two_val = val;
+                },
+                _ => { return vec![]; }
+            }
+
+            let param_one: String = arg_one_val.into_iter().collect::<String>();
+            let param_two: String = arg_two_val.into_iter().collect::<String>();
+
+            if arg_is_float {
+                let float_one: f64 = param_one.parse::<f64>().unwrap();
+                let float_two: f64 = param_two.parse::<f64>().unwrap();
+                let quotient: f64 = float_one / float_two;
+                let remainder: f64 = float_one % float_two;
+
+                return vec![
+                    ParsedNode::Tuple {
+                        items: vec![
+                            ParsedNode::Float { val: quotient.to_string().chars().collect() },
+                            ParsedNode::Float { val: remainder.to_string().chars().collect() }
+                        ]
+                    }
+                ];
+            } else {
+                let int_one: i32 = param_one.parse::<i32>().unwrap();
+                let int_two: i32 = param_two.parse::<i32>().unwrap();
+                let quotient: i32 = int_one / int_two;
+                let remainder: i32 = int_one % int_two;
+
+                return vec![
+                    ParsedNode::Tuple {
+                        items: vec![
+                            ParsedNode::Int { val: quotient.to_string().chars().collect() },
+                            ParsedNode::Int { val: remainder.to_string().chars().collect() }
+                        ]
+                    }
+                ];
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn faraq(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: faraq() 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+        } else {
+            let mut one: i32 = 0;
+            let two: i32;
+
+            let param_one = params[0].to_owned();
+            let mut one_is_int: bool = false;
+            if let ParsedNode::Int { val } = param_one {
+                let string_one = val.into_iter().collect::<String>();
+                one = string_one.parse::<i32>().unwrap();
+                one_is_int = true;
+            }
+
+            if one_is_int {
+                let param_two = params[1].to_owned();
+                if let ParsedNode::Int { val } = param_two {
+                    let string_two = val.into_iter().collect::<String>();
+                    two = string_two.parse::<i32>().unwrap();
+                    let mut range: Vec<ParsedNode> = vec![];
+                    (one..two).for_each(|item| {
+                        range.push(
+                            ParsedNode::Int {
+                                val: item.to_string().chars().collect()
+                            }
+                        );
+                    });
+
+                    return vec![
+                        ParsedNode::List { items: range }
+                    ];
+                }
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn tir(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        for param in params {
+            if let ParsedNode::Variable { name, .. } = param {
+                interpreter.remove_variable(&name);
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn bax(_interpreter: &mut Interpreter, _params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        process::exit(0);
+    }
+
+    pub fn tirodhan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "tirodhan", &kwargs, &["saldhig"]) {
+            return vec![];
+        }
+
+        if args.len() > 2 {
+            interpreter.fail(format!("NoocKhaldan: tirodhan() ugu badnaan 2 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let mut base = Self::kwarg(&kwargs, "saldhig");
+        if args.len() == 2 {
+            base = Some(args[1].clone());
+        }
+
+        if args.is_empty() {
+            return vec![Self::from_number(0.0, false)];
+        }
+
+        if base.is_none() {
+            match &args[0] {
+                ParsedNode::Int { .. } | ParsedNode::Float { .. } | ParsedNode::Bool { .. } => {
+                    return vec![Self::from_number(Self::to_number(&args[0]).unwrap().trunc(), false)];
+                }
+                _ => { }
+            }
+        }
+
+        let text = match &args[0] {
+            ParsedNode::Str { val } => Self::unquote(val),
+            other => {
+                if base.is_some() {
+                    interpreter.fail("NoocKhaldan: tirodhan() saldhig lama siin karo haddii aanu qoraal ahayn".to_owned());
+                } else {
+                    interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo tirodhan", Self::type_name(other)));
+                }
+
+                return vec![];
+            }
+        };
+
+        let radix = match base {
+            Some(ParsedNode::Int { val }) => val.iter().collect::<String>().parse::<i64>().unwrap_or(-1),
+            Some(other) => {
+                interpreter.fail(format!("NoocKhaldan: saldhigga waa inuu tirodhan ahaadaa, ma aha '{}'", Self::type_name(&other)));
+                return vec![];
+            }
+            None => 10
+        };
+
+        if radix != 0 && !(2..=36).contains(&radix) {
+            interpreter.fail("KhaladQiimeyn: tirodhan() saldhigga waa inuu u dhexeeyaa 2 iyo 36, ama 0".to_owned());
+            return vec![];
+        }
+
+        let num = Self::parse_int(&text, radix as u32);
+        if num.is_none() {
+            interpreter.fail(format!("KhaladQiimeyn: tirodhan() qoraal khaldan oo saldhig {} ah: '{}'", radix, text));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Int { val: num.unwrap().to_string().chars().collect() }];
+    }
+
+    pub fn parse_int(text: &str, base: u32) -> Option<i64> {
+        let mut digits = text.trim().replace("_", "");
+        let mut negative = false;
+        if digits.starts_with('-') || digits.starts_with('+') {
+            negative = digits.starts_with('-');
+            digits = digits[1..].to_owned();
+        }
+
+        //A prefix picks the base when it's 0, and is allowed when it matches it
+        let mut radix = base;
+        let lower = digits.to_lowercase();
+        for (prefix, prefix_base) in [("0b", 2), ("0o", 8), ("0x", 16)] {
+            if lower.starts_with(prefix) && (base == 0 || base == prefix_base) {
+                radix = prefix_base;
+                digits = digits[2..].to_owned();
+            }
+        }
+
+        if radix == 0 {
+            radix = 10;
+        }
+
+        if digits.is_empty() || digits.starts_with('-') || digits.starts_with('+') {
+            return None;
+        }
+
+        let num = i64::from_str_radix(&digits, radix).ok()?;
+        return Some(if negative { -num } else { num });
+    }
+
+    pub fn tobanle(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: tobanle() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.is_empty() {
+            return vec![Self::from_number(0.0, true)];
+        }
+
+        match &params[0] {
+            ParsedNode::Str { val } => {
+                let text = Self::unquote(val).trim().replace("_", "");
+                let num = match text.to_lowercase().trim_start_matches(['+', '-']) {
+                    "inf" | "infinity" | "nan" => text.to_lowercase().parse::<f64>().ok(),
+                    _ if text.chars().all(|c| c.is_ascii_digit() || ['.', '-', '+', 'e', 'E'].contains(&c)) => text.parse::<f64>().ok(),
+                    _ => None
+                };
+
+                if num.is_none() {
+                    interpreter.fail(format!("KhaladQiimeyn: qoraalka '{}' looma beddeli karo tobanle", Self::unquote(val)));
+                    return vec![];
+                }
+
+                return vec![Self::from_number(num.unwrap(), true)];
+            }
+            other => {
+                let num = Self::to_number(other);
+                if num.is_none() {
+                    interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo tobanle", Self::type_name(other)));
+                    return vec![];
+                }
+
+                return vec![Self::from_number(num.unwrap(), true)];
+            }
+        }
+    }
+
+    pub fn bool(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: bool() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.is_empty() {
+            return vec![ParsedNode::Bool { val: false }];
+        }
+
+        let truth = interpreter.truthy(&params[0]);
+        if interpreter.error.is_some() {
+            return vec![];
+        }
+
+        return vec![ParsedNode::Bool { val: truth }];
+    }
+
+    pub fn qoraal(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: qoraal() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.is_empty() {
+            return vec![ParsedNode::Str { val: "\"\"".to_owned() }];
+        }
+
+        let text = interpreter.text(&params[0]);
+        if text.is_none() {
+            return vec![];
+        }
+
+        return vec![ParsedNode::Str { val: format!("\"{}\"", text.unwrap()) }];
+    }
+
+    pub fn muuqaal(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: muuqaal() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Str { val: format!("\"{}\"", Self::repr(&params[0])) }];
+    }
+
+    pub fn qoraalkadhig(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: qoraalkadhig() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let ascii: String = Self::repr(&params[0]).chars().map(|c| match c as u32 {
+            0..=0x7f => c.to_string(),
+            0x80..=0xff => format!("\\x{:02x}", c as u32),
+            0x100..=0xffff => format!("\\u{:04x}", c as u32),
+            code => format!("\\U{:08x}", code)
+        }).collect();
+
+        return vec![ParsedNode::Str { val: format!("\"{}\"", ascii) }];
+    }
+
+    pub fn qoraalmid(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: qoraalmid() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let code = match &params[0] {
+            ParsedNode::Int { .. } | ParsedNode::Bool { .. } => Self::to_number(&params[0]).unwrap() as i64,
+            other => {
+                interpreter.fail(format!("NoocKhaldan: qoraalmid() wuxuu filayay tirodhan, laakiin '{}' baa la siiyay", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        let character = u32::try_from(code).ok().and_then(char::from_u32);
+        if character.is_none() {
+            interpreter.fail(format!("KhaladQiimeyn: qoraalmid() {} kama dhex jiro xarfaha la heli karo", code));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Str { val: format!("\"{}\"", character.unwrap()) }];
+    }
+
+    pub fn lambarkadhig(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: lambarkadhig() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        match &params[0] {
+            ParsedNode::Str { val } => {
+                let chars: Vec<char> = Self::unquote(val).chars().collect();
+                if chars.len() != 1 {
+                    interpreter.fail(format!("NoocKhaldan: lambarkadhig() wuxuu filayay xaraf keliya, laakiin qoraal dherer {} ah baa la siiyay", chars.len()));
+                    return vec![];
+                }
+
+                return vec![Self::from_number(chars[0] as u32 as f64, false)];
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: lambarkadhig() wuxuu filayay qoraal, laakiin '{}' baa la siiyay", Self::type_name(other)));
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn byte_values(interpreter: &mut Interpreter, func: &str, params: Vec<ParsedNode>) -> Option<Vec<u8>> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, func, &kwargs, &["habxarfeed"]) {
+            return None;
+        }
+
+        if args.len() > 2 {
+            interpreter.fail(format!("NoocKhaldan: {}() ugu badnaan 2 shay buu qaataa, laakiin {} shay baa la siiyay", func, args.len()));
+            return None;
+        }
+
+        let mut encoding = Self::kwarg(&kwargs, "habxarfeed");
+        if args.len() == 2 {
+            encoding = Some(args[1].clone());
+        }
+
+        if args.is_empty() {
+            return Some(vec![]);
+        }
+
+        match (&args[0], encoding) {
+            (ParsedNode::Str { val }, Some(ParsedNode::Str { val: name })) => {
+                return Self::encode(interpreter, &Self::unquote(val), &Self::unquote(&name));
+            }
+            (ParsedNode::Str { .. }, _) => {
+                interpreter.fail(format!("NoocKhaldan: {}() qoraal habxarfeed la'aan ma qaato", func));
+                return None;
+            }
+            (_, Some(_)) => {
+                interpreter.fail(format!("NoocKhaldan: {}() habxarfeed qoraal la'aan ma qaato", func));
+                return None;
+            }
+            (ParsedNode::Int { .. }, None) => {
+                let size = Self::to_number(&args[0]).unwrap();
+                if size < 0.0 {
+                    interpreter.fail(format!("KhaladQiimeyn: {}() tiro taban ma qaato", func));
+                    return None;
+                }
+
+                return Some(vec![0; size as usize]);
+            }
+            (ParsedNode::Bytes { .. }, None) | (ParsedNode::ByteArray { .. }, None) | (ParsedNode::MemoryView { .. }, None) => {
+                return Self::byte_data(&args[0]);
+            }
+            _ => { }
+        }
+
+        let items = Self::items(interpreter, args[0].clone());
+        if items.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo {}", Self::type_name(&args[0]), func));
+            return None;
+        }
+
+        let mut bytes: Vec<u8> = vec![];
+        for item in items.unwrap() {
+            match item {
+                ParsedNode::Int { .. } | ParsedNode::Bool { .. } => {
+                    let byte = Self::to_number(&item).unwrap();
+                    if !(0.0..=255.0).contains(&byte) {
+                        interpreter.fail("KhaladQiimeyn: dhibcuhu waa inay u dhexeeyaan 0 iyo 255".to_owned());
+                        return None;
+                    }
+
+                    bytes.push(byte as u8);
+                }
+                other => {
+                    interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo dhibic", Self::type_name(&other)));
+                    return None;
+                }
+            }
+        }
+
+        return Some(bytes);
+    }
+
+    pub fn dhibco(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::byte_values(interpreter, "dhibco", params).map(|val| ParsedNode::Bytes { val }).into_iter().collect();
+    }
+
+    pub fn kooxdhibco(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::byte_values(interpreter, "kooxdhibco", params).map(|val| ParsedNode::ByteArray { val: Buffer::new(val) }).into_iter().collect();
+    }
+
+    //The bytes held by a dhibco, kooxdhibco or xasuusaragti, copied out
+    pub fn byte_data(value: &ParsedNode) -> Option<Vec<u8>> {
+        match value {
+            ParsedNode::Bytes { val } => {
+                return Some(val.clone());
+            }
+            ParsedNode::ByteArray { val } | ParsedNode::MemoryView { val } => {
+                return Some(val.0.borrow().clone());
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn xasuusaragti(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: xasuusaragti() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        //A view of a kooxdhibco shares its bytes, so changes to either show through the other
+        match &params[0] {
+            ParsedNode::ByteArray { val } | ParsedNode::MemoryView { val } => {
+                return vec![ParsedNode::MemoryView { val: val.clone() }];
+            }
+            ParsedNode::Bytes { val } => {
+                return vec![ParsedNode::MemoryView { val: Buffer::new(val.clone()) }];
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: xasuusaragti() wuxuu u baahan yahay dhibco, ma aha '{}'", Self::type_name(other)));
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn encoding_name(interpreter: &mut Interpreter, name: &str) -> Option<&'static str> {
+        match name.to_lowercase().replace("_", "-").as_str() {
+            "utf-8" | "utf8" => {
+                return Some("utf-8");
+            }
+            "ascii" => {
+                return Some("ascii");
+            }
+            "latin-1" | "latin1" | "iso-8859-1" => {
+                return Some("latin-1");
+            }
+            _ => { }
+        }
+
+        interpreter.fail(format!("KhaladRaadin: habxarfeed lama yaqaan: {}", name));
+        return None;
+    }
+
+    pub fn encode(interpreter: &mut Interpreter, text: &str, encoding: &str) -> Option<Vec<u8>> {
+        let name = Self::encoding_name(interpreter, encoding)?;
+        let limit: u32 = match name {
+            "ascii" => 128,
+            "latin-1" => 256,
+            _ => {
+                return Some(text.as_bytes().to_vec());
+            }
+        };
+
+        let mut bytes: Vec<u8> = vec![];
+        for (position, c) in text.chars().enumerate() {
+            if c as u32 >= limit {
+                interpreter.fail(format!(
+                    "KhaladHabxarfeedUBadal: habxarfeedka '{}' ma beddeli karo xarafka '{}' ee booska {}: wuxuu ka baxsan yahay 0 ilaa {}",
+                    name, c, position, limit
+                ));
+                return None;
+            }
+
+            bytes.push(c as u32 as u8);
+        }
+
+        return Some(bytes);
+    }
+
+    pub fn decode(interpreter: &mut Interpreter, bytes: &[u8], encoding: &str) -> Option<String> {
+        let name = Self::encoding_name(interpreter, encoding)?;
+        match name {
+            "utf-8" => {
+                match std::str::from_utf8(bytes) {
+                    Ok(text) => {
+                        return Some(text.to_owned());
+                    }
+                    Err(error) => {
+                        let position = error.valid_up_to();
+                        interpreter.fail(format!(
+                            "KhaladHabxarfeedKaBadal: habxarfeedka 'utf-8' ma beddeli karo dhibicda 0x{:02x} ee booska {}",
+                            bytes[position], position
+                        ));
+                    }
+                }
+            }
+            "ascii" => {
+                let position = bytes.iter().position(|&byte| byte >= 128);
+                if let Some(position) = position {
+                    interpreter.fail(format!(
+                        "KhaladHabxarfeedKaBadal: habxarfeedka 'ascii' ma beddeli karo dhibicda 0x{:02x} ee booska {}: waxay ka baxsan tahay 0 ilaa 128",
+                        bytes[position], position
+                    ));
+                    return None;
+                }
+
+                return Some(bytes.iter().map(|&byte| byte as char).collect());
+            }
+            _ => {
+                return Some(bytes.iter().map(|&byte| byte as char).collect());
+            }
+        }
+
+        return None;
+    }
+
+    pub fn encoding_arg(interpreter: &mut Interpreter, func: &str, args: Vec<ParsedNode>) -> Option<String> {
+        let (args, kwargs) = Self::split_kwargs(args);
+        if !Self::known_kwargs(interpreter, func, &kwargs, &["habxarfeed"]) {
+            return None;
+        }
+
+        let mut encoding = Self::kwarg(&kwargs, "habxarfeed");
+        if args.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: {}() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", func, args.len()));
+            return None;
+        }
+
+        if args.len() == 1 {
+            encoding = Some(args[0].clone());
+        }
+
+        match encoding {
+            Some(ParsedNode::Str { val }) => {
+                return Some(Self::unquote(&val));
+            }
+            None => {
+                return Some("utf-8".to_owned());
+            }
+            Some(other) => {
+                interpreter.fail(format!("NoocKhaldan: {}() habxarfeedku waa inuu qoraal ahaadaa, ma aha '{}'", func, Self::type_name(&other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn sequence_method(interpreter: &mut Interpreter, value: &ParsedNode, name: &str, args: Vec<ParsedNode>) -> Option<Option<ParsedNode>> {
+        //The outer None means the method doesn't exist, the inner one that calling it failed
+        match (value, name) {
+            (ParsedNode::Str { val }, "ubadal") => {
+                let encoding = Self::encoding_arg(interpreter, name, args);
+                if encoding.is_none() {
+                    return Some(None);
+                }
+
+                return Some(Self::encode(interpreter, &Self::unquote(val), &encoding.unwrap()).map(|bytes| ParsedNode::Bytes { val: bytes }));
+            }
+            (ParsedNode::Bytes { .. }, "kabadal") | (ParsedNode::ByteArray { .. }, "kabadal") => {
+                let encoding = Self::encoding_arg(interpreter, name, args);
+                if encoding.is_none() {
+                    return Some(None);
+                }
+
+                return Some(Self::decode(interpreter, &Self::byte_data(value).unwrap(), &encoding.unwrap()).map(|text| ParsedNode::Str {
+                    val: format!("\"{}\"", text)
+                }));
+            }
+            (ParsedNode::Complex { real, imag }, "lammaane") => {
+                return Some(Some(ParsedNode::Complex { real: *real, imag: -imag }));
+            }
+            (ParsedNode::Int { .. } | ParsedNode::Bool { .. }, "lammaane") => {
+                return Some(Some(Self::from_integer(Self::to_integer(value).unwrap())));
+            }
+            (ParsedNode::Float { .. }, "lammaane") => {
+                return Some(Some(value.clone()));
+            }
+            (ParsedNode::MemoryView { val }, "dhibcokadhig") => {
+                return Some(Some(ParsedNode::Bytes { val: val.0.borrow().clone() }));
+            }
+            (ParsedNode::MemoryView { .. }, "aruurkadhig") => {
+                return Some(Some(ParsedNode::List { items: Self::items(interpreter, value.clone()).unwrap() }));
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn index_number(interpreter: &mut Interpreter, value: &ParsedNode, length: usize) -> Option<i64> {
+        match value {
+            ParsedNode::Int { .. } | ParsedNode::Bool { .. } => {
+                let num = Self::to_number(value).unwrap() as i64;
+                return Some(if num < 0 { num + length as i64 } else { num });
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: jagaalaha waa inuu tirodhan ahaadaa, ma aha '{}'", Self::type_name(other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn slice_positions(interpreter: &mut Interpreter, parts: &Vec<ParsedNode>, length: usize) -> Option<Vec<usize>> {
+        let mut bounds: Vec<Option<i64>> = vec![];
+        for part in parts {
+            match part {
+                ParsedNode::Null => {
+                    bounds.push(None);
+                }
+                _ => {
+                    bounds.push(Some(Self::index_number(interpreter, part, 0)?));
+                }
+            }
+        }
+
+        let step = bounds.get(2).cloned().flatten().unwrap_or(1);
+        if step == 0 {
+            interpreter.fail("KhaladQiimeyn: tallaabada jarista ma noqon karto eber".to_owned());
+            return None;
+        }
+
+        //Bounds are counted from the end when negative, then kept inside the sequence
+        let length = length as i64;
+        let (low, high) = if step > 0 { (0, length) } else { (-1, length - 1) };
+        let clamp = |bound: Option<i64>, default: i64| match bound {
+            Some(num) => (if num < 0 { num + length } else { num }).max(low).min(high),
+            None => default
+        };
+
+        let mut position = clamp(bounds[0], if step > 0 { 0 } else { length - 1 });
+        let stop = clamp(bounds[1], if step > 0 { length } else { -1 });
+        let mut positions: Vec<usize> = vec![];
+        while (step > 0 && position < stop) || (step < 0 && position > stop) {
+            positions.push(position as usize);
+            position += step;
+        }
+
+        return Some(positions);
+    }
+
+    pub fn index(interpreter: &mut Interpreter, value: ParsedNode, parts: Vec<ParsedNode>) -> Option<ParsedNode> {
+        if parts.len() > 3 {
+            interpreter.fail("KhaladHabQoraal: jarista ugu badnaan 3 qaybood bay leedahay".to_owned());
+            return None;
+        }
+
+        //A value with a hel hook, set with sifobadal, looks up its own items
+        if let Some(hook) = Self::attribute(&value, "hel") {
+            if parts.len() > 1 {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' lama jari karo", Self::type_name(&value)));
+                return None;
+            }
+
+            return interpreter.call_value(&hook, parts);
+        }
+
+        match &Self::snapshot(&value) {
+            ParsedNode::Dict { items } => {
+                if parts.len() > 1 {
+                    interpreter.fail("NoocKhaldan: qaamuus lama jari karo".to_owned());
+                    return None;
+                }
+
+                let found = items.iter().find(|(item, _)| Self::same_key(item, &parts[0]));
+                if found.is_none() {
+                    interpreter.fail(format!("KhaladFuro: {}", Self::repr(&parts[0])));
+                    return None;
+                }
+
+                return Some(found.unwrap().1.clone());
+            }
+            ParsedNode::Str { .. } | ParsedNode::List { .. } | ParsedNode::Tuple { .. } |
+            ParsedNode::Bytes { .. } | ParsedNode::ByteArray { .. } | ParsedNode::MemoryView { .. } => { }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' jagaale ma leh", Self::type_name(other)));
+                return None;
+            }
+        }
+
+        let items = Self::items(interpreter, value.clone()).unwrap();
+        if parts.len() == 1 {
+            let position = Self::index_number(interpreter, &parts[0], items.len())?;
+            if position < 0 || position as usize >= items.len() {
+                interpreter.fail(format!("KhaladJagaale: jagaalaha {} waa ka baxsan yahay", Self::type_name(&value)));
+                return None;
+            }
+
+            return Some(items[position as usize].clone());
+        }
+
+        let chosen: Vec<ParsedNode> = Self::slice_positions(interpreter, &parts, items.len())?
+            .into_iter()
+            .map(|position| items[position].clone())
+            .collect();
+
+        let bytes = || chosen.iter().map(|item| Self::to_number(item).unwrap() as u8).collect::<Vec<u8>>();
+        match value {
+            ParsedNode::Str { .. } => {
+                let text: String = chosen.iter().map(Self::display).collect();
+                return Some(ParsedNode::Str { val: format!("\"{}\"", text) });
+            }
+            ParsedNode::Tuple { .. } => {
+                return Some(ParsedNode::Tuple { items: chosen });
+            }
+            ParsedNode::Bytes { .. } => {
+                return Some(ParsedNode::Bytes { val: bytes() });
+            }
+            ParsedNode::ByteArray { .. } => {
+                return Some(ParsedNode::ByteArray { val: Buffer::new(bytes()) });
+            }
+            ParsedNode::MemoryView { .. } => {
+                return Some(ParsedNode::MemoryView { val: Buffer::new(bytes()) });
+            }
+            _ => {
+                return Some(ParsedNode::List { items: chosen });
+            }
+        }
+    }
+
+    pub fn set_index(interpreter: &mut Interpreter, value: ParsedNode, parts: Vec<ParsedNode>, item: ParsedNode) -> Option<ParsedNode> {
+        if parts.len() != 1 {
+            interpreter.fail("NoocKhaldan: jarista lama beddeli karo, jagaale keliya ayaa la beddeli karaa".to_owned());
+            return None;
+        }
+
+        match value {
+            ParsedNode::Dict { mut items } => {
+                let found = items.iter().position(|(other, _)| Self::same_key(other, &parts[0]));
+                if let Some(found) = found {
+                    items[found].1 = item;
+                } else {
+                    items.push((parts[0].clone(), item));
+                }
+
+                return Some(ParsedNode::Dict { items });
+            }
+            ParsedNode::Namespace { variables } => {
+                match &parts[0] {
+                    ParsedNode::Str { val } => {
+                        let name = Self::unquote(val);
+                        let mut globals = variables.0.borrow_mut();
+                        let found = globals.iter().position(|(other, _)| other == &name);
+                        if let Some(found) = found {
+                            *globals[found].1 = item;
+                        } else {
+                            globals.push((name, Box::new(item)));
+                        }
+                    }
+                    other => {
+                        interpreter.fail(format!("NoocKhaldan: furayaasha caalamiyaasha waa inay qoraal ahaadaan, ma aha '{}'", Self::type_name(other)));
+                        return None;
+                    }
+                }
+
+                return Some(ParsedNode::Namespace { variables });
+            }
+            ParsedNode::List { mut items } => {
+                let position = Self::index_number(interpreter, &parts[0], items.len())?;
+                if position < 0 || position as usize >= items.len() {
+                    interpreter.fail("KhaladJagaale: jagaalaha aruur waa ka baxsan yahay".to_owned());
+                    return None;
+                }
+
+                items[position as usize] = item;
+                return Some(ParsedNode::List { items });
+            }
+            ParsedNode::ByteArray { ref val } | ParsedNode::MemoryView { ref val } => {
+                let length = val.0.borrow().len();
+                let position = Self::index_number(interpreter, &parts[0], length)?;
+                if position < 0 || position as usize >= length {
+                    interpreter.fail("KhaladJagaale: jagaalaha kooxdhibco waa ka baxsan yahay".to_owned());
+                    return None;
+                }
+
+                let byte = match item {
+                    ParsedNode::Int { .. } | ParsedNode::Bool { .. } => Self::to_number(&item).unwrap(),
+                    other => {
+                        interpreter.fail(format!("NoocKhaldan: shayga '{}' looma beddeli karo dhibic", Self::type_name(&other)));
+                        return None;
+                    }
+                };
+
+                if !(0.0..=255.0).contains(&byte) {
+                    interpreter.fail("KhaladQiimeyn: dhibcuhu waa inay u dhexeeyaan 0 iyo 255".to_owned());
+                    return None;
+                }
+
+                //The bytes are shared, so writing them in place reaches every copy and view
+                val.0.borrow_mut()[position as usize] = byte as u8;
+                return Some(value.clone());
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' ma oggola in jagaalihiisa wax lagu beddelo", Self::type_name(&other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn complex_part(num: f64) -> String {
+        if num.is_nan() {
+            return "nan".to_owned();
+        } else if num.is_infinite() {
+            return (if num > 0.0 { "inf" } else { "-inf" }).to_owned();
+        }
+
+        return num.to_string();
+    }
+
+    pub fn complex_tokens(real: f64, imag: f64) -> Vec<Token> {
+        return vec![
+            Token::OpenParen,
+            Token::Float(real.to_string().chars().collect()),
+            Token::Plus,
+            Token::Float(format!("{}j", imag).chars().collect()),
+            Token::CloseParen
+        ];
+    }
+
+    pub fn eval_complex(interpreter: &mut Interpreter, text: &str) -> Option<(f64, f64)> {
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut position = 0;
+        let result = Self::complex_sum(interpreter, &chars, &mut position)?;
+        if position < chars.len() {
+            interpreter.fail(format!("KhaladHabQoraal: xisaabta '{}' waa khaldan tahay", text));
+            return None;
+        }
+
+        return Some(result);
+    }
+
+    pub fn complex_sum(interpreter: &mut Interpreter, chars: &Vec<char>, position: &mut usize) -> Option<(f64, f64)> {
+        let mut total = Self::complex_product(interpreter, chars, position)?;
+        while *position < chars.len() && ['+', '-'].contains(&chars[*position]) {
+            let operator = chars[*position];
+            *position += 1;
+            let (real, imag) = Self::complex_product(interpreter, chars, position)?;
+            total = if operator == '+' { (total.0 + real, total.1 + imag) } else { (total.0 - real, total.1 - imag) };
+        }
+
+        return Some(total);
+    }
+
+    pub fn complex_product(interpreter: &mut Interpreter, chars: &Vec<char>, position: &mut usize) -> Option<(f64, f64)> {
+        let mut total = Self::complex_unary(interpreter, chars, position)?;
+        while *position < chars.len() && ['*', '/', '%'].contains(&chars[*position]) {
+            let operator = chars[*position];
+            *position += 1;
+            let (real, imag) = Self::complex_unary(interpreter, chars, position)?;
+            match operator {
+                '*' => {
+                    total = (total.0 * real - total.1 * imag, total.0 * imag + total.1 * real);
+                }
+                '/' => {
+                    let divisor = real * real + imag * imag;
+                    if divisor == 0.0 {
+                        interpreter.fail("KhaladEberUQeybin: kakan eber looma qaybin karo".to_owned());
+                        return None;
+                    }
+
+                    total = (
+                        (total.0 * real + total.1 * imag) / divisor,
+                        (total.1 * real - total.0 * imag) / divisor
+                    );
+                }
+                _ => {
+                    interpreter.fail("NoocKhaldan: kakan baaqi lama qaadan karo".to_owned());
+                    return None;
+                }
+            }
+        }
+
+        return Some(total);
+    }
+
+    pub fn complex_unary(interpreter: &mut Interpreter, chars: &Vec<char>, position: &mut usize) -> Option<(f64, f64)> {
+        if *position < chars.len() && ['+', '-'].contains(&chars[*position]) {
+            let negative = chars[*position] == '-';
+            *position += 1;
+            let (real, imag) = Self::complex_unary(interpreter, chars, position)?;
+            return Some(if negative { (-real, -imag) } else { (real, imag) });
+        }
+
+        let base = Self::complex_atom(interpreter, chars, position)?;
+        if *position < chars.len() && chars[*position] == '^' {
+            *position += 1;
+            let exponent = Self::complex_unary(interpreter, chars, position)?;
+            return Self::complex_power(interpreter, base, exponent);
+        }
+
+        return Some(base);
+    }
+
+    pub fn complex_power(interpreter: &mut Interpreter, base: (f64, f64), exponent: (f64, f64)) -> Option<(f64, f64)> {
+        if base == (0.0, 0.0) {
+            if exponent == (0.0, 0.0) {
+                return Some((1.0, 0.0));
+            }
+
+            if exponent.1 != 0.0 || exponent.0 < 0.0 {
+                interpreter.fail("KhaladEberUQeybin: eber looma kordhin karo tiro taban ama kakan".to_owned());
+                return None;
+            }
+
+            return Some((0.0, 0.0));
+        }
+
+        //Whole powers are multiplied out, which keeps results like 1j^2 exact
+        if exponent.1 == 0.0 && exponent.0.fract() == 0.0 && exponent.0.abs() <= 100.0 {
+            let mut total = (1.0, 0.0);
+            for _ in 0..(exponent.0.abs() as usize) {
+                total = (total.0 * base.0 - total.1 * base.1, total.0 * base.1 + total.1 * base.0);
+            }
+
+            if exponent.0 < 0.0 {
+                let divisor = total.0 * total.0 + total.1 * total.1;
+                total = (total.0 / divisor, -total.1 / divisor);
+            }
+
+            return Some(total);
+        }
+
+        //z^w is e^(w * ln z)
+        let magnitude = base.0.hypot(base.1);
+        let angle = base.1.atan2(base.0);
+        let (log_real, log_imag) = (magnitude.ln(), angle);
+        let real = exponent.0 * log_real - exponent.1 * log_imag;
+        let imag = exponent.0 * log_imag + exponent.1 * log_real;
+        return Some((real.exp() * imag.cos(), real.exp() * imag.sin()));
+    }
+
+    pub fn complex_atom(interpreter: &mut Interpreter, chars: &Vec<char>, position: &mut usize) -> Option<(f64, f64)> {
+        if *position < chars.len() && chars[*position] == '(' {
+            *position += 1;
+            let inner = Self::complex_sum(interpreter, chars, position)?;
+            if *position >= chars.len() || chars[*position] != ')' {
+                interpreter.fail("KhaladHabQoraal: xisaabta waxaa ka maqan ')'".to_owned());
+                return None;
+            }
+
+            *position += 1;
+            return Some(inner);
+        }
+
+        let start = *position;
+        while *position < chars.len() && (chars[*position].is_ascii_alphanumeric() || chars[*position] == '.') && chars[*position] != 'j' {
+            *position += 1;
+        }
+
+        let num = chars[start..*position].iter().collect::<String>().parse::<f64>();
+        if num.is_err() {
+            interpreter.fail(format!("KhaladHabQoraal: '{}' ma aha tiro", chars[start..*position].iter().collect::<String>()));
+            return None;
+        }
+
+        if *position < chars.len() && chars[*position] == 'j' {
+            *position += 1;
+            return Some((0.0, num.unwrap()));
+        }
+
+        return Some((num.unwrap(), 0.0));
+    }
+
+    pub fn kakan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "kakan", &kwargs, &["dhab", "male"]) {
+            return vec![];
+        }
+
+        if args.len() > 2 {
+            interpreter.fail(format!("NoocKhaldan: kakan() ugu badnaan 2 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let mut parts: Vec<Option<ParsedNode>> = vec![Self::kwarg(&kwargs, "dhab"), Self::kwarg(&kwargs, "male")];
+        for (index, arg) in args.iter().enumerate() {
+            parts[index] = Some(arg.clone());
+        }
+
+        if let Some(ParsedNode::Str { val }) = &parts[0] {
+            if parts[1].is_some() {
+                interpreter.fail("NoocKhaldan: kakan() shay labaad ma qaato haddii kan koowaad qoraal yahay".to_owned());
+                return vec![];
+            }
+
+            //Strings are read the same way a literal such as (1+2j) would be
+            let text = Self::unquote(val).replace(" ", "");
+            let valid = text.chars().all(|c| c.is_ascii_digit() || ['.', '+', '-', 'j', '(', ')'].contains(&c));
+            let value = if valid && !text.is_empty() { Self::eval_complex(interpreter, &text) } else { None };
+            if value.is_none() {
+                interpreter.error = None;
+
+                interpreter.fail(format!("KhaladQiimeyn: kakan() qoraal khaldan: '{}'", Self::unquote(val)));
+                return vec![];
+            }
+
+            let (real, imag) = value.unwrap();
+            return vec![ParsedNode::Complex { real, imag }];
+        }
+
+        let mut total = (0.0, 0.0);
+        for (index, part) in parts.into_iter().enumerate() {
+            let value = match part {
+                None => (0.0, 0.0),
+                Some(ParsedNode::Complex { real, imag }) => (real, imag),
+                Some(other) => {
+                    let num = Self::to_number(&other);
+                    if num.is_none() {
+                        interpreter.fail(format!("NoocKhaldan: kakan() wuxuu filayay tiro, laakiin '{}' baa la siiyay", Self::type_name(&other)));
+                        return vec![];
+                    }
+
+                    (num.unwrap(), 0.0)
+                }
+            };
+
+            //The second part is multiplied by j, so real + male * j
+            total = if index == 0 { value } else { (total.0 - value.1, total.1 + value.0) };
+        }
+
+        return vec![ParsedNode::Complex { real: total.0, imag: total.1 }];
+    }
+
+    pub fn nooc(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: nooc() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        //Types are their own conversion built-ins, so nooc(x)(y) converts y
+        return vec![ParsedNode::Type { name: Self::type_name(&params[0]) }];
+    }
+
+    pub fn sifosheeg(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: sifosheeg() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let mut names: Vec<String> = vec![];
+        if params.is_empty() {
+            names = interpreter.scope().into_iter().map(|(name, _)| name).collect();
+        } else {
+            names = Self::member_names(&params[0]);
+        }
+
+        names.sort();
+        names.dedup();
+        return vec![ParsedNode::List {
+            items: names.into_iter().map(|name| ParsedNode::Str { val: format!("\"{}\"", name) }).collect()
+        }];
+    }
+
+    pub fn namespace(interpreter: &mut Interpreter, local: bool) -> ParsedNode {
+        //Outside of a call the locals are the globals, and those are handed out live
+        if !local || interpreter.frames.is_empty() {
+            return ParsedNode::Namespace { variables: interpreter.variables.clone() };
+        }
+
+        let mut items: Vec<(ParsedNode, ParsedNode)> = vec![];
+        for (name, value) in interpreter.scope() {
+            items.push((ParsedNode::Str { val: format!("\"{}\"", name) }, value));
+        }
+
+        return ParsedNode::Dict { items };
+    }
+
+    //What a caalamiyaasha() handle holds right now, as a qaamuus
+    pub fn snapshot(value: &ParsedNode) -> ParsedNode {
+        match value {
+            ParsedNode::Namespace { variables } => {
+                return ParsedNode::Dict {
+                    items: variables.0.borrow().iter().map(|(name, value)| (
+                        ParsedNode::Str { val: format!("\"{}\"", name) },
+                        value.as_ref().clone()
+                    )).collect()
+                };
+            }
+            _ => {
+                return value.clone();
+            }
+        }
+    }
+
+    pub fn caalamiyaasha(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if !params.is_empty() {
+            interpreter.fail(format!("NoocKhaldan: caalamiyaasha() shay ma qaato, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        return vec![Self::namespace(interpreter, false)];
+    }
+
+    pub fn xeradaan(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if !params.is_empty() {
+            interpreter.fail(format!("NoocKhaldan: xeradaan() shay ma qaato, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        return vec![Self::namespace(interpreter, true)];
+    }
+
+    pub fn doorsoomayaal(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: doorsoomayaal() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.is_empty() {
+            return vec![Self::namespace(interpreter, true)];
+        }
+
+        if Self::attribute_owner(&params[0]).is_none() {
+            interpreter.fail(format!("NoocKhaldan: doorsoomayaal() wuxuu u baahan yahay shay sifooyin leh, ma aha '{}'", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Dict {
+            items: Self::attributes(&params[0]).into_iter()
+                .map(|(name, value)| (ParsedNode::Str { val: format!("\"{}\"", name) }, value))
+                .collect()
+        }];
+    }
+
+    pub fn lambarlagugarto(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: lambarlagugarto() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        //Values that share their state carry an identity, so the id is where that state lives
+        let address = match &params[0] {
+            ParsedNode::Function { attributes, .. } => Some(Rc::as_ptr(&attributes.0) as usize),
+            ParsedNode::Namespace { variables } => Some(Rc::as_ptr(&variables.0) as usize),
+            ParsedNode::Iterator { position, .. } => Some(Rc::as_ptr(&position.0) as usize),
+            ParsedNode::File { handle, .. } => Some(Rc::as_ptr(&handle.0) as usize),
+            ParsedNode::ByteArray { val } => Some(Rc::as_ptr(&val.0) as usize),
+            _ => None
+        };
+
+        if let Some(address) = address {
+            return vec![Self::from_integer(address as i128)];
+        }
+
+        //Everything else is copied rather than shared, so equal values have the same identity
+        let mut hasher = DefaultHasher::new();
+        Self::type_name(&params[0]).hash(&mut hasher);
+        Self::repr(&params[0]).hash(&mut hasher);
+        return vec![Self::from_number((hasher.finish() >> 12) as f64, false)];
+    }
+
+    pub fn lambarugaar(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: lambarugaar() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let hash = Self::hash_value(interpreter, &params[0]);
+        if hash.is_none() {
+            return vec![];
+        }
+
+        return vec![Self::from_number(hash.unwrap() as f64, false)];
+    }
+
+    //Numbers that are equal are the same key, so d[1] and d[1.0] find the one entry
+    pub fn same_key(one: &ParsedNode, two: &ParsedNode) -> bool {
+        let (int_one, int_two) = (Self::to_integer(one), Self::to_integer(two));
+        if let (Some(int_one), Some(int_two)) = (int_one, int_two) {
+            return int_one == int_two;
+        }
+
+        let (num_one, num_two) = (Self::to_number(one), Self::to_number(two));
+        if let (Some(num_one), Some(num_two)) = (num_one, num_two) {
+            return num_one == num_two;
+        }
+
+        return Self::repr(one) == Self::repr(two);
+    }
+
+    pub fn hash_value(interpreter: &mut Interpreter, value: &ParsedNode) -> Option<i64> {
+        match value {
+            ParsedNode::Int { .. } | ParsedNode::Bool { .. } => {
+                let integer = Self::to_integer(value);
+                if let Some(integer) = integer {
+                    return Some(integer as i64);
+                }
+            }
+            ParsedNode::Float { .. } => {
+                let num = Self::to_number(value).unwrap();
+                if num.fract() == 0.0 && num.is_finite() {
+                    return Some(num as i64);
+                }
+            }
+            ParsedNode::Tuple { items } => {
+                let mut hasher = DefaultHasher::new();
+                for item in items {
+                    Self::hash_value(interpreter, item)?.hash(&mut hasher);
+                }
+
+                return Some((hasher.finish() >> 12) as i64);
+            }
+            ParsedNode::List { .. } | ParsedNode::Dict { .. } | ParsedNode::Namespace { .. } | ParsedNode::Set { .. } | ParsedNode::ByteArray { .. } => {
+                interpreter.fail(format!("NoocKhaldan: shayga '{}' lambar ugaar ma yeelan karo", Self::type_name(value)));
+                return None;
+            }
+            _ => { }
+        }
+
+        let mut hasher = DefaultHasher::new();
+        Self::repr(value).hash(&mut hasher);
+        return Some((hasher.finish() >> 12) as i64);
+    }
+
+    pub fn mashaquuqabtaa(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: mashaquuqabtaa() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        return vec![ParsedNode::Bool { val: matches!(params[0], ParsedNode::Function { .. } | ParsedNode::Type { .. }) }];
+    }
+
+    //Each time a qayb or laamda is run it makes a new function, with attributes of its own
+    //and the scopes it was run in
+    pub fn new_function(function: ParsedNode, closure: Vec<Variables>) -> ParsedNode {
+        match function {
+            ParsedNode::Function { name, params, body, asynchronous, .. } => {
+                return ParsedNode::Function {
+                    name,
+                    params,
+                    body,
+                    attributes: Attributes::default(),
+                    asynchronous,
+                    closure
+                };
+            }
+            other => {
+                return other;
+            }
+        }
+    }
+
+    pub fn attribute_owner(value: &ParsedNode) -> Option<String> {
+        if let ParsedNode::Function { name, .. } = value {
+            return Some(name.to_owned());
+        }
+
+        return None;
+    }
+
+    pub fn attributes(value: &ParsedNode) -> Vec<(String, ParsedNode)> {
+        if let ParsedNode::Function { attributes, .. } = value {
+            return attributes.0.borrow().clone();
+        }
+
+        return vec![];
+    }
+
+    //Everything sifosheeg() lists for a value: its attributes and the methods it can be called with
+    pub fn member_names(value: &ParsedNode) -> Vec<String> {
+        let methods: &[&str] = match value {
+            ParsedNode::Int { .. } | ParsedNode::Float { .. } | ParsedNode::Bool { .. } | ParsedNode::Complex { .. } => &["dhab", "male", "lammaane"],
+            ParsedNode::Str { .. } => &["ubadal"],
+            ParsedNode::Bytes { .. } | ParsedNode::ByteArray { .. } => &["kabadal"],
+            ParsedNode::MemoryView { .. } => &["dhibcokadhig", "aruurkadhig"],
+            ParsedNode::File { .. } => &["akhri", "akhrisadar", "akhrisadarro", "qor", "raadi", "xir"],
+            ParsedNode::Function { .. } => &["magac"],
+            _ => &[]
+        };
+
+        let mut names: Vec<String> = methods.iter().map(|name| name.to_string()).collect();
+        names.extend(Self::attributes(value).into_iter().map(|(name, _)| name));
+        return names;
+    }
+
+    pub fn attribute(value: &ParsedNode, name: &str) -> Option<ParsedNode> {
+        match (value, name) {
+            (ParsedNode::Complex { real, .. }, "dhab") => {
+                return Some(Self::from_number(*real, true));
+            }
+            (ParsedNode::Complex { imag, .. }, "male") => {
+                return Some(Self::from_number(*imag, true));
+            }
+            //Real numbers are complex numbers with nothing on the j side
+            (ParsedNode::Int { .. } | ParsedNode::Bool { .. }, "dhab") => {
+                return Some(Self::from_integer(Self::to_integer(value).unwrap()));
+            }
+            (ParsedNode::Float { .. }, "dhab") => {
+                return Some(value.clone());
+            }
+            (ParsedNode::Int { .. } | ParsedNode::Bool { .. }, "male") => {
+                return Some(Self::from_integer(0));
+            }
+            (ParsedNode::Float { .. }, "male") => {
+                return Some(Self::from_number(0.0, true));
+            }
+            _ => { }
+        }
+
+        let owner = Self::attribute_owner(value);
+        if let (Some(owner), "magac") = (owner, name) {
+            return Some(ParsedNode::Str { val: format!("\"{}\"", owner) });
+        }
+
+        return Self::attributes(value).into_iter().find(|(attr, _)| attr == name).map(|(_, item)| item);
+    }
+
+    pub fn attribute_name(interpreter: &mut Interpreter, func: &str, params: &[ParsedNode]) -> Option<String> {
+        match &params[1] {
+            ParsedNode::Str { val } => {
+                return Some(Self::unquote(val));
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: {}() magaca sifada waa inuu qoraal ahaadaa, ma aha '{}'", func, Self::type_name(other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn sifokeen(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 && params.len() != 3 {
+            interpreter.fail(format!("NoocKhaldan: sifokeen() 2 ama 3 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let name = Self::attribute_name(interpreter, "sifokeen", &params);
+        if name.is_none() {
+            return vec![];
+        }
+
+        let value = Self::attribute(&params[0], name.as_ref().unwrap());
+        if let Some(value) = value {
+            return vec![value];
+        }
+
+        if params.len() == 3 {
+            return vec![params[2].clone()];
+        }
+
+        interpreter.fail(format!("KhaladSifeed: shayga '{}' ma laha sifada '{}'", Self::type_name(&params[0]), name.unwrap()));
+        return vec![];
+    }
+
+    pub fn sifomaleeyahay(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: sifomaleeyahay() 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let name = Self::attribute_name(interpreter, "sifomaleeyahay", &params);
+        if name.is_none() {
+            return vec![];
+        }
+
+        return vec![ParsedNode::Bool { val: Self::attribute(&params[0], &name.unwrap()).is_some() }];
+    }
+
+    pub fn sifobadal(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 3 {
+            interpreter.fail(format!("NoocKhaldan: sifobadal() 3 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let name = Self::attribute_name(interpreter, "sifobadal", &params);
+        if name.is_none() {
+            return vec![];
+        }
+
+        //Only functions defined with qayb carry their own attributes
+        let attributes = match &params[0] {
+            ParsedNode::Function { body, attributes, .. } if !body.is_empty() => attributes.clone(),
+            other => {
+                interpreter.fail(format!("KhaladSifeed: shayga '{}' sifo cusub lama siin karo", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        let attr = name.unwrap();
+        if &attr == "magac" {
+            interpreter.fail("KhaladSifeed: sifada 'magac' lama beddeli karo".to_owned());
+            return vec![];
+        }
+
+        let entry = &mut attributes.0.borrow_mut();
+        let existing = entry.iter().position(|(name, _)| name == &attr);
+        if let Some(existing) = existing {
+            entry[existing].1 = params[2].clone();
+        } else {
+            entry.push((attr, params[2].clone()));
+        }
+
+        return vec![];
+    }
+
+    pub fn sifotir(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: sifotir() 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let name = Self::attribute_name(interpreter, "sifotir", &params);
+        if name.is_none() {
+            return vec![];
+        }
+
+        let attr = name.unwrap();
+        if let ParsedNode::Function { attributes, .. } = &params[0] {
+            let pos = attributes.0.borrow().iter().position(|(name, _)| name == &attr);
+            if let Some(pos) = pos {
+                attributes.0.borrow_mut().remove(pos);
+                return vec![];
+            }
+        }
+
+        interpreter.fail(format!("KhaladSifeed: shayga '{}' ma laha sifada '{}'", Self::type_name(&params[0]), attr));
+        return vec![];
+    }
+
+    pub fn weydii(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: weydii() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.len() == 1 {
+            Self::write_out(&Self::display(&params[0]));
+        }
+
+        let line = Self::read_line();
+        if line.is_none() {
+            Self::write_out("\n");
+            interpreter.fail("KhaladQoraalDhamaa: weydii() wuxuu gaaray dhammaadka qoraalka".to_owned());
+            return vec![];
+        }
+
+        return vec![ParsedNode::Str { val: format!("\"{}\"", line.unwrap()) }];
+    }
+
+    pub fn read_line() -> Option<String> {
+        io::stdout().flush().unwrap();
+
+        //The REPL reads keys in raw mode, so line editing is handed back to the terminal while reading
+        let raw = is_raw_mode_enabled().unwrap_or(false);
+        if raw {
+            disable_raw_mode().unwrap();
+        }
+
+        let mut line = String::new();
+        let read = io::stdin().lock().read_line(&mut line);
+
+        if raw {
+            enable_raw_mode().unwrap();
+        }
+
+        match read {
+            Ok(0) | Err(_) => {
+                return None;
+            }
+            _ => { }
+        }
+
+        return Some(line.trim_end_matches(['\n', '\r']).to_owned());
+    }
+
+    pub fn os_error(error: &io::Error, name: &str) -> String {
+        let kind = match error.raw_os_error() {
+            Some(20) => "KhaladGalMaaha",
+            Some(21) => "KhaladWaaGal",
+            _ => match error.kind() {
+                io::ErrorKind::NotFound => "KhaladKaydLamaHelin",
+                io::ErrorKind::PermissionDenied => "KhaladOgolaansho",
+                io::ErrorKind::AlreadyExists => "KhaladKaydWuuJiraa",
+                io::ErrorKind::Interrupted => "KhaladLaGoo",
+                io::ErrorKind::TimedOut => "KhaladWaqtigaaKaDhamaaday",
+                _ => "KhaladGB"
+            }
+        };
+
+        return format!("{}: [{}] '{}'", kind, error, name);
+    }
+
+    pub fn fur(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "fur", &kwargs, &["hab"]) {
+            return vec![];
+        }
+
+        if args.len() != 1 && args.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: fur() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let name = match &args[0] {
+            ParsedNode::Str { val } => Self::unquote(val),
+            other => {
+                interpreter.fail(format!("NoocKhaldan: fur() magaca kaydka waa inuu qoraal ahaadaa, ma aha '{}'", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        let mut mode_node = Self::kwarg(&kwargs, "hab");
+        if args.len() == 2 {
+            mode_node = Some(args[1].clone());
+        }
+
+        let mode = match mode_node {
+            Some(ParsedNode::Str { val }) => Self::unquote(&val),
+            Some(other) => {
+                interpreter.fail(format!("NoocKhaldan: fur() habka waa inuu qoraal ahaadaa, ma aha '{}'", Self::type_name(&other)));
+                return vec![];
+            }
+            None => "r".to_owned()
+        };
+
+        let kinds = mode.chars().filter(|c| ['r', 'w', 'a', 'x'].contains(c)).count();
+        let valid = mode.chars().all(|c| "rwaxbt+".contains(c))
+            && kinds == 1
+            && !(mode.contains('b') && mode.contains('t'))
+            && mode.chars().all(|c| mode.matches(c).count() == 1);
+        if !valid {
+            interpreter.fail(format!("KhaladQiimeyn: fur() hab khaldan: '{}'", mode));
+            return vec![];
+        }
+
+        let update = mode.contains('+');
+        let mut options = OpenOptions::new();
+        if mode.contains('r') {
+            options.read(true).write(update);
+        } else if mode.contains('w') {
+            options.write(true).create(true).truncate(true).read(update);
+        } else if mode.contains('a') {
+            options.append(true).create(true).read(update);
+        } else {
+            options.write(true).create_new(true).read(update);
+        }
+
+        //Directories open fine for reading on some systems, so they're refused up front
+        if Path::new(&name).is_dir() {
+            interpreter.fail(Self::os_error(&io::Error::from_raw_os_error(21), &name));
+            return vec![];
+        }
+
+        match options.open(&name) {
+            Ok(file) => {
+                return vec![ParsedNode::File {
+                    handle: FileHandle(Rc::new(RefCell::new(Some(file)))),
+                    name,
+                    mode
+                }];
+            }
+            Err(error) => {
+                interpreter.fail(Self::os_error(&error, &name));
+            }
+        }
+
+        return vec![];
+    }
+
+    pub fn with_file<T>(interpreter: &mut Interpreter, file: &ParsedNode, action: &dyn Fn(&mut File) -> io::Result<T>) -> Option<T> {
+        if let ParsedNode::File { handle, name, .. } = file {
+            let mut open = handle.0.borrow_mut();
+            if open.is_none() {
+                interpreter.fail("KhaladQiimeyn: hawl lagu sameeyay kayd xiran".to_owned());
+                return None;
+            }
+
+            match action(open.as_mut().unwrap()) {
+                Ok(value) => {
+                    return Some(value);
+                }
+                Err(error) => {
+                    interpreter.fail(Self::os_error(&error, name));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    pub fn file_data(interpreter: &mut Interpreter, file: &ParsedNode, bytes: Vec<u8>) -> ParsedNode {
+        match file {
+            ParsedNode::File { mode, .. } if mode.contains('b') => {
+                return ParsedNode::Bytes { val: bytes };
+            }
+            _ => { }
+        }
+
+        //Quotes are added so the text reads back the same way as a literal
+        let text = Self::decode(interpreter, &bytes, "utf-8");
+        if text.is_none() {
+            return ParsedNode::Null;
+        }
+
+        return ParsedNode::Str { val: format!("\"{}\"", text.unwrap()) };
+    }
+
+    pub fn is_empty(value: &ParsedNode) -> bool {
+        match value {
+            ParsedNode::Str { val } => {
+                return Self::unquote(val).is_empty();
+            }
+            ParsedNode::Bytes { val } => {
+                return val.is_empty();
+            }
+            //Text that couldn't be decoded ends the reading as well
+            ParsedNode::Null => {
+                return true;
+            }
+            _ => { }
+        }
+
+        return false;
+    }
+
+    pub fn readline(interpreter: &mut Interpreter, file: &ParsedNode) -> Option<ParsedNode> {
+        let bytes = Self::with_file(interpreter, file, &|open| {
+            let mut line: Vec<u8> = vec![];
+            let mut byte = [0u8; 1];
+            while open.read(&mut byte)? == 1 {
+                line.push(byte[0]);
+                if byte[0] == b'\n' {
+                    break;
+                }
+            }
+
+            return Ok(line);
+        })?;
+
+        return Some(Self::file_data(interpreter, file, bytes));
+    }
+
+    pub fn call_method(interpreter: &mut Interpreter, value: ParsedNode, name: &str, args: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if let ParsedNode::File { .. } = &value {
+            let out = Self::file_method(interpreter, &value, name, args);
+            return out.into_iter().collect();
+        }
+
+        let out = Self::sequence_method(interpreter, &value, name, args.clone());
+        if let Some(out) = out {
+            return out.into_iter().collect();
+        }
+
+        let attribute = Self::attribute(&value, name);
+        if let Some(attribute) = attribute {
+            let out = interpreter.call_value(&attribute, args);
+            return out.into_iter().collect();
+        }
+
+        interpreter.fail(format!("KhaladSifeed: shayga '{}' ma laha sifada '{}'", Self::type_name(&value), name));
+        return vec![];
+    }
+
+    pub fn file_method(interpreter: &mut Interpreter, file: &ParsedNode, name: &str, args: Vec<ParsedNode>) -> Option<ParsedNode> {
+        let binary = match file {
+            ParsedNode::File { mode, .. } => mode.contains('b'),
+            _ => false
+        };
+
+        match name {
+            "akhri" => {
+                let mut size: i64 = -1;
+                if !args.is_empty() {
+                    match &args[0] {
+                        ParsedNode::Int { .. } => {
+                            size = Self::to_number(&args[0]).unwrap() as i64;
+                        }
+                        ParsedNode::Null => { }
+                        other => {
+                            interpreter.fail(format!("NoocKhaldan: akhri() wuxuu filayay tirodhan, laakiin '{}' baa la siiyay", Self::type_name(other)));
+                            return None;
+                        }
+                    }
+                }
+
+                let bytes = Self::with_file(interpreter, file, &|open| {
+                    let mut data: Vec<u8> = vec![];
+                    if size < 0 {
+                        open.read_to_end(&mut data)?;
+                    } else if binary {
+                        open.take(size as u64).read_to_end(&mut data)?;
+                    } else {
+                        //Text files are read a character at a time, and a character is one to four bytes
+                        let mut byte = [0u8; 1];
+                        for _ in 0..size {
+                            if open.read(&mut byte)? == 0 {
+                                break;
                             }
-                        ];
+
+                            let length = match byte[0] {
+                                0xF0..=0xFF => 4,
+                                0xE0..=0xEF => 3,
+                                0xC0..=0xDF => 2,
+                                _ => 1
+                            };
+
+                            data.push(byte[0]);
+                            open.take(length - 1).read_to_end(&mut data)?;
+                        }
                     }
-                },
-                ParsedNode::Bool { val } => {
-                    let num = if val { '0' } else { '1' };
-                    return vec![
-                        ParsedNode::Int {
-                            val: vec![num]
+
+                    return Ok(data);
+                })?;
+
+                return Some(Self::file_data(interpreter, file, bytes));
+            }
+            "akhrisadar" => {
+                return Self::readline(interpreter, file);
+            }
+            "akhrisadarro" => {
+                let mut lines: Vec<ParsedNode> = vec![];
+                loop {
+                    let line = Self::readline(interpreter, file)?;
+                    if Self::is_empty(&line) {
+                        break;
+                    }
+
+                    lines.push(line);
+                }
+
+                return Some(ParsedNode::List { items: lines });
+            }
+            "qor" => {
+                if args.len() != 1 {
+                    interpreter.fail(format!("NoocKhaldan: qor() 1 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+                    return None;
+                }
+
+                let (data, count) = match (&args[0], binary) {
+                    (ParsedNode::Str { val }, false) => {
+                        let text = Self::unquote(val);
+                        let count = text.chars().count();
+                        (text.into_bytes(), count)
+                    }
+                    (ParsedNode::Bytes { .. }, true) | (ParsedNode::ByteArray { .. }, true) | (ParsedNode::MemoryView { .. }, true) => {
+                        let data = Self::byte_data(&args[0]).unwrap();
+                        let count = data.len();
+                        (data, count)
+                    }
+                    (other, _) => {
+                        let expected = if binary { "dhibco" } else { "qoraal" };
+                        interpreter.fail(format!("NoocKhaldan: qor() wuxuu filayay {}, laakiin '{}' baa la siiyay", expected, Self::type_name(other)));
+                        return None;
+                    }
+                };
+
+                Self::with_file(interpreter, file, &|open| open.write_all(&data))?;
+                return Some(Self::from_number(count as f64, false));
+            }
+            "raadi" => {
+                if args.len() != 1 || !matches!(args[0], ParsedNode::Int { .. }) {
+                    interpreter.fail("NoocKhaldan: raadi() tirodhan keliya buu qaataa".to_owned());
+                    return None;
+                }
+
+                let offset = Self::to_number(&args[0]).unwrap() as u64;
+                let position = Self::with_file(interpreter, file, &|open| open.seek(SeekFrom::Start(offset)))?;
+                return Some(Self::from_number(position as f64, false));
+            }
+            "xir" => {
+                if let ParsedNode::File { handle, .. } = file {
+                    handle.0.borrow_mut().take();
+                }
+
+                return Some(ParsedNode::Null);
+            }
+            _ => { }
+        }
+
+        interpreter.fail(format!("KhaladSifeed: shayga 'kayd' ma laha sifada '{}'", name));
+        return None;
+    }
+
+    pub fn compile(interpreter: &mut Interpreter, source: &str, name: &str, mode: &str) -> Option<ParsedNode> {
+        //Line numbers would point into the string rather than the file, so the debugger skips them
+        let parsed: Vec<ParsedNode> = Parser::new(Lexer::new(source).lex()).parse()
+            .into_iter()
+            .filter(|node| !matches!(node, ParsedNode::Ignore | ParsedNode::Line { .. }))
+            .collect();
+
+        for node in &parsed {
+            if let ParsedNode::Error { .. } = node {
+                interpreter.raise(node.clone());
+                return None;
+            }
+        }
+
+        //Only a single expression has a value to give back
+        if mode == "qiimee" && (parsed.len() != 1 || !interpreter.is_expression(&parsed[0])) {
+            interpreter.fail(format!("KhaladHabQoraal: qiimee() wuxuu filayay hal tibaax: '{}'", source.trim()));
+            return None;
+        }
+
+        return Some(ParsedNode::Code {
+            name: name.to_owned(),
+            mode: mode.to_owned(),
+            body: parsed
+        });
+    }
+
+    pub fn code_from(interpreter: &mut Interpreter, func: &str, value: &ParsedNode) -> Option<ParsedNode> {
+        match value {
+            ParsedNode::Str { val } => {
+                return Self::compile(interpreter, &Self::unquote(val), "<qoraal>", func);
+            }
+            ParsedNode::Code { mode, .. } => {
+                if func == "qiimee" && mode != "qiimee" {
+                    interpreter.fail(format!("NoocKhaldan: qiimee() koodh loo dhisay '{}' ma fulin karo", mode));
+                    return None;
+                }
+
+                return Some(value.clone());
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: {}() wuxuu filayay qoraal ama koodh, laakiin '{}' baa la siiyay", func, Self::type_name(other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn run_code(interpreter: &mut Interpreter, func: &str, params: Vec<ParsedNode>) -> Option<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, func, &kwargs, &["caalami", "deegaan"]) {
+            return None;
+        }
+
+        if args.is_empty() || args.len() > 3 {
+            interpreter.fail(format!("NoocKhaldan: {}() 1 ilaa 3 shay buu qaataa, laakiin {} shay baa la siiyay", func, args.len()));
+            return None;
+        }
+
+        let mut namespaces: Vec<Option<ParsedNode>> = vec![Self::kwarg(&kwargs, "caalami"), Self::kwarg(&kwargs, "deegaan")];
+        for (index, arg) in args[1..].iter().enumerate() {
+            namespaces[index] = Some(arg.clone());
+        }
+
+        let mut scope: Option<Variables> = None;
+        for namespace in namespaces.into_iter().flatten() {
+            match namespace {
+                //caalamiyaasha() itself is run in directly, so the code's changes stay
+                ParsedNode::Namespace { variables } if scope.is_none() => {
+                    scope = Some(variables);
+                }
+                ParsedNode::Dict { .. } | ParsedNode::Namespace { .. } => {
+                    let items = match Self::snapshot(&namespace) {
+                        ParsedNode::Dict { items } => items,
+                        _ => vec![]
+                    };
+
+                    let scope_variables = scope.unwrap_or_default();
+                    let mut variables = scope_variables.0.borrow_mut();
+                    for (key, value) in items {
+                        match key {
+                            ParsedNode::Str { val } => {
+                                let name = Self::unquote(&val);
+                                variables.retain(|(var, _)| var != &name);
+                                variables.push((name, Box::new(value)));
+                            }
+                            other => {
+                                interpreter.fail(format!("NoocKhaldan: furayaasha {}() waa inay qoraal ahaadaan, ma aha '{}'", func, Self::type_name(&other)));
+                                return None;
+                            }
                         }
-                    ];
+                    }
+
+                    drop(variables);
+                    scope = Some(scope_variables);
                 }
-                _ => { }
+                ParsedNode::Null => { }
+                other => {
+                    interpreter.fail(format!("NoocKhaldan: {}() wuxuu filayay qaamuus, laakiin '{}' baa la siiyay", func, Self::type_name(&other)));
+                    return None;
+                }
+            }
+        }
+
+        let code = Self::code_from(interpreter, func, &args[0])?;
+        let body = match code {
+            ParsedNode::Code { body, .. } => body,
+            _ => vec![]
+        };
+
+        //Given namespaces replace the variables only while the code runs, and
+        //the code runs as though nothing had called it
+        let saved = scope.map(|scope| (
+            std::mem::replace(&mut interpreter.variables, scope),
+            std::mem::take(&mut interpreter.frames)
+        ));
+
+        let result = if func == "qiimee" && body.len() == 1 {
+            interpreter.resolve(body[0].clone())
+        } else {
+            interpreter.interpret(false, body);
+            ParsedNode::Null
+        };
+
+        if let Some(saved) = saved {
+            let (variables, frames) = saved;
+            interpreter.variables = variables;
+            interpreter.frames = frames;
+        }
+
+        return Some(result);
+    }
+
+    pub fn qiimee(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::run_code(interpreter, "qiimee", params).into_iter().collect();
+    }
+
+    pub fn samee(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return Self::run_code(interpreter, "samee", params).into_iter().collect();
+    }
+
+    pub fn dhis(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let (args, kwargs) = Self::split_kwargs(params);
+        if !Self::known_kwargs(interpreter, "dhis", &kwargs, &["qoraal", "magac", "hab"]) {
+            return vec![];
+        }
+
+        if args.len() > 3 {
+            interpreter.fail(format!("NoocKhaldan: dhis() ugu badnaan 3 shay buu qaataa, laakiin {} shay baa la siiyay", args.len()));
+            return vec![];
+        }
+
+        let mut values: Vec<Option<ParsedNode>> = vec![
+            Self::kwarg(&kwargs, "qoraal"),
+            Self::kwarg(&kwargs, "magac"),
+            Self::kwarg(&kwargs, "hab")
+        ];
+        for (index, arg) in args.into_iter().enumerate() {
+            values[index] = Some(arg);
+        }
+
+        let mut strings: Vec<String> = vec![];
+        for (index, value) in values.into_iter().enumerate() {
+            match value {
+                Some(ParsedNode::Str { val }) => {
+                    strings.push(Self::unquote(&val));
+                }
+                Some(other) => {
+                    interpreter.fail(format!("NoocKhaldan: dhis() wuxuu filayay qoraal, laakiin '{}' baa la siiyay", Self::type_name(&other)));
+                    return vec![];
+                }
+                None if index == 1 => {
+                    strings.push("<qoraal>".to_owned());
+                }
+                None if index == 2 => {
+                    strings.push("samee".to_owned());
+                }
+                None => {
+                    interpreter.fail("NoocKhaldan: dhis() wuxuu u baahan yahay qoraal".to_owned());
+                    return vec![];
+                }
+            }
+        }
+
+        if strings[2] != "qiimee" && strings[2] != "samee" {
+            interpreter.fail("KhaladQiimeyn: dhis() habka waa inuu ahaadaa 'qiimee' ama 'samee'".to_owned());
+            return vec![];
+        }
+
+        return Self::compile(interpreter, &strings[0], &strings[1], &strings[2]).into_iter().collect();
+    }
+
+    pub fn docstring(body: &[ParsedNode]) -> Option<String> {
+        let first = body.iter().find(|node| !matches!(node, ParsedNode::Ignore | ParsedNode::Line { .. }));
+        if let Some(ParsedNode::Str { val }) = first {
+            return Some(Self::unquote(val));
+        }
+
+        return None;
+    }
+
+    pub fn bartaanbaar(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if !params.is_empty() {
+            interpreter.fail(format!("NoocKhaldan: bartaanbaar() shay ma qaato, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        //The debugger takes over from the next line that runs
+        interpreter.debugger.start();
+        return vec![];
+    }
+
+    //A coroutine nothing has awaited or made a hawl of yet
+    pub fn fresh_task(interpreter: &mut Interpreter, func: &str, value: &ParsedNode) -> Option<Task> {
+        match value {
+            ParsedNode::Coroutine { task } if !task.0.borrow().scheduled && !task.0.borrow().awaited => {
+                return Some(task.clone());
+            }
+            other => {
+                interpreter.fail(format!("NoocKhaldan: {}() wuxuu u baahan yahay shay mardambe ah oo aan la sugin, ma aha '{}'", func, Self::type_name(other)));
+            }
+        }
+
+        return None;
+    }
+
+    pub fn loop_running(interpreter: &mut Interpreter, func: &str) -> bool {
+        if !interpreter.events.running {
+            interpreter.fail(format!("KhaladGoortaShaqada: {}() wuxuu u baahan yahay wareeg socda", func));
+        }
+
+        return interpreter.events.running;
+    }
+
+    pub fn wareeg(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: wareeg() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if interpreter.events.running {
+            interpreter.fail("KhaladGoortaShaqada: wareeg() lagama wici karo wareeg socda gudihiisa".to_owned());
+            return vec![];
+        }
+
+        let task = Self::fresh_task(interpreter, "wareeg", &params[0]);
+        if task.is_none() {
+            return vec![];
+        }
+
+        return eventloop::run(interpreter, task.unwrap()).into_iter().collect();
+    }
+
+    pub fn hurdo(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 && params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: hurdo() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let seconds = match &params[0] {
+            ParsedNode::Int { .. } | ParsedNode::Float { .. } | ParsedNode::Bool { .. } => Self::to_number(&params[0]).unwrap(),
+            other => {
+                interpreter.fail(format!("NoocKhaldan: hurdo() ilbiriqsiyada waa inay tiro ahaadaan, ma aha '{}'", Self::type_name(other)));
+                return vec![];
+            }
+        };
+
+        return vec![eventloop::coroutine(Awaitable::Sleep {
+            seconds,
+            value: params.get(1).cloned().unwrap_or(ParsedNode::Null)
+        })];
+    }
+
+    pub fn hawl(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: hawl() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if !Self::loop_running(interpreter, "hawl") {
+            return vec![];
+        }
+
+        let task = Self::fresh_task(interpreter, "hawl", &params[0]);
+        if task.is_none() {
+            return vec![];
+        }
+
+        eventloop::schedule(interpreter, task.as_ref().unwrap());
+        return vec![params[0].clone()];
+    }
+
+    pub fn wadajir(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if !Self::loop_running(interpreter, "wadajir") {
+            return vec![];
+        }
+
+        //Everything is started straight away, and awaiting the result waits for them all
+        for item in &params {
+            match item {
+                ParsedNode::Coroutine { task } if task.0.borrow().scheduled => { }
+                other => {
+                    let task = Self::fresh_task(interpreter, "wadajir", other);
+                    if task.is_none() {
+                        return vec![];
+                    }
+
+                    eventloop::schedule(interpreter, task.as_ref().unwrap());
+                }
+            }
+        }
+
+        return vec![eventloop::coroutine(Awaitable::Gather { items: params })];
+    }
+
+    pub fn kadib(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() < 2 {
+            interpreter.fail(format!("NoocKhaldan: kadib() ugu yaraan 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if !Self::loop_running(interpreter, "kadib") {
+            return vec![];
+        }
+
+        let seconds = Self::to_number(&params[0]);
+        if seconds.is_none() {
+            interpreter.fail(format!("NoocKhaldan: kadib() ilbiriqsiyada waa inay tiro ahaadaan, ma aha '{}'", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        interpreter.events.add_timer(seconds.unwrap(), TimerAction::Call(params[1].clone(), params[2..].to_vec()));
+        return vec![];
+    }
+
+    //What mardambe kastoo and kawad go through: a value with a kawad hook as it is, or any sequence
+    pub fn async_iter(interpreter: &mut Interpreter, value: ParsedNode) -> Option<ParsedNode> {
+        match &value {
+            ParsedNode::Iterator { kind, .. } if kind == "kamidmid" => {
+                return Some(value);
+            }
+            _ => { }
+        }
+
+        if Self::attribute(&value, "kawad").is_some() {
+            return Some(value);
+        }
+
+        let iterator = Self::iter(interpreter, value.clone());
+        if iterator.is_none() {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Self::type_name(&value)));
+            return None;
+        }
+
+        return Some(ParsedNode::Iterator {
+            kind: "kamidmid".to_owned(),
+            sources: vec![iterator.unwrap()],
+            position: Position::default(),
+            start: 0
+        });
+    }
+
+    pub fn kamidmid(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 {
+            interpreter.fail(format!("NoocKhaldan: kamidmid() 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        return Self::async_iter(interpreter, params[0].clone()).into_iter().collect();
+    }
+
+    pub fn kawad(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() != 1 && params.len() != 2 {
+            interpreter.fail(format!("NoocKhaldan: kawad() 1 ama 2 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        let is_async = match &params[0] {
+            ParsedNode::Iterator { kind, .. } => kind == "kamidmid",
+            other => Self::attribute(other, "kawad").is_some()
+        };
+
+        if !is_async {
+            interpreter.fail(format!("NoocKhaldan: shayga '{}' ma aha celceliye mardambe ah", Self::type_name(&params[0])));
+            return vec![];
+        }
+
+        return vec![eventloop::coroutine(Awaitable::Next {
+            iterator: params[0].clone(),
+            default: params.get(1).cloned()
+        })];
+    }
+
+    pub fn caawimaad(interpreter: &mut Interpreter, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        if params.len() > 1 {
+            interpreter.fail(format!("NoocKhaldan: caawimaad() ugu badnaan 1 shay buu qaataa, laakiin {} shay baa la siiyay", params.len()));
+            return vec![];
+        }
+
+        if params.is_empty() {
+            let builtins: Vec<&str> = docs::BUILTINS.iter().map(|(name, _, _)| *name).collect();
+            let keywords: Vec<&str> = docs::KEYWORDS.iter().map(|(name, _)| *name).collect();
+            Self::write_out(&format!(
+                "Ku soo dhawoow caawimaadda Geel {}.\n\nQaybaha ku dhex dhisan:\n    {}\n\nErayada muhiimka ah:\n    {}\n\nKu qor caawimaad(\"magac\") si aad wax badan uga ogaato.\n",
+                env!("CARGO_PKG_VERSION"),
+                builtins.join(", "),
+                keywords.join(", ")
+            ));
+            return vec![];
+        }
+
+        let (name, text) = match &params[0] {
+            ParsedNode::Function { name, params, body, .. } if !body.is_empty() => {
+                let signature = params.iter().map(|param| match param {
+                    ParsedNode::Variable { name, value: Some(default), .. } => format!("{}={}", name, Self::repr(default)),
+                    ParsedNode::Variable { name, .. } => name.to_owned(),
+                    _ => "".to_owned()
+                }).collect::<Vec<String>>().join(", ");
+                let description = Self::docstring(body).unwrap_or("Qaybtan caawimaad ma leh.".to_owned());
+                (name.to_owned(), Some(format!("{}({})\n    {}", name, signature, description)))
             }
+            ParsedNode::Function { name, .. } | ParsedNode::Type { name } => (name.to_owned(), docs::find(name)),
+            ParsedNode::Str { val } => (Self::unquote(val), docs::find(&Self::unquote(val))),
+            other => (Self::type_name(other), docs::find(&Self::type_name(other)))
+        };
+
+        if text.is_none() {
+            Self::write_out(&format!("Lama helin caawimaad ku saabsan '{}'.\n", name));
+            return vec![];
         }
 
+        Self::write_out(&format!("Caawimaad ku saabsan {}:\n\n{}\n", name, text.unwrap()));
         return vec![];
     }
 }
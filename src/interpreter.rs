@@ -1,333 +1,1416 @@
-use crate::parser::ParsedNode;
+use crate::debugger::{self, Debugger};
+use crate::eventloop::{self, Awaitable, EventLoop, Resume};
+use crate::parser::{Clause, ParsedNode, Parser, Position, Variables};
 use crate::inbuilt::Inbuilt;
 use crate::lexer::Token;
 use meval::eval_str;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+//The variables of one running call
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Frame {
+    //Shared, so a qayb written during the call keeps seeing them after it returns
+    pub variables: Variables,
+    //Names declared caalami, which are read from and written to the globals
+    pub globals: Vec<String>,
+    //The scopes of the calls the running qayb was written in, nearest first
+    pub enclosing: Vec<Variables>,
+    //Whether it's a mardambe qayb, the only kind that can pause
+    pub asynchronous: bool
+}
+
+//What ended a tijaabi's body, put aside while its ugu-dambeyn runs: the error, the value being
+//given back, and whether a loop was being broken out of or continued
+pub type Outcome = (Option<ParsedNode>, Option<ParsedNode>, bool, bool);
 
 pub struct Interpreter {
+    pub variables: Variables,
+    pub breaking: bool,
+    pub continuing: bool,
+    pub error: Option<ParsedNode>,
+    pub returned: Option<ParsedNode>,
+    pub assertions: bool,
+    pub frames: Vec<Frame>,
+    //The line being run, which errors are reported against
+    pub line: usize,
+    pub debugger: Debugger,
+    pub events: EventLoop
 }
 
-pub static mut VARIABLE_DICT: Vec<(String, Box<ParsedNode>)> = Vec::new();
-pub static mut BREAK: bool = false;
+impl Interpreter {
+    pub fn new() -> Self {
+        Self {
+            variables: Variables::default(),
+            breaking: false,
+            continuing: false,
+            error: None,
+            returned: None,
+            assertions: true,
+            frames: vec![],
+            line: 0,
+            debugger: Debugger::default(),
+            events: EventLoop::default()
+        }
+    }
+
+    //Anything left over from a previous REPL line would stop the next one from running
+    pub fn reset(&mut self) {
+        self.error = None;
+        self.returned = None;
+        self.breaking = false;
+        self.continuing = false;
+    }
+
+    pub fn interpret(&mut self, repl: bool, parsed: Vec<ParsedNode>) {
+        //A task picking back up after a pause starts at the statement it paused in
+        let (start, mut journal) = eventloop::resume_block(self);
+        //parsed.iter().for_each(|block| {
+        for (index, block) in parsed.into_iter().enumerate().skip(start) {
+            if self.breaking || self.continuing || self.error.is_some() || self.returned.is_some() {
+                break;
+            }
+
+            let journaled = eventloop::begin(self, journal.take());
+            match block {
+                ParsedNode::FunctionCall { name, params } => {
+                    let out: Vec<ParsedNode> = self.call(name, params);
+
+                    if repl && !out.is_empty() {
+                        self.print(out[0].to_owned());
+                    }
+                },
+                ParsedNode::MethodCall { object, name, params } => {
+                    let out = self.call_method(object.as_ref().clone(), name, params);
+                    if repl && !out.is_empty() {
+                        self.print(out[0].to_owned());
+                    }
+                },
+                ParsedNode::Equation { items } => {
+                    let output = self.solve_equation(&items).0;
+                    if repl {
+                        self.print(output);
+                    }
+                },
+                ParsedNode::Variable { name, exists, add_sub, value } => {
+                    let prev = self.lookup(&name);
+                    if let (false, Some(value)) = (exists, value) {
+                        let value = *value;
+                        if prev.is_some() && add_sub != 0 {
+                            let operator = if add_sub == 1 { Token::Plus } else { Token::Minus };
+                            let output = self.augment(prev.clone().unwrap(), operator, value);
+                            self.set_variable(name.clone(), output);
+                        } else {
+                            let resolved = self.resolve(value);
+                            if self.error.is_none() {
+                                self.set_variable(name.clone(), resolved);
+                            }
+                        }
+                    }
+
+                    if let (true, true, Some(prev)) = (exists, repl, prev) {
+                        match prev {
+                            ParsedNode::Equation { items } => {
+                                let output = self.solve_equation(&items).0;
+                                self.print(output);
+                            },
+                            parsed => { self.print(parsed) }
+                        }
+                    }
+                },
+                ParsedNode::ForLoop { vars, iterable, body, asynchronous } => {
+                    if asynchronous {
+                        self.iterate_async(vars, iterable.as_ref().clone(), body);
+                    } else {
+                        self.iterate(
+                            vars.clone(),
+                            iterable.as_ref().clone(),
+                            body.clone()
+                        );
+                    }
+                },
+                ParsedNode::With { managers, body } => {
+                    //A task picking back up here doesn't enter the managers it had already entered again
+                    let previous = match eventloop::resumed(self, |record| matches!(record, Resume::With { .. })) {
+                        Some(Resume::With { bound }) => bound,
+                        _ => vec![]
+                    };
+
+                    let mut entered: Vec<ParsedNode> = vec![];
+                    let mut bound: Vec<ParsedNode> = vec![];
+                    for (index, (manager, name)) in managers.iter().enumerate() {
+                        let value = self.resolve(manager.clone());
+                        let given = match previous.get(index) {
+                            Some(given) => Some(given.clone()),
+                            None => self.enter_context(value.clone())
+                        };
+
+                        if given.is_none() {
+                            break;
+                        }
+
+                        if name.is_some() {
+                            self.set_variable(name.clone().unwrap(), given.clone().unwrap());
+                        }
+
+                        entered.push(value);
+                        bound.push(given.unwrap());
+                    }
+
+                    if entered.len() == managers.len() {
+                        self.interpret(repl, body);
+                    }
+
+                    //A task pausing hasn't left the block, so its managers stay entered.
+                    //Otherwise they're exited in reverse, whether or not the body ran
+                    if eventloop::suspended(self) {
+                        self.events.resume.push(Resume::With { bound });
+                    } else {
+                        for value in entered.into_iter().rev() {
+                            self.exit_context(value);
+                        }
+                    }
+                },
+                ParsedNode::Error { .. } => {
+                    self.raise(block);
+                },
+                ParsedNode::Function { ref name, ref body, .. } if !body.is_empty() && name != "laamda" => {
+                    let function = Inbuilt::new_function(block.clone(), self.enclosing());
+                    self.set_variable(name.clone(), function);
+                },
+                ParsedNode::Return { value } => {
+                    let value = self.resolve(value.as_ref().clone());
+                    self.returned = Some(value);
+                },
+                ParsedNode::Global { names } => {
+                    if !self.frames.is_empty() {
+                        self.frames.last_mut().unwrap().globals.extend(names);
+                    }
+                },
+                ParsedNode::Pass => { },
+                ParsedNode::Decorated { decorators, function } => {
+                    let name = match function.as_ref() {
+                        ParsedNode::Function { name, .. } => name.clone(),
+                        _ => "".to_owned()
+                    };
+
+                    let mut value = Some(Inbuilt::new_function(function.as_ref().clone(), self.enclosing()));
+                    for decorator in decorators.into_iter().rev() {
+                        let func = self.resolve(decorator);
+                        value = self.call_value(&func, vec![value.unwrap()]);
+                        if value.is_none() || self.error.is_some() {
+                            break;
+                        }
+                    }
+
+                    if let Some(value) = value {
+                        self.set_variable(name, value);
+                    }
+                },
+                ParsedNode::SetIndex { object, parts, value } => {
+                    let value = self.resolve(value.as_ref().clone());
+                    self.store(ParsedNode::Index { object, parts }, value);
+                },
+                ParsedNode::Line { line_num } => {
+                    self.line = line_num;
+                    debugger::line(self, line_num);
+                },
+                ParsedNode::Match { subject, arms } => {
+                    let arm = self.select_arm(subject.as_ref().clone(), arms);
+                    if let Some(arm) = arm {
+                        self.interpret(repl, arm);
+                    }
+                },
+                ParsedNode::Try { body, handlers, finally } => {
+                    //A task that paused in a handler or the cleanup picks back up there, not in the body
+                    let mut outcome: Option<Outcome> = None;
+                    match eventloop::resumed(self, |record| matches!(record, Resume::Handler { .. } | Resume::Finally { .. })) {
+                        Some(Resume::Handler { index }) => {
+                            self.handle(repl, handlers, Some(index));
+                        }
+                        Some(Resume::Finally { outcome: kept }) => {
+                            outcome = Some(kept);
+                        }
+                        _ => {
+                            self.interpret(repl, body);
+                            if self.error.is_some() && !eventloop::suspended(self) {
+                                self.handle(repl, handlers, None);
+                            }
+                        }
+                    }
+
+                    if !finally.is_empty() && (outcome.is_some() || !eventloop::suspended(self)) {
+                        //Whatever ended the body is put aside while the cleanup runs, and
+                        //picked back up unless the cleanup ends things its own way
+                        let outcome = outcome.unwrap_or_else(|| {
+                            let outcome = (self.error.take(), self.returned.take(), self.breaking, self.continuing);
+                            self.breaking = false;
+                            self.continuing = false;
+                            return outcome;
+                        });
+
+                        self.interpret(repl, finally);
+                        if eventloop::suspended(self) {
+                            self.events.resume.push(Resume::Finally { outcome });
+                        } else if self.error.is_none() && self.returned.is_none() && !self.breaking && !self.continuing {
+                            let (error, returned, breaking, continuing) = outcome;
+                            self.error = error;
+                            self.returned = returned;
+                            self.breaking = breaking;
+                            self.continuing = continuing;
+                        }
+                    }
+                },
+                ParsedNode::Continue => {
+                    self.continuing = true;
+                },
+                ParsedNode::Break => {
+                    self.breaking = true;
+                },
+                ParsedNode::IfChain { blocks } => {
+                    for block in blocks {
+                        if self.is_true(&block.0) {
+                            self.interpret(repl, block.1.to_owned());
+                            break;
+                        }
+                    }
+                },
+                ParsedNode::Assert { condition, message, line_num, line } => {
+                    if self.assertions && !self.is_true(&condition) {
+                        let mut error = "KhaladXaqiijin".to_owned();
+                        if let Some(message) = message {
+                            let text = self.resolve(message.as_ref().clone());
+                            error = format!("{}: {}", error, Inbuilt::display(&text));
+                        }
+
+                        self.raise(ParsedNode::Error {
+                            line_num,
+                            line,
+                            arrow: "".to_owned(),
+                            error
+                        });
+                    }
+                },
+                //Only the REPL echoes bare values, so docstrings stay quiet
+                _ => {
+                    let value = self.resolve(block.to_owned());
+                    if repl {
+                        self.print(value);
+                    }
+                }
+            }
+
+            eventloop::end(self, index, journaled);
+        }
+    }
+
+    //Puts a value where an assignment's target says, so d[a][b] = value
+    //changes d[a] and then stores that back into d
+    fn store(&mut self, target: ParsedNode, value: ParsedNode) {
+        match target {
+            ParsedNode::Variable { name, .. } => {
+                self.set_variable(name, value);
+            }
+            ParsedNode::Index { object, parts } => {
+                let container = self.resolve(object.as_ref().clone());
+                let parts = self.resolve_args(parts);
+                if container == ParsedNode::Ignore || self.error.is_some() {
+                    return;
+                }
+
+                let changed = Inbuilt::set_index(self, container, parts, value);
+                if let Some(changed) = changed {
+                    self.store(*object, changed);
+                }
+            }
+            _ => { }
+        }
+    }
+
+    //Works out `name += value` and `name -= value` as one equation
+    fn augment(&mut self, old: ParsedNode, operator: Token, value: ParsedNode) -> ParsedNode {
+        let mut all_items: Vec<Token> = Vec::new();
+        for (index, part) in vec![old, value].into_iter().enumerate() {
+            let mut tokens = match part {
+                ParsedNode::Equation { items } => items,
+                ParsedNode::Int { val } => vec![Token::Int(val)],
+                ParsedNode::Float { val } => vec![Token::Float(val)],
+                ParsedNode::Complex { real, imag } => Inbuilt::complex_tokens(real, imag),
+                _ => vec![]
+            };
+
+            if index == 1 && !tokens.is_empty() {
+                all_items.push(operator.clone());
+            }
+
+            all_items.append(&mut tokens);
+        }
+
+        return self.solve_equation(&all_items).0;
+    }
+
+    fn iterate(
+        &mut self,
+        vars: Vec<String>,
+        node: ParsedNode,
+        body: Vec<ParsedNode>
+    ) {
+        //A task that paused in the body carries on with the iterator it had
+        let (iterator, mut resuming) = match eventloop::resumed(self, |record| matches!(record, Resume::Loop { .. })) {
+            Some(Resume::Loop { iterator, count }) => {
+                eventloop::skip_to(self, count);
+                (Some(iterator), true)
+            }
+            _ => (self.get_iter(node), false)
+        };
+
+        if iterator.is_none() {
+            return;
+        }
+
+        let count = eventloop::counted(self);
+
+        let mut iterator = iterator.unwrap();
+        loop {
+            if self.breaking || self.error.is_some() || self.returned.is_some() {
+                break;
+            }
+
+            if !resuming {
+                let x = Inbuilt::next_item(self, &mut iterator);
+                if x.is_none() {
+                    break;
+                }
+
+                if !self.unpack(&vars, x.unwrap()) {
+                    break;
+                }
+            }
+
+            resuming = false;
+            self.interpret(false, body.clone());
+            if eventloop::suspended(self) {
+                self.events.resume.push(Resume::Loop { iterator, count });
+                return;
+            }
+
+            self.continuing = false;
+        }
+
+        self.breaking = false;
+    }
+
+    //mardambe kastoo awaits kawad for each item, until the iterator raises JoojiKalaNoqnoqodka
+    fn iterate_async(
+        &mut self,
+        vars: Vec<String>,
+        node: ParsedNode,
+        body: Vec<ParsedNode>
+    ) {
+        //A task picks back up either waiting on the item it was waiting on, or in the body
+        let (iterator, mut step, mut resuming) = match eventloop::resumed(self, |record| matches!(record, Resume::AsyncLoop { .. })) {
+            Some(Resume::AsyncLoop { iterator, step, count }) => {
+                eventloop::skip_to(self, count);
+                (iterator, step, true)
+            }
+            _ => {
+                let value = self.resolve(node);
+                if self.error.is_some() {
+                    return;
+                }
+
+                let iterator = Inbuilt::async_iter(self, value);
+                if iterator.is_none() {
+                    return;
+                }
+
+                (iterator.unwrap(), None, false)
+            }
+        };
+
+        let count = eventloop::counted(self);
+
+        loop {
+            if self.breaking || self.error.is_some() || self.returned.is_some() {
+                break;
+            }
+
+            if !resuming || step.is_some() {
+                let waiting = step.take().unwrap_or_else(|| eventloop::coroutine(Awaitable::Next { iterator: iterator.clone(), default: None }));
+                let x = eventloop::wait(self, waiting.clone());
+                if eventloop::suspended(self) {
+                    self.events.resume.push(Resume::AsyncLoop { iterator, step: Some(waiting), count });
+                    return;
+                }
+
+                if self.error_kind() == "JoojiKalaNoqnoqodka" {
+                    self.error = None;
+                    break;
+                }
+
+                if x.is_none() || !self.unpack(&vars, x.unwrap()) {
+                    break;
+                }
+            }
+
+            resuming = false;
+            self.interpret(false, body.clone());
+            if eventloop::suspended(self) {
+                self.events.resume.push(Resume::AsyncLoop { iterator, step: None, count });
+                return;
+            }
+
+            self.continuing = false;
+        }
+
+        self.breaking = false;
+    }
+
+    fn comprehend(
+        &mut self,
+        clauses: &[Clause],
+        depth: usize,
+        element: &ParsedNode,
+        value: &Option<Box<ParsedNode>>,
+        items: &mut Vec<ParsedNode>
+    ) {
+        //Each clause loops inside the one before it, and the innermost adds an item
+        if depth == clauses.len() {
+            let item = self.resolve(element.clone());
+            if value.is_some() {
+                let value = self.resolve(value.clone().unwrap().as_ref().clone());
+                items.push(ParsedNode::Tuple { items: vec![item, value] });
+            } else {
+                items.push(item);
+            }
+
+            return;
+        }
+
+        let (vars, iterable, filters) = &clauses[depth];
+        let iterator = self.get_iter(iterable.clone());
+        if iterator.is_none() {
+            return;
+        }
+
+        let mut iterator = iterator.unwrap();
+        loop {
+            if self.error.is_some() {
+                break;
+            }
+
+            let x = Inbuilt::next_item(self, &mut iterator);
+            if x.is_none() || !self.unpack(vars, x.unwrap()) {
+                break;
+            }
+
+            if filters.iter().all(|filter| self.is_true(filter)) {
+                self.comprehend(clauses, depth + 1, element, value, items);
+            }
+        }
+    }
+
+    pub fn unpack(&mut self, vars: &[String], value: ParsedNode) -> bool {
+        if vars.len() == 1 {
+            self.set_variable(vars[0].clone(), value);
+            return true;
+        }
+
+        let items = Inbuilt::items(self, value.clone());
+        if items.is_none() {
+            self.fail(format!("NoocKhaldan: shayga '{}' lama kala furfuri karo", Inbuilt::type_name(&value)));
+            return false;
+        }
+
+        let items = items.unwrap();
+        if items.len() != vars.len() {
+            self.fail(format!("KhaladQiimeyn: {} magac baa la filayay, laakiin {} shay baa la helay", vars.len(), items.len()));
+            return false;
+        }
+
+        for (name, item) in vars.iter().zip(items) {
+            self.set_variable(name.clone(), item);
+        }
+
+        return true;
+    }
+
+    pub fn is_true(&mut self, conditions: &Vec<Vec<ParsedNode>>) -> bool {
+        for or_block in conditions {
+            let mut or_true = true;
+            for cond in or_block {
+                match cond {
+                    ParsedNode::Comparison { operator, left, right } => {
+                        let is_true = self.compare(
+                            operator.clone(),
+                            left.clone(),
+                            right.clone()
+                        );
+                        if !is_true {
+                            or_true = false;
+                            break;
+                        }
+                    },
+                    ParsedNode::Bool { val } if !val => {
+                        or_true = false;
+                        break;
+                    },
+                    ParsedNode::Null => {
+                        or_true = false;
+                        break;
+                    },
+                    _ => { }
+                }
+            }
+
+            if or_true {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    //Every error goes through here, and only the first one counts, since
+    //anything after it is fallout from the same problem
+    pub fn raise(&mut self, error: ParsedNode) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    pub fn fail(&mut self, error: String) {
+        let line = self.debugger.source.get(self.line.wrapping_sub(1)).cloned().unwrap_or_default();
+        self.raise(ParsedNode::Error {
+            line_num: self.line,
+            line: line.trim().to_owned(),
+            arrow: "".to_owned(),
+            error
+        });
+    }
+
+    //Prints an error nothing caught, an empty one is how bax stops the program quietly
+    pub fn report(&mut self) {
+        match &self.error {
+            Some(ParsedNode::Error { line_num, line, arrow, error }) if !error.is_empty() => {
+                if *line_num > 0 {
+                    Inbuilt::write_out(&format!("Sadarka {}:\n    {}\n", line_num, line));
+                }
+
+                if !arrow.is_empty() {
+                    Inbuilt::write_out(&format!("    {}\n", arrow));
+                }
+
+                Inbuilt::write_out(&format!("{}\n", error));
+            }
+            _ => { }
+        }
+    }
+
+    //The kind is the name an error message starts with, e.g. NoocKhaldan
+    pub fn error_kind(&mut self) -> String {
+        if let Some(ParsedNode::Error { error, .. }) = &self.error {
+            return error.split(':').next().unwrap_or("").trim().to_owned();
+        }
+
+        return "".to_owned();
+    }
+
+    //resumed is the handler a task paused in, which has already been given the error
+    pub fn handle(
+        &mut self,
+        repl: bool,
+        handlers: Vec<(Vec<String>, Option<String>, Vec<ParsedNode>)>,
+        resumed: Option<usize>
+    ) {
+        let kind = self.error_kind();
+        for (index, (kinds, name, body)) in handlers.into_iter().enumerate() {
+            if resumed.is_some() && resumed != Some(index) {
+                continue;
+            }
+
+            if resumed.is_none() {
+                if !kinds.is_empty() && !kinds.iter().any(|parent| Inbuilt::catches(parent, &kind)) {
+                    continue;
+                }
+
+                let error = self.error.take();
+                if let Some(name) = name {
+                    let message = match error {
+                        Some(ParsedNode::Error { error, .. }) => error,
+                        _ => "".to_owned()
+                    };
+
+                    self.set_variable(name, ParsedNode::Str { val: format!("\"{}\"", message) });
+                }
+            }
+
+            self.interpret(repl, body);
+            if eventloop::suspended(self) {
+                self.events.resume.push(Resume::Handler { index });
+            }
+
+            return;
+        }
+    }
+
+    pub fn select_arm(
+        &mut self,
+        subject: ParsedNode,
+        arms: Vec<(ParsedNode, Vec<Vec<ParsedNode>>, Vec<ParsedNode>)>
+    ) -> Option<Vec<ParsedNode>> {
+        let value = self.resolve(subject);
+        for (pattern, guard, body) in arms {
+            let mut bindings: Vec<(String, ParsedNode)> = vec![];
+            if !self.match_pattern(&pattern, &value, &mut bindings) {
+                continue;
+            }
+
+            for (name, bound) in bindings {
+                self.set_variable(name, bound);
+            }
+
+            if guard.is_empty() || self.is_true(&guard) {
+                return Some(body);
+            }
+        }
+
+        return None;
+    }
+
+    pub fn match_pattern(
+        &mut self,
+        pattern: &ParsedNode,
+        value: &ParsedNode,
+        bindings: &mut Vec<(String, ParsedNode)>
+    ) -> bool {
+        match pattern {
+            ParsedNode::Ignore => {
+                return true;
+            }
+            ParsedNode::Variable { name, exists: false, .. } => {
+                bindings.push((name.to_owned(), value.clone()));
+                return true;
+            }
+            ParsedNode::FunctionCall { name, params } if params.is_empty() && Inbuilt::is_type_name(name) => {
+                return &Inbuilt::type_name(value) == name;
+            }
+            ParsedNode::List { items: patterns } | ParsedNode::Tuple { items: patterns } => {
+                match value {
+                    ParsedNode::List { items } | ParsedNode::Tuple { items } => {
+                        if items.len() != patterns.len() {
+                            return false;
+                        }
+
+                        for (sub_pattern, item) in patterns.iter().zip(items.iter()) {
+                            if !self.match_pattern(sub_pattern, item, bindings) {
+                                return false;
+                            }
+                        }
+
+                        return true;
+                    }
+                    _ => {
+                        return false;
+                    }
+                }
+            }
+            ParsedNode::FunctionCall { .. } => {
+                //Calls such as faraq(1, 10) match anything they contain
+                let resolved = self.resolve(pattern.clone());
+                match resolved {
+                    ParsedNode::List { items } | ParsedNode::Tuple { items } | ParsedNode::Set { items } => {
+                        return items.iter().any(|item| self.values_equal(item, value));
+                    }
+                    other => {
+                        return self.values_equal(&other, value);
+                    }
+                }
+            }
+            _ => {
+                let resolved = self.resolve(pattern.clone());
+                return self.values_equal(&resolved, value);
+            }
+        }
+    }
+
+    pub fn values_equal(&mut self, left: &ParsedNode, right: &ParsedNode) -> bool {
+        return Inbuilt::equal(left, right);
+    }
+
+    pub fn is_expression(&mut self, node: &ParsedNode) -> bool {
+        match node {
+            ParsedNode::Int { .. } | ParsedNode::Float { .. } | ParsedNode::Str { .. } |
+            ParsedNode::Bool { .. } | ParsedNode::Null | ParsedNode::List { .. } |
+            ParsedNode::Tuple { .. } | ParsedNode::Dict { .. } | ParsedNode::Set { .. } |
+            ParsedNode::Equation { .. } | ParsedNode::FunctionCall { .. } |
+            ParsedNode::MethodCall { .. } | ParsedNode::Call { .. } | ParsedNode::Match { .. } | ParsedNode::Bytes { .. } |
+            ParsedNode::Index { .. } | ParsedNode::Attribute { .. } | ParsedNode::Complex { .. } |
+            ParsedNode::Comprehension { .. } | ParsedNode::Conditional { .. } | ParsedNode::Variable { exists: true, .. } => {
+                return true;
+            }
+            ParsedNode::Lambda { .. } | ParsedNode::Await { .. } => {
+                return true;
+            }
+            _ => { }
+        }
+
+        return false;
+    }
+
+    pub fn block_value(&mut self, body: Vec<ParsedNode>) -> ParsedNode {
+        let statements: Vec<ParsedNode> = body.into_iter().filter(|node| node != &ParsedNode::Ignore).collect();
+        if statements.is_empty() {
+            return ParsedNode::Null;
+        }
+
+        let last = statements.len() - 1;
+        if !self.is_expression(&statements[last]) {
+            self.interpret(false, statements);
+            return ParsedNode::Null;
+        }
+
+        //The last expression is a statement like the others, and a task that paused in it picks back up there
+        if !eventloop::paused_at(self, last) {
+            self.interpret(false, statements[..last].to_vec());
+            if self.error.is_some() {
+                return ParsedNode::Null;
+            }
+        }
+
+        let journal = eventloop::resume_block(self).1;
+        let journaled = eventloop::begin(self, journal);
+        let value = self.resolve(statements[last].clone());
+        eventloop::end(self, last, journaled);
+        return value;
+    }
+
+    pub fn set_variable(&mut self, name: String, value: ParsedNode) {
+        if !self.frames.is_empty() {
+            let frame = self.frames.last_mut().unwrap();
+            if !frame.globals.contains(&name) {
+                let mut variables = frame.variables.0.borrow_mut();
+                let pos = variables.iter().position(|(v, _)| v == &name);
+                if let Some(pos) = pos {
+                    *variables[pos].1 = value;
+                } else {
+                    variables.push((name, Box::new(value)));
+                }
+
+                return;
+            }
+        }
+
+        self.set_global(name, value);
+    }
+
+    pub fn set_global(&mut self, name: String, value: ParsedNode) {
+        let mut variables = self.variables.0.borrow_mut();
+        let pos = variables.iter().position(
+            |(v, _)| v == &name
+        );
+
+        if let Some(pos) = pos {
+            *variables[pos].1 = value;
+        } else {
+            variables.push(
+                (name, Box::new(value))
+            );
+        }
+    }
+
+    //The variables assignments currently go to, the running call's or the globals
+    pub fn scope(&self) -> Vec<(String, ParsedNode)> {
+        if !self.frames.is_empty() {
+            return self.frames[self.frames.len() - 1].variables.0.borrow().iter().map(|(name, value)| (name.clone(), value.as_ref().clone())).collect();
+        }
+
+        return self.variables.0.borrow().iter().map(|(name, value)| (name.clone(), value.as_ref().clone())).collect();
+    }
+
+    //The scopes a qayb written here can read besides its own, nearest first
+    pub fn enclosing(&self) -> Vec<Variables> {
+        match self.frames.last() {
+            Some(frame) => {
+                let mut scopes = vec![frame.variables.clone()];
+                scopes.extend(frame.enclosing.iter().cloned());
+                return scopes;
+            }
+            None => {
+                return vec![];
+            }
+        }
+    }
+
+    pub fn remove_variable(&mut self, name: &str) {
+        if !self.frames.is_empty() {
+            let frame = self.frames.last_mut().unwrap();
+            if !frame.globals.iter().any(|global| global == name) {
+                frame.variables.0.borrow_mut().retain(|(v, _)| v != name);
+                return;
+            }
+        }
+
+        self.variables.0.borrow_mut().retain(|(v, _)| v != name);
+    }
+
+    pub fn enter_context(&mut self, value: ParsedNode) -> Option<ParsedNode> {
+        if let ParsedNode::File { .. } = value {
+            return Some(value);
+        }
+
+        //Anything carrying gal and bax hooks, e.g. set with sifobadal, is a manager too
+        let enter = Inbuilt::attribute(&value, "gal");
+        if let (Some(enter), Some(_)) = (enter, Inbuilt::attribute(&value, "bax")) {
+            let bound = self.call_value(&enter, vec![]);
+            if self.error.is_some() {
+                return None;
+            }
+
+            return bound;
+        }
+
+        self.fail(format!("NoocKhaldan: shayga '{}' isticmaal kuma shaqeeyo", Inbuilt::type_name(&value)));
+        return None;
+    }
+
+    pub fn exit_context(&mut self, value: ParsedNode) {
+        if let ParsedNode::File { handle, .. } = value {
+            handle.0.borrow_mut().take();
+            return;
+        }
+
+        let exit = Inbuilt::attribute(&value, "bax");
+        if exit.is_none() {
+            return;
+        }
+
+        //Like ugu-dambeyn, whatever ended the body is put aside while the hook runs
+        let error = self.error.take();
+        let returned = self.returned.take();
+        let breaking = self.breaking;
+        let continuing = self.continuing;
+        self.breaking = false;
+        self.continuing = false;
+
+        //The hook is told the error's kind and message, or waxba twice if the body finished
+        let args = match &error {
+            Some(ParsedNode::Error { error: message, .. }) => {
+                let kind = message.split(':').next().unwrap_or("").trim().to_owned();
+                vec![
+                    ParsedNode::Str { val: format!("\"{}\"", kind) },
+                    ParsedNode::Str { val: format!("\"{}\"", message) }
+                ]
+            }
+            _ => vec![ParsedNode::Null, ParsedNode::Null]
+        };
+
+        let out = self.call_value(&exit.unwrap(), args);
+        if self.error.is_some() {
+            return;
+        }
+
+        //A true answer means the hook dealt with the error, so it goes no further
+        let handled = error.is_some() && out.is_some() && self.truthy(&out.unwrap());
+        if !handled {
+            self.error = error;
+        }
+
+        self.returned = returned;
+        self.breaking = breaking;
+        self.continuing = continuing;
+    }
+
+    //Whether a value counts as true, which a bool hook set with sifobadal answers for itself
+    pub fn truthy(&mut self, value: &ParsedNode) -> bool {
+        let hook = Inbuilt::attribute(value, "bool");
+        if hook.is_none() {
+            return Inbuilt::truthy(value);
+        }
+
+        let out = self.call_value(&hook.unwrap(), vec![]);
+        match out {
+            Some(ParsedNode::Bool { val }) => {
+                return val;
+            }
+            Some(other) if self.error.is_none() => {
+                self.fail(format!("NoocKhaldan: bool waa inuu celiyaa Run ama Been, ma aha '{}'", Inbuilt::type_name(&other)));
+            }
+            _ => { }
+        }
+
+        return false;
+    }
+
+    //A value as qor prints it, which a qoraal hook set with sifobadal answers for itself
+    pub fn text(&mut self, value: &ParsedNode) -> Option<String> {
+        let hook = Inbuilt::attribute(value, "qoraal");
+        if hook.is_none() {
+            return Some(Inbuilt::display(value));
+        }
+
+        let out = self.call_value(&hook.unwrap(), vec![]);
+        match out {
+            Some(ParsedNode::Str { val }) => {
+                return Some(Inbuilt::unquote(&val));
+            }
+            Some(other) if self.error.is_none() => {
+                self.fail(format!("NoocKhaldan: qoraal waa inuu celiyaa qoraal, ma aha '{}'", Inbuilt::type_name(&other)));
+            }
+            _ => { }
+        }
+
+        return None;
+    }
+
+    pub fn get_iter(&mut self, node: ParsedNode) -> Option<ParsedNode> {
+        let value = self.resolve(node);
+        if value == ParsedNode::Ignore {
+            return None;
+        }
+
+        let iterator = Inbuilt::iter(self, value.clone());
+        if iterator.is_none() {
+            self.fail(format!("NoocKhaldan: shayga '{}' lama celcelin karo", Inbuilt::type_name(&value)));
+        }
+
+        return iterator;
+    }
+
+    pub fn call(&mut self, name: String, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let mut inbuilt = Inbuilt::new();
+        let defined = self.lookup(&name);
+        if defined.is_none() && !inbuilt.has_method(&name) {
+            self.fail(format!("KhaladMagceed: magaca '{}' lama qeexin", name));
+            return vec![];
+        }
+
+        let mut args: Vec<ParsedNode> = params;
+        //tir needs the names themselves rather than their values
+        if &name != "tir" {
+            args = self.resolve_args(args);
+        }
+
+        if self.error.is_some() {
+            return vec![];
+        }
+
+        if let Some(defined) = defined {
+            let out = self.call_value(&defined, args);
+            return out.into_iter().collect();
+        }
+
+        return inbuilt.get_method(name)(self, args);
+    }
 
-impl Interpreter {
-    pub fn new() -> Self {
-        Self {}
+    pub fn resolve_args(&mut self, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        return params.into_iter().map(|arg| match arg {
+            ParsedNode::Variable { name, exists: false, add_sub, value: Some(value) } => {
+                ParsedNode::Variable {
+                    name,
+                    exists: false,
+                    add_sub,
+                    value: Some(Box::new(self.resolve(value.as_ref().clone())))
+                }
+            }
+            arg => self.resolve(arg)
+        }).collect();
     }
 
-    pub fn interpret(&mut self, repl: bool, parsed: Vec<ParsedNode>) {
-        //parsed.iter().for_each(|block| {
-        for block in parsed {
-            match block {
-                ParsedNode::FunctionCall { name, params } => {
-                    let out: Vec<ParsedNode> = Inbuilt::new().get_method(name.to_owned())(params.to_vec());
+    pub fn call_method(&mut self, object: ParsedNode, name: String, params: Vec<ParsedNode>) -> Vec<ParsedNode> {
+        let value = self.resolve(object);
+        let args = self.resolve_args(params);
+        if value == ParsedNode::Ignore || self.error.is_some() {
+            return vec![];
+        }
 
-                    if repl && out.len() > 0 {
-                        match (&out[0]).to_owned() {
-                            ParsedNode::Str { val } => {
-                                print!("{:?}\r\n", val);
-                            },
-                            _ => {
-                                self.print((&out[0]).to_owned())
-                            }
-                        }
-                    }
-                },
-                ParsedNode::Bool { val } => {
-                    if val {
-                        print!("Run\r\n");
-                    } else {
-                        print!("Been\r\n");
-                    }
-                },
-                ParsedNode::Equation { items } => {
-                    let output = self.solve_equation(&items).0;
-                    self.print(output);
-                },
-                ParsedNode::Variable { name, exists, add_sub, value } => {
-                    unsafe {
-                        let prev = VARIABLE_DICT.iter().position(|(v, _)| v == &name);
-                        if !exists && value.is_some() {
-                            if prev.is_some() {
-                                let pos = prev.unwrap();
-
-                                match add_sub {
-                                    0 => {
-                                        VARIABLE_DICT[pos].1 = value.clone().unwrap();
-                                    }
-                                    //TODO: Put this in a method
-                                    1 => {
-                                        let mut all_items: Vec<Token> = Vec::new();
-                                        let old = (&VARIABLE_DICT[pos].1).to_owned();
-                                        match old.as_ref() {
-                                            ParsedNode::Equation { items } => {
-                                                all_items = items.to_owned();
-                                            }
-                                            ParsedNode::Int { val } | ParsedNode::Float { val } => {
-                                                all_items.push(Token::Float(val.to_owned()));
-                                            }
-                                            _ => { }
-                                        }
-
-                                        match value.clone().unwrap().as_ref().clone() {
-                                            ParsedNode::Equation { items } => {
-                                                all_items.push(Token::Plus);
-                                                all_items.append(&mut items.to_owned());
-                                            }
-                                            ParsedNode::Int { val } | ParsedNode::Float { val } => {
-                                                all_items.push(Token::Plus);
-                                                all_items.push(Token::Float(val.to_owned()));
-                                            }
-                                            _ => { }
-                                        }
-
-                                        let output = self.solve_equation(&all_items);
-                                        VARIABLE_DICT[pos].1 = Box::new(output.0);
-                                    }
-                                    2 => {
-                                        let mut all_items: Vec<Token> = Vec::new();
-                                        let old = (&VARIABLE_DICT[pos].1).to_owned();
-                                        match old.as_ref() {
-                                            ParsedNode::Equation { items } => {
-                                                all_items = items.to_owned();
-                                            }
-                                            ParsedNode::Int { val } | ParsedNode::Float { val } => {
-                                                all_items.push(Token::Float(val.to_owned()));
-                                            }
-                                            _ => { }
-                                        }
-
-                                        match value.clone().unwrap().as_ref().clone() {
-                                            ParsedNode::Equation { items } => {
-                                                all_items.push(Token::Minus);
-                                                all_items.append(&mut items.to_owned());
-                                            }
-                                            ParsedNode::Int { val } | ParsedNode::Float { val } => {
-                                                all_items.push(Token::Minus);
-                                                all_items.push(Token::Float(val.to_owned()));
-                                            }
-                                            _ => { }
-                                        }
-
-                                        let output = self.solve_equation(&all_items);
-                                        VARIABLE_DICT[pos].1 = Box::new(output.0);
-                                    }
-                                    _ => { }
-                                }
-                            } else {
-                                VARIABLE_DICT.push((name.to_owned(), value.clone().unwrap()));
-                            }
-                        }
+        return Inbuilt::call_method(self, value, &name, args);
+    }
 
-                        if exists {
-                            if prev.is_some() {
-                                let pos = prev.unwrap();
-                                let parsed = VARIABLE_DICT[pos].1.as_ref().clone();
-
-                                match parsed {
-                                    ParsedNode::Equation { items } => {
-                                        let output = self.solve_equation(&items).0;
-                                        self.print(output);
-                                    },
-                                    ParsedNode::Bool { val } => {
-                                        if val {
-                                            print!("Run\r\n");
-                                        } else {
-                                            print!("Been\r\n");
-                                        }
-                                    },
-                                    _ => { self.print(parsed) }
-                                }
-                            }
-                        }
-                    }
-                },
-                ParsedNode::ForLoop { var, iterable, body } => {
-                    match iterable.as_ref().clone() {
-                        ParsedNode::FunctionCall { name, params } => {
-                            let out: Vec<ParsedNode> = Inbuilt::new().get_method(name.to_owned())(params.to_vec());
-                            self.iterate(
-                                var.clone(),
-                                (&out[0]).to_owned(),
-                                body.clone());
-                        }
-                        node => {
-                            self.iterate(
-                                var.clone(),
-                                node,
-                                body.clone()
-                            );
-                        }
-                    }
-                },
-                ParsedNode::Continue => {
-                    break;
-                },
-                ParsedNode::Break => {
-                    unsafe {
-                        BREAK = true;
-                        break;
-                    }
-                },
-                ParsedNode::IfChain { blocks } => {
-                    for block in blocks {
-                        let conditions = &block.0;
-
-                        let mut any = false;
-                        for or_block in conditions {
-                            let mut or_true = true;
-                            for cond in or_block {
-                                match cond {
-                                    ParsedNode::Comparison { operator, left, right } => {
-                                        let is_true = self.compare(
-                                            operator.clone(),
-                                            left.clone(),
-                                            right.clone()
-                                        );
-                                        if !is_true {
-                                            or_true = false;
-                                            break;
-                                        }
-                                    },
-                                    ParsedNode::Bool { val } => {
-                                        if !val {
-                                            or_true = false;
-                                            break;
-                                        }
-                                    },
-                                    ParsedNode::Null => {
-                                        or_true = false;
-                                        break;
-                                    },
-                                    _ => { }
-                                }
-                            }
+    pub fn lookup(&mut self, name: &str) -> Option<ParsedNode> {
+        //A variable read before a task paused keeps the value it had, whatever else ran meanwhile
+        let slot = match eventloop::recall(self) {
+            Ok(value) => {
+                return value;
+            }
+            Err(slot) => slot
+        };
 
-                            if or_true {
-                                any = true;
-                                break;
-                            }
-                        }
+        let value = self.find(name);
+        eventloop::note(self, slot, value.as_ref());
+        return value;
+    }
 
-                        if any {
-                            self.interpret(repl, (&block.1).to_owned());
-                            break;
-                        }
+    fn find(&self, name: &str) -> Option<ParsedNode> {
+        if let Some(frame) = self.frames.last() {
+            if !frame.globals.iter().any(|global| global == name) {
+                for scope in std::iter::once(&frame.variables).chain(frame.enclosing.iter()) {
+                    let found = scope.0.borrow().iter().find(|(v, _)| v == name).map(|(_, value)| value.as_ref().clone());
+                    if found.is_some() {
+                        return found;
                     }
-                },
-                _ => { self.print(block.to_owned()) }
+                }
             }
         }
-    }
 
-    fn iterate(
-        &mut self,
-        var: String,
-        node: ParsedNode,
-        body: Vec<ParsedNode>
-    ) {
-        match node {
-            ParsedNode::List { items } => {
-                for x in items {
-                    unsafe {
-                        if BREAK {
-                            break;
-                        }
+        let variables = self.variables.0.borrow();
+        let pos = variables.iter().position(|(v, _)| v == name);
+        if let Some(pos) = pos {
+            return Some(variables[pos].1.as_ref().clone());
+        }
 
-                        let pos = VARIABLE_DICT.iter().position(
-                            |(v, _)| v == &var
-                        );
+        return None;
+    }
 
-                        if pos.is_some() {
-                            VARIABLE_DICT[pos.unwrap()].1 = Box::new(x.clone());
-                        } else {
-                            VARIABLE_DICT.push(
-                                (var.clone(), Box::new(x.clone()))
-                            );
-                        }
-                    }
-                    self.interpret(false, body.clone());
+    pub fn call_value(&mut self, func: &ParsedNode, args: Vec<ParsedNode>) -> Option<ParsedNode> {
+        match func {
+            ParsedNode::Function { asynchronous: true, .. } => {
+                return Some(eventloop::coroutine(Awaitable::Call { function: func.clone(), args }));
+            }
+            ParsedNode::Function { name, params, body, closure, .. } => {
+                if body.is_empty() {
+                    let out = Inbuilt::new().get_method(name.to_owned())(self, args);
+                    return Some(out.first().cloned().unwrap_or(ParsedNode::Null));
                 }
+
+                return self.call_function(name, params, body, closure, args, false);
+            }
+            ParsedNode::Type { name } => {
+                //A type makes new values through its conversion built-in, which not every type has, e.g. qayb
+                if !Inbuilt::new().has_method(name) {
+                    self.fail(format!("NoocKhaldan: shay cusub oo nooca '{}' ah lama samayn karo", name));
+                    return None;
+                }
+
+                let out = Inbuilt::new().get_method(name.to_owned())(self, args);
+                return Some(out.first().cloned().unwrap_or(ParsedNode::Null));
+            }
+            _ => {
+                self.fail(format!("NoocKhaldan: shayga '{}' lama wici karo", Inbuilt::type_name(func)));
             }
-            _ => { }
         }
+
+        return None;
     }
 
-    pub fn compare(
+    pub fn call_function(
         &mut self,
-        operator: Token,
-        left: Box<Vec<ParsedNode>>,
-        right: Box<Vec<ParsedNode>>
-        ) -> bool {
-        let mut left_solved: f64 = 0.0;
-        let mut right_solved: f64 = 0.0;
+        name: &str,
+        params: &[ParsedNode],
+        body: &[ParsedNode],
+        closure: &[Variables],
+        arguments: Vec<ParsedNode>,
+        asynchronous: bool
+    ) -> Option<ParsedNode> {
+        let names: Vec<String> = params.iter().map(|param| match param {
+            ParsedNode::Variable { name, .. } => name.to_owned(),
+            _ => "".to_owned()
+        }).collect();
 
-        for l in left.as_ref().clone() {
-            let mut parsed_l = l.clone();
-            match l {
-                ParsedNode::Variable { name, .. } => {
-                    unsafe {
-                        let pos = VARIABLE_DICT.iter().position(|(v, _)| v == &name);
-                        if pos.is_some() {
-                            let variable = &VARIABLE_DICT[pos.unwrap()].1;
-                            parsed_l = variable.as_ref().clone();
-                        }
-                    }
+        let (args, kwargs) = Inbuilt::split_kwargs(arguments);
+        let mut values: Vec<Option<ParsedNode>> = names.iter().map(|_| None).collect();
+        for (index, arg) in args.iter().enumerate() {
+            if index < values.len() {
+                values[index] = Some(arg.clone());
+            }
+        }
+
+        for (key, value) in kwargs {
+            let pos = names.iter().position(|param| param == &key);
+            if pos.is_none() {
+                self.fail(format!("NoocKhaldan: {}() ma yaqaan shayga '{}'", name, key));
+                return None;
+            }
+
+            values[pos.unwrap()] = Some(value);
+        }
+
+        //Defaults come from params written as name=value
+        for (index, param) in params.iter().enumerate() {
+            match param {
+                ParsedNode::Variable { value: Some(default), .. } if values[index].is_none() => {
+                    values[index] = Some(self.resolve(default.as_ref().clone()));
                 }
                 _ => { }
             }
+        }
+
+        if args.len() > names.len() || values.iter().any(|value| value.is_none()) {
+            self.fail(format!("NoocKhaldan: {}() {} shay buu qaataa, laakiin {} shay baa la siiyay", name, names.len(), args.len()));
+            return None;
+        }
+
+        //A task picking back up in the call gets back the variables it had, the arguments are still
+        //bound first so that the defaults are worked out in the same order as before
+        let variables = names.into_iter().zip(values).map(|(name, value)| (name, Box::new(value.unwrap()))).collect();
+        let (frame, line) = match eventloop::resumed(self, |record| matches!(record, Resume::Call { .. })) {
+            Some(Resume::Call { frame, line }) => (frame, line),
+            _ => (Frame {
+                variables: Variables(Rc::new(RefCell::new(variables))),
+                globals: vec![],
+                enclosing: closure.to_vec(),
+                asynchronous
+            }, self.line)
+        };
+
+        self.frames.push(frame);
+        self.debugger.enter();
+        let result = if name == "laamda" {
+            self.block_value(body.to_vec())
+        } else {
+            self.interpret(false, body.to_vec());
+            self.returned.take().unwrap_or(ParsedNode::Null)
+        };
+
+        self.debugger.leave();
+        let frame = self.frames.pop().unwrap();
+        self.line = line;
+        if eventloop::suspended(self) {
+            self.events.resume.push(Resume::Call { frame, line });
+            return None;
+        }
+
+        return Some(result);
+    }
+
+    pub fn resolve(&mut self, node: ParsedNode) -> ParsedNode {
+        let slot = match eventloop::recall(self) {
+            Ok(value) => {
+                return value.unwrap_or(ParsedNode::Null);
+            }
+            Err(slot) => slot
+        };
 
-            match parsed_l {
-                ParsedNode::Int { val } | ParsedNode::Float { val} => {
-                    left_solved += val.iter().collect::<String>().parse::<f64>().unwrap();
+        let value = self.evaluate(node);
+        eventloop::note(self, slot, Some(&value));
+        return value;
+    }
+
+    fn evaluate(&mut self, node: ParsedNode) -> ParsedNode {
+        match node {
+            ParsedNode::Variable { name, exists: true, .. } => {
+                let value = self.lookup(&name);
+                if let Some(value) = value {
+                    return value;
                 }
-                ParsedNode::Equation { items } => {
-                    left_solved += self.solve_equation(&items).1;
+
+                self.fail(format!("KhaladMagceed: magaca '{}' lama qeexin", name));
+                return ParsedNode::Ignore;
+            }
+            ParsedNode::Equation { items } => {
+                return self.solve_equation(&items).0;
+            }
+            ParsedNode::Lambda { function } => {
+                let closure = self.enclosing();
+                return Inbuilt::new_function(*function, closure);
+            }
+            ParsedNode::Await { value } => {
+                let value = self.resolve(*value);
+                if self.error.is_some() {
+                    return ParsedNode::Null;
                 }
-                _ => { }
+
+                return eventloop::wait(self, value).unwrap_or(ParsedNode::Null);
             }
-        }
+            ParsedNode::FunctionCall { name, params } => {
+                let out: Vec<ParsedNode> = self.call(name, params);
+                if !out.is_empty() {
+                    return self.resolve(out[0].to_owned());
+                }
 
-        for r in right.as_ref().clone() {
-            let mut parsed_r = r.clone();
-            match r {
-                ParsedNode::Variable { name, .. } => {
-                    unsafe {
-                        let pos = VARIABLE_DICT.iter().position(|(v, _)| v == &name);
-                        if pos.is_some() {
-                            let variable = &VARIABLE_DICT[pos.unwrap()].1;
-                            parsed_r = variable.as_ref().clone();
-                        }
-                    }
+                return ParsedNode::Null;
+            }
+            ParsedNode::MethodCall { object, name, params } => {
+                let out = self.call_method(object.as_ref().clone(), name, params);
+                if !out.is_empty() {
+                    return out[0].clone();
                 }
-                _ => { }
+
+                return ParsedNode::Null;
+            }
+            ParsedNode::Call { function, params } => {
+                let value = self.resolve(*function);
+                let args = self.resolve_args(params);
+                if value == ParsedNode::Ignore || self.error.is_some() {
+                    return ParsedNode::Null;
+                }
+
+                return self.call_value(&value, args).unwrap_or(ParsedNode::Null);
             }
+            ParsedNode::Attribute { object, name } => {
+                let value = self.resolve(object.as_ref().clone());
+                if value == ParsedNode::Ignore || self.error.is_some() {
+                    return ParsedNode::Null;
+                }
 
-            match parsed_r {
-                ParsedNode::Int { val } | ParsedNode::Float { val} => {
-                    right_solved += val.iter().collect::<String>().parse::<f64>().unwrap();
+                let attribute = Inbuilt::attribute(&value, &name);
+                if attribute.is_none() {
+                    self.fail(format!("KhaladSifeed: shayga '{}' ma laha sifada '{}'", Inbuilt::type_name(&value), name));
+                    return ParsedNode::Null;
                 }
-                ParsedNode::Equation { items } => {
-                    right_solved += self.solve_equation(&items).1;
+
+                return attribute.unwrap();
+            }
+            ParsedNode::Index { object, parts } => {
+                let value = self.resolve(object.as_ref().clone());
+                let parts = self.resolve_args(parts);
+                if value == ParsedNode::Ignore || self.error.is_some() {
+                    return ParsedNode::Null;
                 }
-                _ => { }
+
+                return Inbuilt::index(self, value, parts).unwrap_or(ParsedNode::Null);
+            }
+            ParsedNode::Conditional { condition, value, otherwise } => {
+                if self.is_true(&condition) {
+                    return self.resolve(value.as_ref().clone());
+                }
+
+                return self.resolve(otherwise.as_ref().clone());
+            }
+            ParsedNode::Match { subject, arms } => {
+                let arm = self.select_arm(subject.as_ref().clone(), arms);
+                if let Some(arm) = arm {
+                    return self.block_value(arm);
+                }
+
+                return ParsedNode::Null;
+            }
+            ParsedNode::Comprehension { kind, element, value, clauses } => {
+                //Unlike a kastoo loop, the loop names don't outlive the comprehension
+                let names: Vec<String> = clauses.iter().flat_map(|clause| clause.0.clone()).collect();
+                let saved: Vec<Option<ParsedNode>> = names.iter().map(|name| self.lookup(name)).collect();
+                let mut items: Vec<ParsedNode> = vec![];
+                self.comprehend(&clauses, 0, element.as_ref(), &value, &mut items);
+
+                for (name, old) in names.iter().zip(saved).rev() {
+                    if let Some(old) = old {
+                        self.set_variable(name.clone(), old);
+                    } else {
+                        self.remove_variable(name);
+                    }
+                }
+
+                match kind.as_str() {
+                    "qaamuus" => {
+                        let pairs = items.into_iter().map(|pair| match pair {
+                            ParsedNode::Tuple { items } => (items[0].clone(), items[1].clone()),
+                            other => (other, ParsedNode::Null)
+                        }).collect();
+
+                        return self.resolve(ParsedNode::Dict { items: pairs });
+                    }
+                    "urur" => {
+                        return self.resolve(ParsedNode::Set { items });
+                    }
+                    //The items are worked out up front here, and then handed out one at a time
+                    "dhaliye" => {
+                        return ParsedNode::Iterator {
+                            kind,
+                            sources: items,
+                            position: Position::default(),
+                            start: 0
+                        };
+                    }
+                    _ => {
+                        return ParsedNode::List { items };
+                    }
+                }
+            }
+            ParsedNode::List { items } => {
+                return ParsedNode::List {
+                    items: items.into_iter().map(|x| self.resolve(x)).collect()
+                };
+            }
+            ParsedNode::Tuple { items } => {
+                return ParsedNode::Tuple {
+                    items: items.into_iter().map(|x| self.resolve(x)).collect()
+                };
+            }
+            ParsedNode::Set { items } => {
+                let mut unique: Vec<ParsedNode> = vec![];
+                for item in items {
+                    let resolved = self.resolve(item);
+                    if !unique.iter().any(|other| Inbuilt::same_key(other, &resolved)) {
+                        unique.push(resolved);
+                    }
+                }
+
+                return ParsedNode::Set { items: unique };
             }
+            ParsedNode::Dict { items } => {
+                let mut pairs: Vec<(ParsedNode, ParsedNode)> = vec![];
+                for (key, value) in items {
+                    let key = self.resolve(key);
+                    let value = self.resolve(value);
+                    let pos = pairs.iter().position(|(k, _)| Inbuilt::same_key(k, &key));
+                    if let Some(pos) = pos {
+                        pairs[pos].1 = value;
+                    } else {
+                        pairs.push((key, value));
+                    }
+                }
+
+                return ParsedNode::Dict { items: pairs };
+            }
+            node => {
+                return node;
+            }
+        }
+    }
+
+    pub fn compare(
+        &mut self,
+        operator: Token,
+        left: Vec<ParsedNode>,
+        right: Vec<ParsedNode>
+        ) -> bool {
+        let left_value = self.comparison_value(left);
+        let right_value = self.comparison_value(right);
+        if self.error.is_some() {
+            return false;
         }
 
         match operator {
-            Token::Equal => { 
-                return left_solved == right_solved;
+            Token::Equal => {
+                return self.equal(&left_value, &right_value);
+            }
+            Token::NotEqual => {
+                let equal = self.equal(&left_value, &right_value);
+                return !equal && self.error.is_none();
             }
-            Token::Greater => { 
-                return left_solved > right_solved;
+            _ => { }
+        }
+
+        //Only values with an order can be compared, so a kakan can't
+        let order = self.order(&left_value, &right_value);
+        if self.error.is_some() {
+            return false;
+        }
+
+        if order.is_none() {
+            self.fail(format!(
+                "NoocKhaldan: '{}' laguma isticmaali karo '{}' iyo '{}'",
+                operator.text(),
+                Inbuilt::type_name(&left_value),
+                Inbuilt::type_name(&right_value)
+            ));
+            return false;
+        }
+
+        match operator {
+            Token::Greater => {
+                return order.unwrap() == Ordering::Greater;
             }
-            Token::GreaterOrEqual => { 
-                return left_solved >= right_solved;
+            Token::GreaterOrEqual => {
+                return order.unwrap() != Ordering::Less;
             }
-            Token::Less => { 
-                return left_solved < right_solved;
+            Token::Less => {
+                return order.unwrap() == Ordering::Less;
             }
-            Token::LessOrEqual => { 
-                return left_solved <= right_solved;
+            Token::LessOrEqual => {
+                return order.unwrap() != Ordering::Greater;
             }
             _ => { }
         }
@@ -335,18 +1418,93 @@ impl Interpreter {
         return false;
     }
 
+    //==, which a lamid hook set with sifobadal answers for whichever side has one, the left first
+    fn equal(&mut self, left: &ParsedNode, right: &ParsedNode) -> bool {
+        for (value, other) in [(left, right), (right, left)] {
+            if let Some(hook) = Inbuilt::attribute(value, "lamid") {
+                let out = self.call_value(&hook, vec![other.clone()]);
+                return out.is_some() && self.truthy(&out.unwrap());
+            }
+        }
+
+        return Inbuilt::equal(left, right);
+    }
+
+    //The order of two values, where a kayar hook answers < for whichever side has one, and
+    //the two are only the same if lamid says so
+    fn order(&mut self, left: &ParsedNode, right: &ParsedNode) -> Option<Ordering> {
+        for (value, other, less) in [(left, right, Ordering::Less), (right, left, Ordering::Greater)] {
+            if let Some(hook) = Inbuilt::attribute(value, "kayar") {
+                let out = self.call_value(&hook, vec![other.clone()])?;
+                if self.truthy(&out) {
+                    return Some(less);
+                }
+
+                if self.error.is_none() && self.equal(value, other) {
+                    return Some(Ordering::Equal);
+                }
+
+                return Some(less.reverse());
+            }
+        }
+
+        return Inbuilt::compare_values(left, right);
+    }
+
+    //One side of a comparison as a value, where a side made of several parts is added up
+    pub fn comparison_value(&mut self, parts: Vec<ParsedNode>) -> ParsedNode {
+        let mut values: Vec<ParsedNode> = vec![];
+        for part in parts {
+            let value = match part {
+                ParsedNode::Variable { name, .. } => {
+                    let variable = self.lookup(&name);
+                    if variable.is_none() {
+                        self.fail(format!("KhaladMagceed: magaca '{}' lama qeexin", name));
+                        return ParsedNode::Null;
+                    }
+
+                    variable.unwrap()
+                }
+                part => self.resolve(part)
+            };
+
+            values.push(value);
+        }
+
+        if values.len() == 1 {
+            return values.pop().unwrap();
+        }
+
+        let mut total: f64 = 0.0;
+        for value in &values {
+            total += Inbuilt::to_number(value).unwrap_or(0.0);
+        }
+
+        return Inbuilt::from_number(total, values.iter().any(Inbuilt::is_float));
+    }
+
     pub fn solve_equation(
         &mut self,
-        items: &Vec<Token>
+        items: &[Token]
         ) -> (ParsedNode, f64) {
         let mut equation: String = "".to_owned();
-        items.into_iter().for_each(|item| {
-            match item {
+        //Whether a kakan takes part, from a j literal or a value that is one
+        let mut complex = false;
+        //Whether the answer is a tobanle, which a tobanle operand or a / makes it
+        let mut float = false;
+        //Where each name or call in the equation starts and ends, and what it came to
+        let mut operands: Vec<(usize, usize, ParsedNode)> = vec![];
+        let mut position = 0;
+        while position < items.len() {
+            match &items[position] {
                 Token::Int(val) | Token::Float(val) => {
-                    let chars: &str = &val.into_iter().collect::<String>();
+                    complex = complex || val.last() == Some(&'j');
+                    float = float || matches!(items[position], Token::Float(_));
+                    let chars: &str = &val.iter().collect::<String>();
                     equation += chars;
                 },
                 Token::Divide => {
+                    float = true;
                     equation += "/";
                 },
                 Token::Multiply => {
@@ -364,26 +1522,100 @@ impl Interpreter {
                 Token::Modulus => {
                     equation += "%";
                 },
+                Token::OpenParen => {
+                    equation += "(";
+                },
+                Token::CloseParen => {
+                    equation += ")";
+                },
                 Token::Word(word) => {
-                    unsafe {
-                        let pos = VARIABLE_DICT.iter().position(|(name, _)| name == &word.iter().collect::<String>());
-                        if pos.is_some() {
-                            let loc = pos.unwrap();
-                            equation += &Inbuilt::parsed_string(VARIABLE_DICT[loc].1.as_ref().clone());
+                    if position + 1 < items.len() && [Token::OpenParen, Token::OpenBrack, Token::Dot].contains(&items[position + 1]) {
+                        //Calls, indexes and attributes are resolved first and substituted into the equation
+                        let mut parser = Parser::new(items.to_vec());
+                        let mut node = ParsedNode::Variable {
+                            name: word.iter().collect::<String>(),
+                            exists: true,
+                            add_sub: 0,
+                            value: None
+                        };
+                        let mut end = position + 1;
+                        if items[position + 1] == Token::OpenParen {
+                            let close = parser.closing_bracket(items, position + 1);
+                            node = parser.get_function_call(word.to_vec(), items[position..close + 1].to_vec(), 0).0;
+                            end = close + 1;
                         }
+
+                        let chained = parser.get_method_calls(node, items, end);
+                        let value = self.resolve(chained.0);
+                        complex = complex || matches!(value, ParsedNode::Complex { .. });
+                        float = float || Inbuilt::is_float(&value);
+                        equation += &format!("({})", Inbuilt::parsed_string(value.clone()));
+                        operands.push((position, chained.1 - 1, value));
+                        position = chained.1 - 1;
+                    } else {
+                        let name = word.iter().collect::<String>();
+                        let value = self.lookup(&name);
+                        if value.is_none() {
+                            self.fail(format!("KhaladMagceed: magaca '{}' lama qeexin", name));
+                            return (ParsedNode::Null, 0.0);
+                        }
+
+                        let value = value.unwrap();
+                        complex = complex || matches!(value, ParsedNode::Complex { .. });
+                        float = float || Inbuilt::is_float(&value);
+                        equation += &format!("({})", Inbuilt::parsed_string(value.clone()));
+                        operands.push((position, position, value));
                     }
                 },
                 _ => { }
             }
-        });
 
-        let float_output = eval_str(equation).unwrap();
+            position += 1;
+        }
+
+        //A value with an isugeyn hook, set with sifobadal, works out + for itself
+        if operands.iter().any(|(_, _, value)| Inbuilt::attribute(value, "isugeyn").is_some()) {
+            let value = self.hooked_sum(items, &operands, 0, items.len()).unwrap_or(ParsedNode::Null);
+            let number = Inbuilt::to_number(&value).unwrap_or(0.0);
+            return (value, number);
+        }
+
+        //meval only knows real numbers, so anything with a kakan in it is worked out separately
+        if complex {
+            let result = Inbuilt::eval_complex(self, &equation);
+            if result.is_none() {
+                return (ParsedNode::Null, 0.0);
+            }
+
+            let (real, imag) = result.unwrap();
+            return (ParsedNode::Complex { real, imag }, real);
+        }
+
+        //A plain whole number, such as a negative literal, doesn't need to go through floats
+        let whole = equation.trim_matches(|c| c == '(' || c == ')').parse::<i128>();
+        if let (Ok(whole), false) = (whole, float) {
+            return (ParsedNode::Int { val: whole.to_string().chars().collect() }, whole as f64);
+        }
+
+        let result = eval_str(&equation);
+        if result.is_err() {
+            let text: String = items.iter().map(|item| item.text()).collect();
+            self.fail(format!("KhaladHabQoraal: tibaaxda '{}' lama xisaabin karo", text.trim()));
+            return (ParsedNode::Null, 0.0);
+        }
+
+        let float_output = result.unwrap();
+        //meval gives inf or NaN for a division by zero, which is raised the same way a kakan's is
+        if !float_output.is_finite() && (equation.contains('/') || equation.contains('%')) {
+            self.fail("KhaladEberUQeybin: eber wax looma qaybin karo".to_owned());
+            return (ParsedNode::Null, 0.0);
+        }
+
         let output = float_output.to_string();
 
-        if output.contains(".") {
-            return (ParsedNode::Float {
-                val: output.chars().collect()
-            }, float_output);
+        //An answer that comes out with a fraction, e.g. 2 ** -1, is a tobanle too
+        if float || output.contains(".") {
+            return (Inbuilt::from_number(float_output, true), float_output);
         }
 
         return (ParsedNode::Int {
@@ -391,52 +1623,115 @@ impl Interpreter {
         }, float_output);
     }
 
-    pub fn print(&mut self, node: ParsedNode) {
-        match node {
-            ParsedNode::List { items } | ParsedNode::Tuple { items } => {
-                let mut list: String = "[".to_owned();
-                let mut next = ",";
-                for x in 0..items.len() {
-                    let str_form = &self.get_string((&items[x]).to_owned(), false);
-                    list = list + str_form;
-                    if x == items.len() - 1 {
-                        next = "]";
-                        list = list + next;
-                        break;
-                    }
+    //items[start..end] added up term by term from the left, with the names and calls already worked
+    //out in operands, so that a value with an isugeyn hook is asked for the sum itself
+    fn hooked_sum(
+        &mut self,
+        items: &[Token],
+        operands: &[(usize, usize, ParsedNode)],
+        start: usize,
+        end: usize
+        ) -> Option<ParsedNode> {
+        let mut terms: Vec<(usize, usize)> = vec![];
+        let mut depth = 0;
+        let mut from = start;
+        for (index, item) in items.iter().enumerate().take(end).skip(start) {
+            match item {
+                Token::OpenParen | Token::OpenBrack => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack => {
+                    depth -= 1;
+                }
+                Token::Plus if depth == 0 => {
+                    terms.push((from, index));
+                    from = index + 1;
+                }
+                _ => { }
+            }
+        }
+
+        terms.push((from, end));
 
-                    list = list + next + " ";
+        let mut total: Option<ParsedNode> = None;
+        for (first, last) in terms {
+            let operand = operands.iter().find(|(begin, finish, _)| *begin == first && finish + 1 == last);
+            let value = if let Some((_, _, value)) = operand {
+                value.clone()
+            } else if last > first + 1 && items[first] == Token::OpenParen && Parser::new(items.to_vec()).closing_bracket(items, first) == last - 1 {
+                self.hooked_sum(items, operands, first + 1, last - 1)?
+            } else {
+                //Anything else is plain arithmetic, with its names put back in as numbers
+                let mut tokens: Vec<Token> = vec![];
+                let mut index = first;
+                while index < last {
+                    match operands.iter().find(|(begin, _, _)| *begin == index) {
+                        Some((_, finish, value)) => {
+                            tokens.extend(self.number_tokens(value)?);
+                            index = finish + 1;
+                        }
+                        None => {
+                            tokens.push(items[index].clone());
+                            index += 1;
+                        }
+                    }
                 }
 
-                if items.len() == 0 {
-                    list = "[]".to_owned();
+                self.solve_equation(&tokens).0
+            };
+
+            if self.error.is_some() {
+                return None;
+            }
+
+            total = match total {
+                None => Some(value),
+                Some(left) => {
+                    let hook = Inbuilt::attribute(&left, "isugeyn");
+                    if let Some(hook) = hook {
+                        self.call_value(&hook, vec![value])
+                    } else {
+                        let mut tokens = self.number_tokens(&left)?;
+                        tokens.push(Token::Plus);
+                        tokens.extend(self.number_tokens(&value)?);
+                        Some(self.solve_equation(&tokens).0)
+                    }
                 }
+            };
 
-                print!("{}\r\n", list);
-            },
-            node => { print!("{}", self.get_string(node, true)) }
+            if self.error.is_some() {
+                return None;
+            }
         }
+
+        return total;
     }
 
-    pub fn get_string(&mut self, node: ParsedNode, newline: bool) -> String {
-        let mut end: &str = "\r\n";
-        if !newline { 
-            end = "";
+    //A number as the tokens of an equation, for adding it up with the others
+    fn number_tokens(&mut self, value: &ParsedNode) -> Option<Vec<Token>> {
+        match value {
+            ParsedNode::Int { val } => {
+                return Some(vec![Token::OpenParen, Token::Int(val.clone()), Token::CloseParen]);
+            }
+            ParsedNode::Float { val } => {
+                return Some(vec![Token::OpenParen, Token::Float(val.clone()), Token::CloseParen]);
+            }
+            ParsedNode::Complex { real, imag } => {
+                let mut tokens = vec![Token::OpenParen];
+                tokens.extend(Inbuilt::complex_tokens(*real, *imag));
+                tokens.push(Token::CloseParen);
+                return Some(tokens);
+            }
+            other => {
+                self.fail(format!("NoocKhaldan: '+' laguma isticmaali karo '{}'", Inbuilt::type_name(other)));
+                return None;
+            }
         }
+    }
 
-        match node {
-            ParsedNode::Function { name, .. } => {
-                return format!("Function {}(){}", name, end);
-            },
-            ParsedNode::Int { val } | ParsedNode::Float { val } => {
-                return format!("{}{}", val.into_iter(). collect::<String>(), end);
-            },
-            ParsedNode::Str { val } => {
-                return format!("{}{}", val, end);
-            },
-            _ => { }
+    pub fn print(&mut self, node: ParsedNode) {
+        if Inbuilt::is_value(&node) {
+            Inbuilt::write_out(&format!("{}\n", Inbuilt::repr(&node)));
         }
-
-        return "".to_owned();
     }
 }
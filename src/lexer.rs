@@ -1,6 +1,7 @@
 #[derive(PartialEq)]
 #[derive(Debug)]
 #[derive(Clone)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Token {
     OpenParen,
     CloseParen,
@@ -12,6 +13,8 @@ pub enum Token {
     Assign,
     EOF,
     Comma,
+    Dot,
+    At,
     Plus,
     Minus,
     Divide,
@@ -23,6 +26,7 @@ pub enum Token {
     Less,
     LessOrEqual,
     Equal,
+    NotEqual,
     Ignore,
     Int(Vec<char>),
     Float(Vec<char>),
@@ -44,16 +48,53 @@ impl PartialEq<Token> for &&Token {
     }
 }
 
+impl Token {
+    pub fn text(&self) -> String {
+        let text = match self {
+            Token::OpenParen => "(",
+            Token::CloseParen => ")",
+            Token::OpenBrack => "[",
+            Token::CloseBrack => "]",
+            Token::OpenBrace => "{",
+            Token::CloseBrace => "}",
+            Token::Colon => ":",
+            Token::Assign => "=",
+            Token::Comma => ",",
+            Token::Dot => ".",
+            Token::At => "@",
+            Token::Plus => "+",
+            Token::Minus => "-",
+            Token::Divide => "/",
+            Token::Multiply => "*",
+            Token::Power => "^",
+            Token::Modulus => "%",
+            Token::Greater => ">",
+            Token::GreaterOrEqual => ">=",
+            Token::Less => "<",
+            Token::LessOrEqual => "<=",
+            Token::Equal => "==",
+            Token::NotEqual => "!=",
+            Token::EOF | Token::Ignore => "",
+            Token::Int(val) | Token::Float(val) | Token::Whitespace(val) |
+            Token::Speech(val) | Token::Comment(val) | Token::Word(val) => {
+                return val.iter().collect::<String>();
+            }
+        };
+
+        return text.to_owned();
+    }
+}
+
 fn is_letter(ch: char) -> bool {
-    'a' <= ch && ch <= 'z' || 'A' <= ch && ch <= 'Z' || ch == '_'
+    ch.is_ascii_lowercase() || ch.is_ascii_uppercase() || ch == '_'
 }
 
 fn is_digit(ch: char) -> bool {
-    '0' <= ch && ch <= '9'
+    ch.is_ascii_digit()
 }
 
 fn is_whitespace(ch: char) -> bool {
-    let whitespaces = vec![' ', '\t', '\n', '\r'];
+    let whitespaces = [' ', '\t', '\n', '\r'];
     return whitespaces.contains(&ch)
 }
 
@@ -86,7 +127,7 @@ impl Lexer {
     }
 
     pub fn get_num(&mut self) -> Token {
-        let mut num: Vec<char> = vec![];
+        let mut num: Vec<char> = if self.ch == '.' { vec!['0'] } else { vec![] };
         loop {
             if !is_digit(self.ch) && self.ch != '.' {
                 break;
@@ -96,6 +137,14 @@ impl Lexer {
             self.read_char();
         }
 
+        //2j is an imaginary number, kept as a float with the j on the end
+        let next = if self.read_position < self.input.len() { self.input[self.read_position] } else { ' ' };
+        if self.ch == 'j' && !is_letter(next) && !is_digit(next) {
+            num.push(self.ch);
+            self.read_char();
+            return Token::Float(num);
+        }
+
         if num.contains(&'.') {
             return Token::Float(num);
         } else {
@@ -167,6 +216,11 @@ impl Lexer {
         self.read_char();
 
         loop {
+            //A string left open runs to the end of the code, where the parser reports it
+            if self.position >= self.input.len() {
+                break;
+            }
+
             if self.ch == end && self.input[self.position - 1] != '\\' {
                 speech.push(self.ch);
                 self.read_char();
@@ -181,17 +235,39 @@ impl Lexer {
     }
 
     pub fn next_token(&mut self) -> Token {
-        let token;
+        //b"..." is a bytes literal, kept as speech with the b in front
+        if self.ch == 'b' && self.read_position < self.input.len() && ['"', '\''].contains(&self.input[self.read_position]) {
+            self.read_char();
+            let speech = self.get_speech();
+            let mut chars = vec!['b'];
+            chars.extend(speech.text().chars());
+            return Token::Speech(chars);
+        }
 
         if is_letter(self.ch) {
             let a = self.get_word();
             return a;
         }
 
-        if is_digit(self.ch) {
+        //.5 is a float, written without the 0 in front
+        let next = if self.read_position < self.input.len() { self.input[self.read_position] } else { ' ' };
+        if is_digit(self.ch) || (self.ch == '.' && is_digit(next)) {
             return self.get_num();
         }
 
+        if self.ch == '!' && next == '=' {
+            self.read_char();
+            self.read_char();
+            return Token::NotEqual;
+        }
+
+        //** is a power, the same as ^
+        if self.ch == '*' && next == '*' {
+            self.read_char();
+            self.read_char();
+            return Token::Power;
+        }
+
         if is_whitespace(self.ch) {
             return self.get_whitespace();
         }
@@ -212,27 +288,29 @@ impl Lexer {
             //return Token::LesserOrEqual;
         //}
 
-        match self.ch {
-            '(' => { token = Token::OpenParen; }
-            ')' => { token = Token::CloseParen; }
-            '[' => { token = Token::OpenBrack; }
-            ']' => { token = Token::CloseBrack; }
-            '{' => { token = Token::OpenBrace; }
-            '}' => { token = Token::CloseBrace; }
-            ':' => { token = Token::Colon; }
-            ',' => { token = Token::Comma; }
-            '=' => { token = Token::Assign; }
-            '+' => { token = Token::Plus; }
-            '-' => { token = Token::Minus; }
-            '/' => { token = Token::Divide; }
-            '*' => { token = Token::Multiply; }
-            '^' => { token = Token::Power; }
-            '>' => { token = Token::Greater; }
-            '<' => { token = Token::Less; }
-            '%' => { token = Token::Modulus; }
-            '§' => { token = Token::EOF; }
-            _ => { token = Token::Comma; }
-        }
+        let token = match self.ch {
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '[' => Token::OpenBrack,
+            ']' => Token::CloseBrack,
+            '{' => Token::OpenBrace,
+            '}' => Token::CloseBrace,
+            ':' => Token::Colon,
+            ',' => Token::Comma,
+            '.' => Token::Dot,
+            '@' => Token::At,
+            '=' => Token::Assign,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '/' => Token::Divide,
+            '*' => Token::Multiply,
+            '^' => Token::Power,
+            '>' => Token::Greater,
+            '<' => Token::Less,
+            '%' => Token::Modulus,
+            '§' => Token::EOF,
+            _ => Token::Comma
+        };
         self.read_char();
         token
     }
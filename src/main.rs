@@ -1,3 +1,6 @@
+//Every function in this crate ends with an explicit return, which is how the codebase is written
+#![allow(clippy::needless_return)]
+
 extern crate clap;
 extern crate dirs;
 extern crate meval;
@@ -5,6 +8,9 @@ extern crate meval;
 #[macro_use]
 extern crate crossterm;
 
+mod debugger;
+mod docs;
+mod eventloop;
 mod inbuilt;
 mod interpreter;
 mod lexer;
@@ -21,6 +27,7 @@ use std::env;
 use std::fs::{File, OpenOptions, read_to_string};
 use std::io::{Stdout, Write, stdout};
 use std::path::Path;
+use std::process;
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -34,7 +41,13 @@ struct Args {
     caawimaad: bool,
 
     #[arg(short, long)]
-    nooca: bool
+    nooca: bool,
+
+    #[arg(short, long)]
+    aanxaqiijin: bool,
+
+    #[arg(short, long)]
+    debug: bool
 }
 
 fn show_help() {
@@ -44,6 +57,8 @@ Doorashooyinka:
   -q, --qoraal <QORAAL>
   -k, --kayd <KAYD>
   -n, --nooca                 Nooca ii sheeg
+  -a, --aanxaqiijin           Xaqiiji ha la fulin
+  -d, --debug                 Bartaanbaaraha ka bilow sadarka koowaad
   -c, --caawimaad             I caawi"#)
 }
 
@@ -54,11 +69,8 @@ macro_rules! get_version {
 fn get_home() -> String {
     let mut home = String::new();
 
-    match home_dir() {
-        Some(path) => {
-            home = path.display().to_string();
-        },
-        None => {}
+    if let Some(path) = home_dir() {
+        home = path.display().to_string();
     }
 
     return home
@@ -73,7 +85,6 @@ fn write_history(text: &str) {
     match file {
         Ok(_) => {
             let mut history = OpenOptions::new()
-                .write(true)
                 .append(true)
                 .open(&name)
                 .unwrap();
@@ -112,7 +123,7 @@ fn insert(mut out: &Stdout, line: &mut String, offset: usize, c: char) {
     );
 
     if offset == 0 {
-        repl_print(&out, &" ");
+        repl_print(out, " ");
     }
 
     execute!(out, cursor::MoveLeft(offset as u16)).unwrap();
@@ -149,138 +160,135 @@ fn repl() {
     let mut line = String::new();
     let mut line_num: isize = -1;
     let mut offset = 0;
+    let mut interpreter = interpreter::Interpreter::new();
     loop {
-        match read().unwrap() {
-            Event::Key(event) => {
-                if event.code == KeyCode::Backspace && line.len() != 0 && offset != line.len() {
-                    move_right(&out, offset);
-                    repl_print(&out, &"\x08 \x08".repeat(line.len()));
-                    line.remove(line.len() - offset - 1);
-                    repl_print(&out, &line);
-                    move_left(&out, offset);
-                } else if event.code == KeyCode::Enter {
-                    offset = 0;
-                    write_history(&format!("{}\r\n", line));
-                    if line.chars().last() == Some(':') && !multiline {
-                        multiline = true;
-                        code.clear();
-                    } else if line.trim().is_empty() {
-                        multiline = false;
-                    }
+        if let Event::Key(event) = read().unwrap() {
+            if event.code == KeyCode::Backspace && !line.is_empty() && offset != line.len() {
+                move_right(&out, offset);
+                repl_print(&out, &"\x08 \x08".repeat(line.len()));
+                line.remove(line.len() - offset - 1);
+                repl_print(&out, &line);
+                move_left(&out, offset);
+            } else if event.code == KeyCode::Enter {
+                offset = 0;
+                write_history(&format!("{}\r\n", line));
+                //Decorators are followed by the qayb they wrap, so they open a block too
+                if (line.ends_with(':') || line.trim_start().starts_with('@')) && !multiline {
+                    multiline = true;
+                    code.clear();
+                } else if line.trim().is_empty() {
+                    multiline = false;
+                }
 
-                    if multiline {
-                        code = code + &line + "\n";
-                        repl_print(&out, "\r\n... ");
+                if multiline {
+                    code = code + &line + "\n";
+                    repl_print(&out, "\r\n... ");
+                } else {
+                    repl_print(&out, "\r\n");
+                    let parsed: Vec<parser::ParsedNode>;
+                    let ran = code.clone();
+                    if !code.is_empty() {
+                        parsed = parser::Parser::new(lexer::Lexer::new(&code).lex()).parse();
+                        code.clear();
                     } else {
-                        repl_print(&out, "\r\n");
-                        let parsed: Vec<parser::ParsedNode>;
-                        if !code.is_empty() {
-                            parsed = parser::Parser::new(lexer::Lexer::new(&code).lex()).parse();
-                            code.clear();
-                        } else {
-                            parsed = parser::Parser::new(lexer::Lexer::new(&line).lex()).parse();
-                        }
-
-                        interpreter::Interpreter::new().interpret(true, parsed);
-                        repl_print(&out, ">>> ");
+                        parsed = parser::Parser::new(lexer::Lexer::new(&line).lex()).parse();
                     }
 
-                    line.clear();
-                } else if event.code == KeyCode::Up {
-                    if has_history() {
-                        let new = read_history(line_num + 1);
-                        if !new.0.is_empty() && line_num < new.1 as isize {
-                            repl_print(
-                                &out,
-                             &"\x08 \x08".repeat(line.len())
-                            );
-                            line.clear();
-                            line_num += 1;
-                            line += &new.0;
-                            repl_print(
-                                &out,
-                                &line
-                            );
-                        }
-                    }
-                } else if event.code == KeyCode::Down {
-                    if has_history() {
+                    interpreter.reset();
+                    interpreter.debugger.source(if !ran.is_empty() { &ran } else { &line });
+                    interpreter.interpret(true, parsed);
+                    interpreter.report();
+                    repl_print(&out, ">>> ");
+                }
+
+                line.clear();
+            } else if event.code == KeyCode::Up {
+                if has_history() {
+                    let new = read_history(line_num + 1);
+                    if !new.0.is_empty() && line_num < new.1 as isize {
                         repl_print(
                             &out,
-                            &"\x08 \x08".repeat(line.len())
+                         &"\x08 \x08".repeat(line.len())
                         );
                         line.clear();
-
-                        if line_num >= 0 {
-                            line_num -= 1;
-                            line += &read_history(line_num).0;
-                            repl_print(
-                                &out,
-                                &line
-                            );
-                        }
-                    }
-                } else if event.code == KeyCode::Left {
-                    if offset < line.len() {
-                        execute!(&out, cursor::MoveLeft(1)).unwrap();
-                        offset += 1;
+                        line_num += 1;
+                        line += &new.0;
+                        repl_print(
+                            &out,
+                            &line
+                        );
                     }
-                } else if event.code == KeyCode::Right {
-                    if offset > 0 {
-                        execute!(&out, cursor::MoveRight(1)).unwrap();
-                        offset -= 1;
+                }
+            } else if event.code == KeyCode::Down {
+                if has_history() {
+                    repl_print(
+                        &out,
+                        &"\x08 \x08".repeat(line.len())
+                    );
+                    line.clear();
+
+                    if line_num >= 0 {
+                        line_num -= 1;
+                        line += &read_history(line_num).0;
+                        repl_print(
+                            &out,
+                            &line
+                        );
                     }
-                } else if event.code == KeyCode::Home && offset < line.len() {
-                    let change = line.len() - offset;
-                    execute!(&out, cursor::MoveLeft(change as u16)).unwrap();
-                    offset = line.len();
-                } else if event.code == KeyCode::End /*&& offset > 0*/ {
-                    move_right(&out, offset);
-                    offset = 0;
-                } else {
-                    match event.modifiers {
-                        KeyModifiers::NONE => {
-                            match event.code {
-                                KeyCode::Char(c) => {
-                                    insert(&out, &mut line, offset, c);
-                                },
-                                _ => {}
-                            }
-                        },
-                        KeyModifiers::SHIFT => {
-                            match event.code {
-                                KeyCode::Char(c) => {
-                                    insert(&out, &mut line, offset, c);
-                                },
-                                _ => {}
-                            }
+                }
+            } else if event.code == KeyCode::Left {
+                if offset < line.len() {
+                    execute!(&out, cursor::MoveLeft(1)).unwrap();
+                    offset += 1;
+                }
+            } else if event.code == KeyCode::Right {
+                if offset > 0 {
+                    execute!(&out, cursor::MoveRight(1)).unwrap();
+                    offset -= 1;
+                }
+            } else if event.code == KeyCode::Home && offset < line.len() {
+                let change = line.len() - offset;
+                execute!(&out, cursor::MoveLeft(change as u16)).unwrap();
+                offset = line.len();
+            } else if event.code == KeyCode::End /*&& offset > 0*/ {
+                move_right(&out, offset);
+                offset = 0;
+            } else {
+                match event.modifiers {
+                    KeyModifiers::NONE => {
+                        if let KeyCode::Char(c) = event.code {
+                            insert(&out, &mut line, offset, c);
+                        }
+                    },
+                    KeyModifiers::SHIFT => {
+                        if let KeyCode::Char(c) = event.code {
+                            insert(&out, &mut line, offset, c);
                         }
-                        KeyModifiers::CONTROL => {
-                            match event.code {
-                                KeyCode::Char('d') => {
-                                    disable_raw_mode().unwrap();
-                                    break
-                                },
-                                KeyCode::Char('c') =>  {
-                                    repl_print(&out, &"\r\nWaaLaJoojiyey\r\n>>> ");
-                                },
-                                KeyCode::Char('u') => {
-                                    move_right(&out, offset);
-                                    repl_print(&out, &"\x08 \x08".repeat(line.len()));
-                                    let _new = &line.chars().collect::<String>()[line.len() - offset..line.len()];
-                                    repl_print(&out, &_new);
-                                    move_left(&out, offset);
-                                    line.clear();
-                                    line += _new;
-                                },
-                                _ => {}
-                            }
-                        },
-                        _ => {}
                     }
+                    KeyModifiers::CONTROL => {
+                        match event.code {
+                            KeyCode::Char('d') => {
+                                disable_raw_mode().unwrap();
+                                break
+                            },
+                            KeyCode::Char('c') =>  {
+                                repl_print(&out, "\r\nWaaLaJoojiyey\r\n>>> ");
+                            },
+                            KeyCode::Char('u') => {
+                                move_right(&out, offset);
+                                repl_print(&out, &"\x08 \x08".repeat(line.len()));
+                                let _new = &line.chars().collect::<String>()[line.len() - offset..line.len()];
+                                repl_print(&out, _new);
+                                move_left(&out, offset);
+                                line.clear();
+                                line += _new;
+                            },
+                            _ => {}
+                        }
+                    },
+                    _ => {}
                 }
-            },
-            _ => {}
+            }
         }
     }
 }
@@ -288,12 +296,13 @@ fn repl() {
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() == 2 && args[1].chars().nth(0).unwrap() != '-' {
+    if args.len() == 2 && !args[1].starts_with('-') {
         println!("TODO: Compile Code");
     } else if args.len() == 1 {
         repl();
     } else {
         let c_args = Args::parse();
+        let mut interpreter = interpreter::Interpreter::new();
 
         if c_args.caawimaad {
             show_help();
@@ -303,8 +312,15 @@ fn main() {
             println!("Geel {}", get_version!());
         }
 
-        if c_args.qoraal.is_some() {
-            let qoraal = c_args.qoraal.unwrap();
+        if c_args.aanxaqiijin {
+            interpreter.assertions = false;
+        }
+
+        if c_args.debug {
+            interpreter.debugger.start();
+        }
+
+        if let Some(qoraal) = c_args.qoraal {
             if !qoraal.is_empty() {
                 println!("{:?}", Lexer::new(&qoraal).lex());
             } else {
@@ -312,13 +328,19 @@ fn main() {
             }
         }
 
-        if c_args.kayd.is_some() {
-            let name = c_args.kayd.unwrap();
+        if let Some(name) = c_args.kayd {
             if !name.is_empty() {
                 if Path::new(&name).exists() {
                     let contents = read_to_string(name).unwrap();
+                    interpreter.debugger.source(&contents);
                     let parsed = parser::Parser::new(Lexer::new(&contents).lex()).parse();
-                    interpreter::Interpreter::new().interpret(false, parsed);
+                    interpreter.interpret(false, parsed);
+                    interpreter.report();
+
+                    //An error nothing caught means the program failed
+                    if interpreter.error.is_some() {
+                        process::exit(1);
+                    }
                 } else {
                     println!("Wax jirin baad noo tilmaamtey.");
                 }
@@ -1,9 +1,90 @@
 use crate::lexer::Token;
+use crate::inbuilt::Inbuilt;
+use crate::eventloop::TaskState;
+use std::cell::{Cell, RefCell};
+use std::fs::File;
+use std::rc::Rc;
 
+//Every copy of a file value shares the one handle, which closes once the last copy goes
 #[derive(Debug)]
-enum CondStruct {
-    Normal((Vec<Token>, usize)),
-    Scope(Vec<(Vec<Token>, usize)>)
+#[derive(Clone)]
+pub struct FileHandle(pub Rc<RefCell<Option<File>>>);
+
+impl PartialEq for FileHandle {
+    fn eq(&self, other: &Self) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
+}
+
+//One kastoo ... kujira ... clause of a comprehension: the loop names, the iterable and its haddii conditions
+pub type Clause = (Vec<String>, ParsedNode, Vec<Vec<Vec<ParsedNode>>>);
+
+//A name and the value bound to it
+pub type Variable = (String, Box<ParsedNode>);
+
+//The globals are shared with what caalamiyaasha() hands out, so writes through it reach the program
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct Variables(pub Rc<RefCell<Vec<Variable>>>);
+
+impl PartialEq for Variables {
+    fn eq(&self, other: &Self) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
+}
+
+//A function's attributes belong to that function, and every copy of it shares them
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct Attributes(pub Rc<RefCell<Vec<(String, ParsedNode)>>>);
+
+impl PartialEq for Attributes {
+    fn eq(&self, other: &Self) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
+}
+
+//A kooxdhibco changes in place, so its copies and the xasuusaragti views of it all see the change
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Buffer(pub Rc<RefCell<Vec<u8>>>);
+
+impl Buffer {
+    pub fn new(val: Vec<u8>) -> Self {
+        return Buffer(Rc::new(RefCell::new(val)));
+    }
+}
+
+//Byte arrays compare by what they hold, as they do in Python
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        return *self.0.borrow() == *other.0.borrow();
+    }
+}
+
+//Every copy of an iterator moves the same position along, so each item is only handed out once
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct Position(pub Rc<Cell<usize>>);
+
+impl PartialEq for Position {
+    fn eq(&self, other: &Self) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
+}
+
+//A coroutine and the hawl it may become are one value, so whoever awaits it sees how it ended
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Task(pub Rc<RefCell<TaskState>>);
+
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
 }
 
 #[derive(Debug)]
@@ -13,19 +94,44 @@ pub enum ParsedNode {
     Function {
         name: String,
         params: Vec<ParsedNode>,
-        body: Vec<ParsedNode>
+        body: Vec<ParsedNode>,
+        attributes: Attributes,
+        //A mardambe qayb gives back a coroutine instead of running its body
+        asynchronous: bool,
+        //The scopes of the calls it was written in, nearest first, which its body can still read
+        closure: Vec<Variables>
+    },
+    //A laamda as it's written, which makes a new function each time it's worked out
+    Lambda {
+        function: Box<ParsedNode>
     },
     FunctionCall {
         name: String,
         params: Vec<ParsedNode>
     },
+    //A call on whatever a value works out to, e.g. the second call in f()(2)
+    Call {
+        function: Box<ParsedNode>,
+        params: Vec<ParsedNode>
+    },
     ForLoop {
-        var: String,
+        vars: Vec<String>,
         iterable: Box<ParsedNode>,
+        body: Vec<ParsedNode>,
+        asynchronous: bool
+    },
+    With {
+        managers: Vec<(ParsedNode, Option<String>)>,
         body: Vec<ParsedNode>
     },
-    WhileLoop {
-        condition: Vec<ParsedNode>
+    Try {
+        body: Vec<ParsedNode>,
+        handlers: Vec<(Vec<String>, Option<String>, Vec<ParsedNode>)>,
+        finally: Vec<ParsedNode>
+    },
+    Match {
+        subject: Box<ParsedNode>,
+        arms: Vec<(ParsedNode, Vec<Vec<ParsedNode>>, Vec<ParsedNode>)>
     },
     List {
         items: Vec<ParsedNode>
@@ -34,7 +140,22 @@ pub enum ParsedNode {
         items: Vec<ParsedNode>
     },
     Dict {
-        items: (Box<ParsedNode>, Box<ParsedNode>)
+        items: Vec<(ParsedNode, ParsedNode)>
+    },
+    Set {
+        items: Vec<ParsedNode>
+    },
+    Comprehension {
+        kind: String,
+        element: Box<ParsedNode>,
+        value: Option<Box<ParsedNode>>,
+        clauses: Vec<Clause>
+    },
+    Iterator {
+        kind: String,
+        sources: Vec<ParsedNode>,
+        position: Position,
+        start: i64
     },
     Int {
         val: Vec<char>
@@ -54,10 +175,15 @@ pub enum ParsedNode {
     IfChain {
         blocks: Vec<(Vec<Vec<ParsedNode>>, Vec<ParsedNode>)>
     },
+    Conditional {
+        condition: Vec<Vec<ParsedNode>>,
+        value: Box<ParsedNode>,
+        otherwise: Box<ParsedNode>
+    },
     Comparison {
         operator: Token,
-        left: Box<Vec<ParsedNode>>,
-        right: Box<Vec<ParsedNode>>
+        left: Vec<ParsedNode>,
+        right: Vec<ParsedNode>
     },
     Error {
         line_num: usize,
@@ -71,13 +197,87 @@ pub enum ParsedNode {
         add_sub: usize,
         value: Option<Box<ParsedNode>>
     },
+    Assert {
+        condition: Vec<Vec<ParsedNode>>,
+        message: Option<Box<ParsedNode>>,
+        line_num: usize,
+        line: String
+    },
+    Return {
+        value: Box<ParsedNode>
+    },
+    Global {
+        names: Vec<String>
+    },
+    MethodCall {
+        object: Box<ParsedNode>,
+        name: String,
+        params: Vec<ParsedNode>
+    },
+    Namespace {
+        variables: Variables
+    },
+    File {
+        handle: FileHandle,
+        name: String,
+        mode: String
+    },
+    Bytes {
+        val: Vec<u8>
+    },
+    ByteArray {
+        val: Buffer
+    },
+    Complex {
+        real: f64,
+        imag: f64
+    },
+    Coroutine {
+        task: Task
+    },
+    Await {
+        value: Box<ParsedNode>
+    },
+    MemoryView {
+        val: Buffer
+    },
+    Index {
+        object: Box<ParsedNode>,
+        parts: Vec<ParsedNode>
+    },
+    Attribute {
+        object: Box<ParsedNode>,
+        name: String
+    },
+    Decorated {
+        decorators: Vec<ParsedNode>,
+        function: Box<ParsedNode>
+    },
+    SetIndex {
+        object: Box<ParsedNode>,
+        parts: Vec<ParsedNode>,
+        value: Box<ParsedNode>
+    },
+    Code {
+        name: String,
+        mode: String,
+        body: Vec<ParsedNode>
+    },
+    //A type, as nooc() gives it back, which is the same as any other with its name
+    Type {
+        name: String
+    },
+    Line {
+        line_num: usize
+    },
     Null,
     Continue,
     Break,
+    Pass,
     Ignore
 }
 
-const KEYWORDS: [&str; 172] = [
+const KEYWORDS: [&str; 180] = [
     "iyo", "maaha", "ama", "gudub", "booliyan", "jooji", "Run", "Been",
     "Waxba", "keen", "ka", "sida", "tijaabi", "qabo", "ugu", "dambeyn",
     "xaqiiji", "kayd", "qayb", "tir", "hadduu", "haddii", "kale", "kastoo",
@@ -95,7 +295,8 @@ const KEYWORDS: [&str; 172] = [
     "KhaladMagceed", "KhaladLamaSameyn", "KhaladCelcelis", "NoocKhaldan",
     "KhaladQiimeyn", "KhaladEberUQeybin", "KhaladXiriixLaGoo", "KhaladXiriixLaDiid",
     "KhaladOgolaansho", "DigniinKeenid", "markuu",
-    "kamidmid", "kawad", "qoraalkadhig", "bartaanbaar",
+    "kamidmid", "kawad", "qoraalkadhig", "bartaanbaar", "mardambe", "sug",
+    "wareeg", "hurdo", "hawl", "wadajir", "kadib", "HawlLaJoojiyey",
     "kooxdhibco", "dhibco", "mashaquuqabtaa", "qoraalmid",
     "kakan", "sifotir", "samee", "ururbadalmeyn", "sifokeen",
     "lambarugaar", "makaydkoosocotaa", "makaydkuudhaxlay",
@@ -121,14 +322,21 @@ const KEYWORDS: [&str; 172] = [
 #[derive(Debug)]
 pub struct Parser {
     pub tokens: Vec<Token>,
-    pub position: usize
+    pub position: usize,
+    pub line_offset: usize,
+    //Where the statement being parsed starts, so an error in a piece cut out of it can still say where it is
+    pub statement: usize,
+    pub errors: Vec<ParsedNode>
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
         Self {
-            tokens: tokens,
-            position: 0
+            tokens,
+            position: 0,
+            line_offset: 0,
+            statement: 0,
+            errors: vec![]
         }
     }
 
@@ -154,854 +362,2113 @@ impl Parser {
         return position;
     }
 
-    pub fn get_function_call(
+    pub fn newline_indent(
         &mut self,
-        word: Vec<char>,
-        tokens: Vec<Token>,
+        tokens: &[Token],
         pos: usize
-    ) -> (ParsedNode, usize) {
-        let mut position = pos + 1;
-        let name: String = word.into_iter().collect();
-        let mut open_count: usize = 0;
-        let mut close_count: usize = 0;
-        let mut args: Vec<Vec<Token>> = Vec::new();
-        let mut arg_pos: usize = 0;
-        loop {
-            position = self.skip_unnecessary(
-                (&tokens).to_owned(),
-                (&position).to_owned()
-            );
-            if position >= tokens.len() {
-                break;
-            }
-
-            let token = &tokens[position];
-            position += 1;
-            if token == Token::OpenParen {
-                open_count += 1;
-            } else if token == Token::CloseParen {
-                if (close_count + 1) != open_count {
-                    close_count += 1;
-                } else {
-                    break;
-                }
-            } else if token == Token::Comma {
-                arg_pos += 1;
-            } else {
-                if args.len() != arg_pos + 1 {
-                    args.push(vec![token.clone()]);
-                } else {
-                    args[arg_pos].push(token.clone());
+    ) -> Option<usize> {
+        match &tokens[pos] {
+            Token::Whitespace(space) => {
+                let newline = space.iter().rposition(|&n| n == '\n');
+                if let Some(newline) = newline {
+                    return Some(space.len() - newline - 1);
                 }
             }
-        }
+            //Single-line comments swallow their newline, so the indent
+            //lives in the whitespace that follows them
+            Token::Comment(comment) if comment.last() == Some(&'\n') => {
+                if pos + 1 >= tokens.len() {
+                    return Some(0);
+                }
 
-        let mut params: Vec<ParsedNode> = vec![];
-        args.iter().for_each(|item| {
-            if item.len() == 1 {
-                match &item[0] {
-                    Token::Int(value) => {
-                        params.push(
-                            ParsedNode::Int { val: value.to_vec() }
-                            );
-                    },
-                    Token::Float(value) => {
-                        params.push(
-                            ParsedNode::Float { val: value.to_vec() }
-                            );
-                    },
-                    Token::Speech(value) => {
-                        params.push(
-                            ParsedNode::Str { val: value.into_iter().collect() }
-                            );
-                    },
-                    Token::Word(word) => {
-                        let word_str = word.iter().collect::<String>();
-                        if !KEYWORDS.contains(&(word_str.as_str())) {
-                            params.push(
-                                ParsedNode::Variable {
-                                    name: word_str.to_owned(),
-                                    exists: true,
-                                    add_sub: 0,
-                                    value: None
-                                }
-                            );
+                match &tokens[pos + 1] {
+                    Token::Whitespace(space) => {
+                        if !space.contains(&'\n') {
+                            return Some(space.len());
                         }
-                    },
-                    _ => { }
+                    }
+                    _ => {
+                        return Some(0);
+                    }
                 }
             }
-        });
+            _ => { }
+        }
 
-        return (ParsedNode::FunctionCall {
-            name: name,
-            params: params
-        }, position)
+        return None;
     }
 
-    const OPERATIONS: [Token; 6] = [
-        Token::Plus,
-        Token::Minus,
-        Token::Divide,
-        Token::Multiply,
-        Token::Power,
-        Token::Modulus
-    ];
-
-    pub fn get_num_or_parse(
+    pub fn line_indent(
         &mut self,
-        custom: bool,
-        token_list: Option<Vec<Token>>,
-        pos: Option<usize>
-        ) -> (ParsedNode, usize) {
-        let mut tokens = (&self.tokens).to_owned();
-        let mut position = (&self.position).to_owned();
-
-        if custom && token_list.is_some() && pos.is_some() {
-            tokens = token_list.unwrap();
-            position = pos.unwrap();
-        }
-
-        let token = (&tokens[position]).to_owned();
-
-        //TODO: Make this work as a method
-        let mut next = position + 1;
-        loop {
-            if next >= tokens.len() {
-                next -= 1;
-                break;
-            }
-
-            match &tokens[next] {
-                Token::Whitespace(_) | Token::Comment(_) => {
-                    next += 1;
-                },
-                _ => { break; }
+        tokens: &[Token],
+        pos: usize
+    ) -> usize {
+        let mut position = pos;
+        while position > 0 {
+            position -= 1;
+            let indent = self.newline_indent(tokens, position);
+            if let Some(indent) = indent {
+                return indent;
             }
         }
 
-        let next = (&tokens[next]).to_owned();
-        if Self::OPERATIONS.contains(&next) {
-            let parsed =  self.parse_expression(tokens, position);
-
-            if !custom {
-                self.position = parsed.1;
+        match &tokens[0] {
+            Token::Whitespace(space) if pos > 0 => {
+                return space.len();
             }
+            _ => { }
+        }
 
-            return parsed;
-        } else {
-            position += 1;
-            if !custom {
-                self.position = position;
-            }
+        return 0;
+    }
 
-            match token {
-                Token::Int(val) => {
-                    return (ParsedNode::Int { val: val }, position);
-                },
-                Token::Float(val) => {
-                    return (ParsedNode::Float { val: val }, position);
-                },
+    pub fn line_end(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> usize {
+        let mut position = pos;
+        let mut depth: usize = 0;
+        while position < tokens.len() {
+            match &tokens[position] {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Whitespace(_) | Token::Comment(_) if depth == 0 && self.newline_indent(tokens, position).is_some() => {
+                    return position;
+                }
                 _ => { }
             }
-        }
 
-        if !custom {
-            self.position = position;
+            position += 1;
         }
 
-        return (ParsedNode::Ignore, position);
+        return position;
     }
 
-    pub fn operation_end(
+    pub fn get_block(
         &mut self,
-        tokens: Vec<Token>,
-        position: usize
-        ) -> usize {
-        let mut next = position + 1;
-        loop {
-            if next >= tokens.len() {
-                break;
-            }
-
-            let token = (&tokens[next]).to_owned();
-            match token {
-                Token::Whitespace(_) | Token::Comment(_) => {
-                    next += 1;
-                },
-                Token::Plus | Token::Minus | Token::Divide | Token::Multiply | Token::Modulus | Token::Power => {
-                    next += 1;
-                },
-                Token::Int(_) | Token::Float(_) => {
-                    next += 1;
-                },
-                _ => {
+        tokens: &[Token],
+        start: usize,
+        colon: usize
+    ) -> (Vec<Token>, usize) {
+        let indent_level = self.line_indent(tokens, start);
+        let mut position = colon + 1;
+        let mut block: Vec<Token> = vec![];
+
+        while position < tokens.len() {
+            let indent = self.newline_indent(tokens, position);
+            if indent.is_some() && indent.unwrap() <= indent_level {
+                //Lines holding only a comment don't close the block
+                let next = self.skip_unnecessary(tokens.to_vec(), position + 1);
+                let is_comment = matches!(tokens.get(position + 1), Some(Token::Comment(_)));
+
+                if next >= tokens.len() || !is_comment {
                     break;
                 }
             }
+
+            block.push(tokens[position].clone());
+            position += 1;
         }
 
-        return next;
+        return (block, position);
     }
 
-    pub fn parse_expression(
+    pub fn parse_block(
         &mut self,
-        tokens: Vec<Token>,
-        pos: usize
-        ) -> (ParsedNode, usize) {
-        let mut position = pos;
-        let mut operation_list: Vec<Token> = vec![];
-        let end = self.operation_end(tokens.clone(), pos);
+        tokens: &[Token],
+        start: usize,
+        colon: usize,
+        block: Vec<Token>
+    ) -> Vec<ParsedNode> {
+        if self.skip_unnecessary(block.clone(), 0) >= block.len() {
+            let keyword = tokens[start].text();
+            let error = ParsedNode::Error {
+                line_num: self.line_number(tokens, start),
+                line: self.source_line(tokens, start),
+                arrow: "".to_owned(),
+                error: format!("KhaladBeegmid: '{}' kadib waxaa la filayay qayb gudaha ah", keyword)
+            };
+
+            self.errors.push(error);
+            return vec![];
+        }
 
-        loop {
-            if position <= end && position < tokens.len() {
-                let token = (&tokens[position]).to_owned();
-                match token {
-                    Token::Whitespace(_) | Token::Comment(_) => {
-                        position += 1;
-                    },
-                    _ => {
-                        operation_list.push(token);
-                        position += 1;
-                    }
+        let mut parser = Parser::new(block);
+        parser.line_offset = self.line_number(tokens, colon + 1) - 1;
+        let parsed = parser.parse();
+        self.errors.append(&mut parser.errors);
+        return parsed;
+    }
+
+    pub fn line_number(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> usize {
+        let newlines: usize = tokens.iter()
+            .take(pos)
+            .map(|token| match token {
+                Token::Whitespace(val) | Token::Comment(val) | Token::Speech(val) => {
+                    val.iter().filter(|&c| c == &'\n').count()
                 }
-            } else {
-                break;
-            }
-        }
+                _ => 0
+            })
+            .sum();
 
-        return (ParsedNode::Equation { items: operation_list }, position);
+        return self.line_offset + newlines + 1;
+    }
+
+    pub fn source_line(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> String {
+        let end = self.line_end(tokens, pos);
+        let line: String = tokens[pos..end].iter().map(|token| token.text()).collect();
+        return line.trim().to_owned();
     }
 
-    pub fn is_still_if(
+    pub fn split_items(
         &mut self,
         tokens: Vec<Token>,
-        position: usize
-    ) ->  (bool, usize) {
-        let mut non_whitespace: usize = position;
-        loop {
-            if non_whitespace >= tokens.len() {
-                non_whitespace -= 1;
-                break;
+        separator: Token
+    ) -> Vec<Vec<Token>> {
+        let mut items: Vec<Vec<Token>> = vec![];
+        let mut current: Vec<Token> = vec![];
+        let mut depth: usize = 0;
+        //The depths of laamda headers still waiting for their colon, since
+        //the commas between a laamda's params don't separate items
+        let mut lambdas: Vec<usize> = vec![];
+
+        for token in tokens {
+            match &token {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Word(word) if word.iter().collect::<String>() == "laamda" => {
+                    lambdas.push(depth);
+                }
+                Token::Colon if lambdas.last() == Some(&depth) => {
+                    lambdas.pop();
+                    current.push(token);
+                    continue;
+                }
+                _ => { }
             }
 
-            match &tokens[non_whitespace] {
-                Token::Whitespace(_) | Token::Comment(_) => {
-                    non_whitespace += 1;
-                },
-                _ => { break; }
+            if depth == 0 && !lambdas.contains(&0) && token == separator {
+                items.push(current);
+                current = vec![];
+                continue;
             }
+
+            current.push(token);
         }
 
-        if non_whitespace == tokens.len() - 1 {
-            return (true, non_whitespace)
+        let position = self.skip_unnecessary(current.clone(), 0);
+        if position < current.len() {
+            items.push(current);
         }
 
-        if non_whitespace < tokens.len() {
-            let token = (&tokens[non_whitespace]).to_owned();
-            match token {
-                Token::Word(word) => {
-                    if &word.iter().collect::<String>() == "haddii" || &word.iter().collect::<String>() == "ama" {
-                        return (true, non_whitespace);
+        return items;
+    }
+
+    pub fn closing_bracket(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> usize {
+        let mut position = pos;
+        let mut depth: usize = 0;
+        while position < tokens.len() {
+            match &tokens[position] {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return position;
                     }
                 }
                 _ => { }
             }
+
+            position += 1;
         }
 
-        return (false, non_whitespace)
+        return position;
     }
 
-    pub fn get_if_parsed(
+    pub fn get_collection(
         &mut self,
         tokens: Vec<Token>,
-        loc: usize
+        pos: usize
     ) -> (ParsedNode, usize) {
-        let mut position = loc;
-        let mut indent_level = 0;
-
-        if position > 1 {
-            match (&tokens[position - 1]).to_owned() {
-                Token::Whitespace(space) => {
-                    if space[space.len() - 1] != '\n' {
-                        let pos = space.iter().position(|&n| n == '\n').unwrap();
-                        indent_level = space.len() - (pos + 1);
+        let open = tokens[pos].to_owned();
+        let close = self.closing_bracket(&tokens, pos);
+        let inner: Vec<Token> = tokens.iter()
+            .enumerate()
+            .filter(|(i, _)| i > &pos && i < &close)
+            .map(|(_, v)| v.clone())
+            .collect();
+
+        if self.is_comprehension(&inner) {
+            return (self.get_comprehension(&open, inner), close + 1);
+        }
+
+        let parts = self.split_items(inner.clone(), Token::Comma);
+        let is_dict = open == Token::OpenBrace && (parts.is_empty() || self.split_items(parts[0].clone(), Token::Colon).len() == 2);
+
+        if is_dict {
+            let mut items: Vec<(ParsedNode, ParsedNode)> = vec![];
+            for part in parts {
+                let pair = self.split_items(part, Token::Colon);
+                if pair.len() == 2 {
+                    items.push((
+                        self.next_node(true, Some(pair[0].clone()), Some(0)).0,
+                        self.next_node(true, Some(pair[1].clone()), Some(0)).0
+                    ));
+                }
+            }
+
+            return (ParsedNode::Dict { items }, close + 1);
+        }
+
+        let mut items: Vec<ParsedNode> = vec![];
+        for part in parts {
+            items.push(self.next_node(true, Some(part), Some(0)).0);
+        }
+
+        match open {
+            Token::OpenBrack => {
+                return (ParsedNode::List { items }, close + 1);
+            }
+            Token::OpenBrace => {
+                return (ParsedNode::Set { items }, close + 1);
+            }
+            _ => { }
+        }
+
+        //A bracketed expression is only a tuple if it has a comma in it
+        let has_comma = inner.contains(&Token::Comma);
+        if items.len() == 1 && !has_comma {
+            return (items[0].clone(), close + 1);
+        }
+
+        return (ParsedNode::Tuple { items }, close + 1);
+    }
+
+    pub fn keyword_positions(
+        &mut self,
+        tokens: &[Token],
+        word: &str
+    ) -> Vec<usize> {
+        let mut positions: Vec<usize> = vec![];
+        let mut depth: usize = 0;
+        for (position, token) in tokens.iter().enumerate() {
+            match token {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ => {
+                    if depth == 0 && self.is_word(tokens, position, word) {
+                        positions.push(position);
+                    }
+                }
+            }
+        }
+
+        return positions;
+    }
+
+    pub fn is_comprehension(
+        &mut self,
+        tokens: &[Token]
+    ) -> bool {
+        return !self.keyword_positions(tokens, "kastoo").is_empty();
+    }
+
+    pub fn loop_names_start(
+        &mut self,
+        tokens: &[Token],
+        kastoo: usize
+    ) -> usize {
+        //The names are the comma separated words straight before kastoo, so
+        //in [x * 2 x kastoo a kujira] only the second x is a loop name
+        let mut start = kastoo;
+        let mut position = kastoo;
+        loop {
+            while position > 0 && matches!(tokens[position - 1], Token::Whitespace(_)) {
+                position -= 1;
+            }
+
+            if position == 0 {
+                break;
+            }
+
+            match &tokens[position - 1] {
+                Token::Word(word) if !KEYWORDS.contains(&(word.iter().collect::<String>().as_str())) => {
+                    position -= 1;
+                    start = position;
+                }
+                _ => { break; }
+            }
+
+            while position > 0 && matches!(tokens[position - 1], Token::Whitespace(_)) {
+                position -= 1;
+            }
+
+            if position == 0 || tokens[position - 1] != Token::Comma {
+                break;
+            }
+
+            position -= 1;
+        }
+
+        return start;
+    }
+
+    pub fn get_comprehension(
+        &mut self,
+        open: &Token,
+        tokens: Vec<Token>
+    ) -> ParsedNode {
+        let loops = self.keyword_positions(&tokens, "kastoo");
+        let ins = self.keyword_positions(&tokens, "kujira");
+        let starts: Vec<usize> = loops.iter().map(|&kastoo| self.loop_names_start(&tokens, kastoo)).collect();
+
+        let mut clauses: Vec<Clause> = vec![];
+        for (index, &kastoo) in loops.iter().enumerate() {
+            let end = if index + 1 < starts.len() { starts[index + 1] } else { tokens.len() };
+            let kujira = ins.iter().find(|&&pos| pos > kastoo && pos < end);
+            //Each loop needs its names and kujira, and there has to be something to collect
+            if kujira.is_none() || starts[index] == kastoo || self.skip_unnecessary(tokens.clone(), 0) >= starts[0] {
+                return self.syntax_error(&tokens, 0, "KhaladHabQoraal: waxaa la filayay '[tibaax magac kastoo ... kujira]'");
+            }
+
+            let names: Vec<String> = tokens[starts[index]..kastoo].iter().filter_map(|token| match token {
+                Token::Word(word) => Some(word.iter().collect::<String>()),
+                _ => None
+            }).collect();
+
+            //The iterable sits between kastoo and kujira, and any hadduu filters come after
+            let kujira = *kujira.unwrap();
+            let iterable = self.next_node(true, Some(tokens[kastoo + 1..kujira].to_vec()), Some(0)).0;
+            let parts = self.split_items(tokens[kujira + 1..end].to_vec(), Token::Word("hadduu".chars().collect()));
+            let filters = parts.into_iter().skip(1).map(|part| self.get_condition(part)).collect();
+            clauses.push((names, iterable, filters));
+        }
+
+        let element = tokens[..starts[0]].to_vec();
+        let pair = self.split_items(element.clone(), Token::Colon);
+        if open == Token::OpenBrace && pair.len() == 2 {
+            return ParsedNode::Comprehension {
+                kind: "qaamuus".to_owned(),
+                element: Box::new(self.next_node(true, Some(pair[0].clone()), Some(0)).0),
+                value: Some(Box::new(self.next_node(true, Some(pair[1].clone()), Some(0)).0)),
+                clauses
+            };
+        }
+
+        let kind = match open {
+            Token::OpenBrack => "aruur",
+            Token::OpenBrace => "urur",
+            _ => "dhaliye"
+        };
+
+        return ParsedNode::Comprehension {
+            kind: kind.to_owned(),
+            element: Box::new(self.next_node(true, Some(element), Some(0)).0),
+            value: None,
+            clauses
+        };
+    }
+
+    pub fn get_function_call(
+        &mut self,
+        word: Vec<char>,
+        tokens: Vec<Token>,
+        pos: usize
+    ) -> (ParsedNode, usize) {
+        let name: String = word.into_iter().collect();
+        let open = self.skip_unnecessary(tokens.clone(), pos + 1);
+        let (params, end) = self.get_call_params(&tokens, open);
+
+        return (ParsedNode::FunctionCall {
+            name,
+            params
+        }, end)
+    }
+
+    //The arguments between the brackets that open at `open`, and where the call ends
+    pub fn get_call_params(
+        &mut self,
+        tokens: &[Token],
+        open: usize
+    ) -> (Vec<ParsedNode>, usize) {
+        let close = self.closing_bracket(tokens, open);
+        let inner: Vec<Token> = tokens.iter()
+            .enumerate()
+            .filter(|(i, _)| i > &open && i < &close)
+            .map(|(_, v)| v.clone())
+            .collect();
+
+        //A lone comprehension needs no brackets of its own, e.g. iskudar(x x kastoo a kujira)
+        if self.is_comprehension(&inner) {
+            return (vec![self.get_comprehension(&Token::OpenParen, inner)], close + 1);
+        }
+
+        let mut params: Vec<ParsedNode> = vec![];
+        for arg in self.split_items(inner, Token::Comma) {
+            //Keyword arguments come back as assignments, e.g. fure=qiimahasugan
+            let node = self.next_node(true, Some(arg), Some(0)).0;
+            if node != ParsedNode::Ignore {
+                params.push(node);
+            }
+        }
+
+        return (params, close + 1);
+    }
+
+    const OPERATIONS: [Token; 6] = [
+        Token::Plus,
+        Token::Minus,
+        Token::Divide,
+        Token::Multiply,
+        Token::Power,
+        Token::Modulus
+    ];
+
+    pub fn get_num_or_parse(
+        &mut self,
+        custom: bool,
+        token_list: Option<Vec<Token>>,
+        pos: Option<usize>
+        ) -> (ParsedNode, usize) {
+        let mut tokens = self.tokens.to_owned();
+        let mut position = self.position.to_owned();
+
+        if let (true, Some(token_list), Some(pos)) = (custom, token_list, pos) {
+            tokens = token_list;
+            position = pos;
+        }
+
+        let token = tokens[position].to_owned();
+
+        //TODO: Make this work as a method
+        let mut next = position + 1;
+        loop {
+            if next >= tokens.len() {
+                next -= 1;
+                break;
+            }
+
+            match &tokens[next] {
+                Token::Whitespace(_) | Token::Comment(_) => {
+                    next += 1;
+                },
+                _ => { break; }
+            }
+        }
+
+        let next = tokens[next].to_owned();
+        if Self::OPERATIONS.contains(&next) {
+            let parsed =  self.parse_expression(tokens, position);
+
+            if !custom {
+                self.position = parsed.1;
+            }
+
+            return parsed;
+        } else {
+            position += 1;
+            if !custom {
+                self.position = position;
+            }
+
+            match token {
+                Token::Int(val) => {
+                    return (ParsedNode::Int { val }, position);
+                },
+                Token::Float(val) => {
+                    if val.last() == Some(&'j') {
+                        let imag = val[..val.len() - 1].iter().collect::<String>().parse::<f64>().unwrap_or(0.0);
+                        return (ParsedNode::Complex { real: 0.0, imag }, position);
+                    }
+
+                    return (ParsedNode::Float { val }, position);
+                },
+                _ => { }
+            }
+        }
+
+        if !custom {
+            self.position = position;
+        }
+
+        return (ParsedNode::Ignore, position);
+    }
+
+    pub fn operation_end(
+        &mut self,
+        tokens: Vec<Token>,
+        position: usize
+        ) -> usize {
+        let mut next = position + 1;
+        let mut depth: usize = 0;
+        if tokens[position] == Token::OpenParen {
+            depth = 1;
+        }
+
+        loop {
+            if next >= tokens.len() {
+                break;
+            }
+
+            let token = tokens[next].to_owned();
+            match token {
+                Token::Whitespace(_) | Token::Comment(_) => {
+                    if depth == 0 && self.newline_indent(&tokens, next).is_some() {
+                        break;
+                    }
+
+                    next += 1;
+                },
+                Token::Plus | Token::Minus | Token::Divide | Token::Multiply | Token::Modulus | Token::Power => {
+                    next += 1;
+                },
+                //A dot after a name is one of its attributes, e.g. z.male
+                Token::Int(_) | Token::Float(_) | Token::Dot => {
+                    next += 1;
+                },
+                Token::OpenParen | Token::OpenBrack => {
+                    depth += 1;
+                    next += 1;
+                },
+                Token::CloseParen | Token::CloseBrack => {
+                    if depth == 0 {
+                        break;
+                    }
+
+                    depth -= 1;
+                    next += 1;
+                },
+                Token::Word(word) => {
+                    //Keywords such as iyo or kujira end the equation, unless they're calls
+                    let word_str = word.iter().collect::<String>();
+                    if depth == 0 && KEYWORDS.contains(&(word_str.as_str())) && !self.is_call(&tokens, next) {
+                        break;
+                    }
+
+                    next += 1;
+                },
+                _ => {
+                    if depth == 0 {
+                        break;
+                    }
+
+                    next += 1;
+                }
+            }
+        }
+
+        return next;
+    }
+
+    pub fn parse_expression(
+        &mut self,
+        tokens: Vec<Token>,
+        pos: usize
+        ) -> (ParsedNode, usize) {
+        let mut position = pos;
+        let mut operation_list: Vec<Token> = vec![];
+        let end = self.operation_end(tokens.clone(), pos);
+
+        loop {
+            if position < end && position < tokens.len() {
+                let token = tokens[position].to_owned();
+                match token {
+                    Token::Whitespace(_) | Token::Comment(_) => {
+                        position += 1;
+                    },
+                    _ => {
+                        operation_list.push(token);
+                        position += 1;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+
+        return (ParsedNode::Equation { items: operation_list }, position);
+    }
+
+    pub fn is_call(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        let next = self.skip_unnecessary(tokens.to_vec(), pos + 1);
+        return next < tokens.len() && tokens[next] == Token::OpenParen;
+    }
+
+    pub fn continues_equation(
+        &mut self,
+        tokens: &[Token],
+        close: usize
+    ) -> bool {
+        let next = self.skip_unnecessary(tokens.to_vec(), close + 1);
+        return next < tokens.len() && Self::OPERATIONS.contains(&tokens[next]) && self.line_end(tokens, close + 1) > next;
+    }
+
+    pub fn continues_index(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        //a[0] + 1 and a.x + 1 are equations, while a[0] and a.x on their own are just an index and an attribute
+        let mut next = pos;
+        while next + 1 < tokens.len() {
+            if tokens[next] == Token::Dot && matches!(tokens[next + 1], Token::Word(_)) {
+                next += 2;
+            } else if tokens[next] == Token::OpenBrack || (next > pos && tokens[next] == Token::OpenParen) {
+                next = self.chain_end(tokens, next) + 1;
+            } else {
+                break;
+            }
+        }
+
+        return next > pos && self.continues_equation(tokens, next - 1);
+    }
+
+    //The last closing bracket of calls and indexes written one after another, e.g. the ] of f(1)(2)[0]
+    pub fn chain_end(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> usize {
+        let mut close = self.closing_bracket(tokens, pos);
+        while close + 1 < tokens.len() && [Token::OpenParen, Token::OpenBrack].contains(&tokens[close + 1]) {
+            close = self.closing_bracket(tokens, close + 1);
+        }
+
+        return close;
+    }
+
+    //Keeps a KhaladHabQoraal about the line `pos` is on and hands it back as the node
+    pub fn syntax_error(
+        &mut self,
+        tokens: &[Token],
+        pos: usize,
+        message: &str
+    ) -> ParsedNode {
+        //Pieces of a statement, such as the value of an assignment, start their own count,
+        //so the error goes on the statement they were cut from instead
+        if tokens != &self.tokens[..] && self.statement < self.tokens.len() {
+            let (all, start) = (self.tokens.clone(), self.statement);
+            return self.syntax_error(&all, start, message);
+        }
+
+        let error = ParsedNode::Error {
+            line_num: self.line_number(tokens, pos),
+            line: self.source_line(tokens, pos),
+            arrow: "".to_owned(),
+            error: message.to_owned()
+        };
+
+        self.errors.push(error.clone());
+        return error;
+    }
+
+    pub fn block_colon(
+        &mut self,
+        tokens: &[Token],
+        pos: usize,
+        end: usize
+    ) -> Option<usize> {
+        let mut depth: usize = 0;
+        for (position, token) in tokens.iter().enumerate().take(end).skip(pos) {
+            match token {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Colon if depth == 0 => {
+                    return Some(position);
+                }
+                _ => { }
+            }
+        }
+
+        return None;
+    }
+
+    pub fn is_word(
+        &mut self,
+        tokens: &[Token],
+        pos: usize,
+        word: &str
+    ) -> bool {
+        return pos < tokens.len() && tokens[pos] == Token::Word(word.chars().collect());
+    }
+
+    pub fn is_else(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        return self.is_word(tokens, pos, "haddii") && pos + 2 < tokens.len() && tokens[pos + 1] == Token::Minus && self.is_word(tokens, pos + 2, "kale");
+    }
+
+    pub fn is_finally(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        return self.is_word(tokens, pos, "ugu") && pos + 2 < tokens.len() && tokens[pos + 1] == Token::Minus && self.is_word(tokens, pos + 2, "dambeyn");
+    }
+
+    pub fn is_conditional(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        //Statements such as hadduu, celi or qayb handle any conditional in their own parts
+        if let Token::Word(word) = &tokens[pos] {
+            let word_str = word.iter().collect::<String>();
+            if KEYWORDS.contains(&(word_str.as_str())) && !["Run", "Been", "Waxba"].contains(&(word_str.as_str())) && !self.is_call(tokens, pos) {
+                return false;
+            }
+        }
+
+        //Only a hadduu that comes before any assignment, loop or block colon makes
+        //an expression, e.g. a hadduu b > 1 haddii-kale c
+        let end = self.line_end(tokens, pos);
+        let mut depth: usize = 0;
+        let mut condition: Option<usize> = None;
+        for position in pos..end {
+            match &tokens[position] {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ if depth > 0 => { }
+                Token::Colon if condition.is_none() => {
+                    return false;
+                }
+                Token::Assign if condition.is_none() => {
+                    let compared = (position > pos && [Token::Assign, Token::Less, Token::Greater].contains(&tokens[position - 1])) ||
+                        (position + 1 < end && tokens[position + 1] == Token::Assign);
+                    if !compared {
+                        return false;
+                    }
+                }
+                Token::Word(word) => {
+                    let word_str = word.iter().collect::<String>();
+                    if condition.is_none() && &word_str == "kastoo" {
+                        return false;
+                    } else if condition.is_none() && &word_str == "hadduu" && position > pos {
+                        condition = Some(position);
+                    } else if condition.is_some() && self.is_else(tokens, position) {
+                        return true;
+                    }
+                }
+                _ => { }
+            }
+        }
+
+        return false;
+    }
+
+    pub fn is_comparison(
+        &mut self,
+        tokens: &[Token],
+        pos: usize
+    ) -> bool {
+        if let Token::Word(word) = &tokens[pos] {
+            let word_str = word.iter().collect::<String>();
+            if KEYWORDS.contains(&(word_str.as_str())) && !["Run", "Been", "Waxba"].contains(&(word_str.as_str())) && !self.is_call(tokens, pos) {
+                return false;
+            }
+        }
+
+        //A comparison used as a value, e.g. x = a == b, as long as nothing
+        //before it makes the line an assignment, a loop or a block
+        let end = self.line_end(tokens, pos);
+        let mut depth: usize = 0;
+        for position in pos..end {
+            match &tokens[position] {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ if depth > 0 => { }
+                Token::Colon | Token::Comma => {
+                    return false;
+                }
+                Token::Assign => {
+                    return (position > pos && [Token::Assign, Token::Less, Token::Greater].contains(&tokens[position - 1])) ||
+                        (position + 1 < end && tokens[position + 1] == Token::Assign);
+                }
+                Token::Less | Token::Greater | Token::NotEqual => {
+                    return true;
+                }
+                Token::Word(word) => {
+                    let word_str = word.iter().collect::<String>();
+                    if ["kastoo", "hadduu"].contains(&(word_str.as_str())) {
+                        return false;
+                    }
+                }
+                _ => { }
+            }
+        }
+
+        return false;
+    }
+
+    pub fn get_comparison(
+        &mut self,
+        tokens: Vec<Token>,
+        pos: usize
+    ) -> (ParsedNode, usize) {
+        let end = self.line_end(&tokens, pos);
+        let condition = self.get_condition(tokens[pos..end].to_vec());
+
+        //It's worked out the same way as the condition of a hadduu, and gives Run or Been
+        return (ParsedNode::Conditional {
+            condition,
+            value: Box::new(ParsedNode::Bool { val: true }),
+            otherwise: Box::new(ParsedNode::Bool { val: false })
+        }, end);
+    }
+
+    pub fn get_conditional(
+        &mut self,
+        tokens: Vec<Token>,
+        pos: usize
+    ) -> (ParsedNode, usize) {
+        let end = self.line_end(&tokens, pos);
+        let condition = pos + self.keyword_positions(&tokens[pos..end], "hadduu")[0];
+        let mut otherwise = condition;
+        while !self.is_else(&tokens, otherwise) {
+            otherwise += 1;
+        }
+
+        //The condition takes any iyo and ama in it, and a conditional after
+        //haddii-kale chains on, e.g. a hadduu x haddii-kale b hadduu y haddii-kale c
+        let value = self.next_node(true, Some(tokens[pos..condition].to_vec()), Some(0)).0;
+        let parsed_condition = self.get_condition(tokens[condition + 1..otherwise].to_vec());
+        let other = self.next_node(true, Some(tokens[otherwise + 3..end].to_vec()), Some(0)).0;
+
+        return (ParsedNode::Conditional {
+            condition: parsed_condition,
+            value: Box::new(value),
+            otherwise: Box::new(other)
+        }, end);
+    }
+
+    pub fn get_if_parsed(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let mut position = loc;
+        let indent_level = self.line_indent(&tokens, loc);
+        let mut blocks: Vec<(Vec<Vec<ParsedNode>>, Vec<ParsedNode>)> = Vec::new();
+
+        loop {
+            let line_end = self.line_end(&tokens, position);
+            let colon = self.block_colon(&tokens, position, line_end);
+            if colon.is_none() {
+                let keyword = if self.is_else(&tokens, position) { "haddii-kale".to_owned() } else { tokens[position].text() };
+                let error = ParsedNode::Error {
+                    line_num: self.line_number(&tokens, position),
+                    line: self.source_line(&tokens, position),
+                    arrow: "".to_owned(),
+                    error: format!("KhaladHabQoraal: '{}' kadib waxaa la filayay ':'", keyword)
+                };
+
+                self.errors.push(error.clone());
+                return (error, line_end);
+            }
+
+            let colon = colon.unwrap();
+            let mut cond_start = position + 1;
+            if self.is_else(&tokens, position) {
+                cond_start = colon;
+            } else if self.is_word(&tokens, position, "ama") {
+                cond_start = self.skip_unnecessary(tokens.clone(), position + 1) + 1;
+            }
+
+            let condition: Vec<Token> = tokens.iter()
+                .enumerate()
+                .filter(|(i, _)| i >= &cond_start && i < &colon)
+                .map(|(_, v)| v.clone())
+                .collect();
+
+            let block = self.get_block(&tokens, position, colon);
+            let body = self.parse_block(&tokens, position, colon, block.0);
+            blocks.push((
+                self.get_condition(condition),
+                body
+            ));
+            position = block.1;
+
+            let next = self.skip_unnecessary(tokens.clone(), position);
+            if next >= tokens.len() || self.line_indent(&tokens, next) != indent_level {
+                break;
+            }
+
+            let is_elif = self.is_word(&tokens, next, "ama") && {
+                let after = self.skip_unnecessary(tokens.clone(), next + 1);
+                self.is_word(&tokens, after, "hadduu")
+            };
+
+            if !is_elif && !self.is_else(&tokens, next) {
+                break;
+            }
+
+            position = next;
+        }
+
+        return (ParsedNode::IfChain { blocks }, position);
+    }
+
+    pub fn get_condition(
+        &mut self,
+        tokens: Vec<Token>
+        ) -> Vec<Vec<ParsedNode>> {
+        let mut parsed_cond: Vec<Vec<ParsedNode>> = Vec::new();
+        let or_divisions: Vec<Vec<Vec<Token>>> = self.get_or_separated(tokens);
+        for or_list in or_divisions {
+            let mut combined: Vec<ParsedNode> = Vec::new();
+            for and_cond in or_list {
+
+                let assign = and_cond.iter().rev().position(|r| r == Token::Assign);
+                let mut cond_type = Token::Ignore;
+                let mut op_index: usize = 0;
+                let mut op_len: usize = 1;
+                if let Some(assign) = assign {
+                    let assign_index = and_cond.len() - assign - 1;
+                    let prev_token = and_cond[assign_index - 1].to_owned();
+                    op_index = assign_index - 1;
+                    op_len = 2;
+
+                    match prev_token {
+                        Token::Assign => {
+                            cond_type = Token::Equal;
+                        }
+                        Token::Less => {
+                            cond_type = Token::LessOrEqual;
+                        }
+                        Token::Greater => {
+                            cond_type = Token::GreaterOrEqual;
+                        }
+                        _ => { }
+                    }
+                } else {
+                    let compare = and_cond.iter().position(|r| r == Token::Less || r == Token::Greater || r == Token::NotEqual);
+                    if let Some(compare) = compare {
+                        op_index = compare;
+                        cond_type = and_cond[op_index].to_owned();
+                    }
+                }
+
+                if cond_type != Token::Ignore {
+                    let mut comp_one: Vec<Token> = Vec::new();
+                    let mut comp_two: Vec<Token> = Vec::new();
+                    for (index, token) in and_cond.iter().enumerate() {
+                        if index < op_index {
+                            comp_one.push(token.clone());
+                        } else if index >= op_index + op_len {
+                            comp_two.push(token.clone());
+                        }
+                    }
+
+                    let parsed_one = self.get_parsed_comp(comp_one);
+                    let parsed_two = self.get_parsed_comp(comp_two);
+                    let comparison  = ParsedNode::Comparison {
+                        operator: cond_type,
+                        left: parsed_one.clone(),
+                        right: parsed_two.clone()
+                    };
+
+                    combined.push(comparison);
+                } else {
+                    for token in and_cond {
+                        if let Token::Word(word) = token {
+                            let word_str = word.to_vec().iter().collect::<String>();
+                            if &word_str == "Run" {
+                                combined.push(
+                                    ParsedNode::Bool { val: true }
+                                );
+                            } else if &word_str == "Been" {
+                                combined.push(
+                                    ParsedNode::Bool { val: false }
+                                );
+                            } else if &word_str == "Waxba" {
+                                combined.push(
+                                    ParsedNode::Null
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            parsed_cond.push(combined);
+        }
+
+        return parsed_cond;
+    }
+
+    pub fn get_or_separated(
+        &mut self,
+        tokens: Vec<Token>
+        ) -> Vec<Vec<Vec<Token>>> {
+        let or_split = tokens
+            .split(|v| v == Token::Word(vec!['a', 'm', 'a']));
+
+        let mut splits: Vec<Vec<Vec<Token>>> = Vec::new();
+        for or in or_split {
+            let and_split = or
+                .split(|v| v == Token::Word(vec!['i', 'y', 'o']));
+
+            let mut normal_and_split: Vec<Vec<Token>> = Vec::new();
+            for and in and_split {
+                normal_and_split.push(and.to_owned());
+            }
+
+            splits.push(normal_and_split);
+        }
+
+        return splits;
+    }
+
+    pub fn get_parsed_comp(
+        &mut self,
+        comp: Vec<Token>
+        ) -> Vec<ParsedNode> {
+        let mut parsed_comp: Vec<ParsedNode> = Vec::new();
+        let mut index = 0;
+
+        while index < comp.len() {
+            let token = comp[index].to_owned();
+            match token {
+                Token::Word(word) => {
+                    let word_str = word.iter().collect::<String>();
+                    if &word_str == "Run" || &word_str == "Been" {
+                        parsed_comp.push(ParsedNode::Bool { val: &word_str == "Run" });
+                    } else if !KEYWORDS.contains(&(word_str.as_str())) || self.is_call(&comp, index) || Inbuilt::is_type_name(&word_str) {
+                        //Calls, equations and types are handled the same way as anywhere else
+                        let parsed = self.next_node(true, Some(comp.clone()), Some(index));
+                        parsed_comp.push(parsed.0);
+                        index = parsed.1.max(index + 1);
+                        continue;
+                    }
+                }
+                Token::Int(_) | Token::Float(_) => {
+                    let parsed = self.get_num_or_parse(
+                        true,
+                        Some(comp.clone()),
+                        Some(index)
+                        );
+                    parsed_comp.push(parsed.0);
+                    index = parsed.1.max(index + 1);
+                    continue;
+                }
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace | Token::Speech(_) | Token::Minus => {
+                    let parsed = self.next_node(true, Some(comp.clone()), Some(index));
+                    parsed_comp.push(parsed.0);
+                    index = parsed.1.max(index + 1);
+                    continue;
+                }
+                _ => { }
+            }
+
+            index += 1;
+        }
+
+        return parsed_comp;
+    }
+
+    pub fn next_node(
+        &mut self,
+        custom: bool,
+        token_list: Option<Vec<Token>>,
+        pos: Option<usize>
+    ) -> (ParsedNode, usize) {
+        let mut tokens = self.tokens.to_owned();
+        let mut position = self.position.to_owned();
+
+        if let (true, Some(token_list), Some(pos)) = (custom, token_list, pos) {
+            tokens = token_list;
+            position = pos;
+        }
+
+        position = self.skip_unnecessary(tokens.clone(), position);
+        let mut next = position + 1;
+        loop {
+            if next >= tokens.len() {
+                next -= 1;
+                break;
+            }
+
+            match &tokens[next] {
+                Token::Whitespace(_) | Token::Comment(_) => {
+                    next += 1;
+                },
+                _ => { break; }
+            }
+        }
+
+        if position >= tokens.len() {
+            return (ParsedNode::Ignore, position);
+        }
+
+        if self.is_conditional(&tokens, position) {
+            let parsed = self.get_conditional(tokens, position);
+            if !custom && self.position < parsed.1 {
+                self.position = parsed.1;
+            }
+
+            return parsed;
+        }
+
+        if self.is_comparison(&tokens, position) {
+            let parsed = self.get_comparison(tokens, position);
+            if !custom && self.position < parsed.1 {
+                self.position = parsed.1;
+            }
+
+            return parsed;
+        }
+
+        let mut node = ParsedNode::Ignore;
+        match &tokens[position] {
+            Token::Word(word) => {
+                let word_str = word.to_vec().iter().collect::<String>();
+
+                if tokens[next] == Token::OpenParen && self.is_index_assignment(&tokens, position) {
+                    let assigned = self.get_assignment(tokens.clone(), position);
+                    position = assigned.1;
+                    node = assigned.0;
+                } else if tokens[next] == Token::OpenParen {
+                    let close = self.chain_end(&tokens, next);
+                    if self.continues_equation(&tokens, close) {
+                        let parsed = self.parse_expression(tokens.clone(), position);
+                        node = parsed.0;
+                        position = parsed.1;
+                    } else {
+                        let func = self.get_function_call(word.to_vec(), tokens.clone(), position);
+                        node = func.0;
+                        position = func.1;
+                    }
+                } else if &word_str == "qayb" {
+                    let parsed = self.get_function(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "celi" {
+                    let line_end = self.line_end(&tokens, position);
+                    let value = self.next_node(true, Some(tokens[position + 1..line_end].to_vec()), Some(0)).0;
+                    node = ParsedNode::Return {
+                        value: Box::new(if value == ParsedNode::Ignore { ParsedNode::Null } else { value })
+                    };
+                    position = line_end;
+                } else if &word_str == "caalami" {
+                    let line_end = self.line_end(&tokens, position);
+                    let names: Vec<String> = tokens[position + 1..line_end].iter().filter_map(|token| match token {
+                        Token::Word(word) => Some(word.iter().collect::<String>()),
+                        _ => None
+                    }).collect();
+
+                    node = ParsedNode::Global { names };
+                    position = line_end;
+                } else if &word_str == "laamda" {
+                    let parsed = self.get_lambda(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "hadduu" {
+                    let parsed = self.get_if_parsed(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "xaqiiji" {
+                    let parsed = self.get_assert(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "markuu" {
+                    let parsed = self.get_match(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "isticmaal" {
+                    let parsed = self.get_with(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "tijaabi" {
+                    let parsed = self.get_try(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "mardambe" {
+                    let parsed = self.get_async(tokens.clone(), position);
+                    position = parsed.1;
+                    node = parsed.0;
+                } else if &word_str == "sug" {
+                    let line_end = self.line_end(&tokens, position);
+                    let value = self.next_node(true, Some(tokens[position + 1..line_end].to_vec()), Some(0)).0;
+                    node = ParsedNode::Await { value: Box::new(value) };
+                    position = line_end;
+                } else if &word_str == "Run" { 
+                    let value = tokens[next] != Token::Word(vec!['a', 'h', 'e', 'y', 'n']);
+                    node = ParsedNode::Bool { val: value };
+                    position += 1;
+
+                    if !value {
+                        position = next + 1;
                     }
-                },
-                _ => { }
+                } else if &word_str == "Been" {
+                    let value = tokens[next] == Token::Word(vec!['a', 'h', 'e', 'y', 'n']);
+                    node = ParsedNode::Bool { val: value };
+                    position += 1;
+
+                    if value {
+                        position = next + 1;
+                    }
+                } else if &word_str == "Waxba" {
+                    node = ParsedNode::Null;
+                    position += 1;
+                } else if &word_str == "gudub" {
+                    node = ParsedNode::Continue;
+                    position += 1;
+                } else if &word_str == "jooji" {
+                    node = ParsedNode::Break;
+                    position += 1;
+                } else if &word_str == "dhaaf" {
+                    node = ParsedNode::Pass;
+                    position += 1;
+                } else if self.is_assignment(tokens.clone(), position) {
+                    let assigned = self.get_assignment(tokens.clone(), position);
+                    position = assigned.1;
+                    node = assigned.0;
+                } else if Inbuilt::is_type_name(&word_str) {
+                    node = ParsedNode::Type { name: word_str };
+                    position += 1;
+                } else if Inbuilt::new().has_method(&word_str) {
+                    node = ParsedNode::Function {
+                        name: word_str,
+                        params: vec![],
+                        body: vec![],
+                        attributes: Attributes::default(),
+                        asynchronous: false,
+                        closure: vec![]
+                    };
+                    position += 1;
+                } else if !KEYWORDS.contains(&(word_str.as_str())) {
+                    if next < tokens.len() {
+                        if self.is_for_loop(&tokens, position) {
+                            let parsed_loop = self.get_for_loop(tokens, position);
+                            if !custom {
+                                self.position = parsed_loop.1;
+                            }
+
+                            return parsed_loop;
+                        } else if self.continues_index(&tokens, next) {
+                            let parsed = self.parse_expression(tokens, position);
+                            if !custom {
+                                self.position = parsed.1;
+                            }
+
+                            return parsed;
+                        } else if Self::OPERATIONS.contains(&tokens[next]) {
+                           let parsed = self.get_num_or_parse(custom, Some(tokens), Some(position));
+
+                           if !custom {
+                               self.position = parsed.1;
+                           }
+
+                           return parsed;
+                        }
+                    }
+                    node = ParsedNode::Variable {
+                        name: word_str,
+                        exists: true,
+                        add_sub: 0,
+                        value: None
+                    };
+                    position += 1;
+                } else {
+                    position += 1;
+                }
+            },
+            Token::Int(_) => {
+                node = self.get_num_or_parse(custom, Some(tokens.clone()), Some(position)).0;
+            },
+            Token::Float(_) => {
+                node = self.get_num_or_parse(custom, Some(tokens.clone()), Some(position)).0;
+            },
+            Token::Speech(val) => {
+                position += 1;
+                if val[0] == 'b' {
+                    node = ParsedNode::Bytes { val: Inbuilt::byte_literal(&val[1..].iter().collect::<String>()) };
+                } else {
+                    node = ParsedNode::Str { val: Inbuilt::string_literal(&val.iter().collect::<String>()) }
+                }
+            },
+            Token::At => {
+                let parsed = self.get_decorated(tokens.clone(), position);
+                node = parsed.0;
+                position = parsed.1;
+            },
+            Token::Minus => {
+                let parsed = self.parse_expression(tokens.clone(), position);
+                node = parsed.0;
+                position = parsed.1;
+            },
+            Token::OpenBrack | Token::OpenParen | Token::OpenBrace => {
+                let close = self.closing_bracket(&tokens, position);
+                if tokens[position] == Token::OpenParen && self.continues_equation(&tokens, close) {
+                    let parsed = self.parse_expression(tokens.clone(), position);
+                    node = parsed.0;
+                    position = parsed.1;
+                } else {
+                    let collection = self.get_collection(tokens.clone(), position);
+                    node = collection.0;
+                    position = collection.1;
+                }
+            },
+            _ => { position += 1 }
+        }
+
+        match node {
+            ParsedNode::Variable { exists: true, .. } | ParsedNode::FunctionCall { .. } | ParsedNode::Str { .. } | ParsedNode::List { .. } |
+            ParsedNode::Bytes { .. } => {
+                let chained = self.get_method_calls(node, &tokens, position);
+                node = chained.0;
+                position = chained.1;
             }
+            _ => { }
+        }
+
+        if !custom && self.position < position {
+            self.position = position;
         }
 
-        let mut if_tokens: Vec<(Vec<Token>, usize)> = Vec::new();
-        let mut current_indent: usize = indent_level;
-        let mut if_pos: usize = 0;
+        return (node, position);
+    }
+
+    pub fn get_method_calls(
+        &mut self,
+        object: ParsedNode,
+        tokens: &[Token],
+        pos: usize
+    ) -> (ParsedNode, usize) {
+        let mut node = object;
+        let mut position = pos;
+
+        //Each .name, .name(...), (...) or [...] wraps what came before it, so they can be chained
         loop {
-            if position >= tokens.len() {
+            if position < tokens.len() && tokens[position] == Token::OpenParen {
+                let (params, end) = self.get_call_params(tokens, position);
+                node = ParsedNode::Call {
+                    function: Box::new(node),
+                    params
+                };
+
+                position = end;
+                continue;
+            }
+
+            if position < tokens.len() && tokens[position] == Token::OpenBrack {
+                let close = self.closing_bracket(tokens, position);
+                node = ParsedNode::Index {
+                    object: Box::new(node),
+                    parts: self.get_index_parts(tokens[position + 1..close].to_vec())
+                };
+
+                position = close + 1;
+                continue;
+            }
+
+            if position + 1 < tokens.len() && tokens[position] == Token::Dot && !self.is_call(tokens, position + 1) {
+                match &tokens[position + 1] {
+                    Token::Word(word) => {
+                        node = ParsedNode::Attribute {
+                            object: Box::new(node),
+                            name: word.iter().collect::<String>()
+                        };
+
+                        position += 2;
+                        continue;
+                    }
+                    _ => { break; }
+                }
+            }
+
+            if !(position + 2 < tokens.len() && tokens[position] == Token::Dot && tokens[position + 2] == Token::OpenParen) {
                 break;
             }
 
-            match (&tokens[position]).to_owned() {
-                Token::EOF => { 
-                    position += 1;
-                    break;
+            match &tokens[position + 1] {
+                Token::Word(word) => {
+                    let call = self.get_function_call(word.to_vec(), tokens.to_vec(), position + 1);
+                    if let ParsedNode::FunctionCall { name, params } = call.0 {
+                        node = ParsedNode::MethodCall {
+                            object: Box::new(node),
+                            name,
+                            params
+                        };
+                    }
+
+                    position = call.1;
                 }
-                Token::Whitespace(space) => {
-                    let has_newline = space.iter().position(|&n| n == '\n');
-                    if has_newline.is_some() {
-                        if if_pos < if_tokens.len() {
-                            if_tokens[if_pos].0.push(Token::Ignore);
-                        }
+                _ => { break; }
+            }
+        }
 
-                        let pos = has_newline.unwrap();
-                        let level = space.len() - (pos + 1);
-                        if current_indent != level {
-                            current_indent = level;
-                            if_pos += 1;
-                        }
+        return (node, position);
+    }
 
-                        if level == indent_level {
-                            let still = self.is_still_if(tokens.clone(), position);
-                            if still.0 {
-                                let token = (&tokens[position]).to_owned();
-                                if if_pos >= if_tokens.len() {
-                                    if_tokens.push((vec![token], current_indent));
-                                } else {
-                                    if_tokens[if_pos].0.push(token);
-                                }
-
-                                position += 1;
-                                continue;
-                            }
+    pub fn get_decorated(
+        &mut self,
+        tokens: Vec<Token>,
+        pos: usize
+    ) -> (ParsedNode, usize) {
+        //Each @name line wraps the qayb below it, the nearest one first
+        let mut decorators: Vec<ParsedNode> = vec![];
+        let mut position = pos;
+        while position < tokens.len() && tokens[position] == Token::At {
+            let end = self.line_end(&tokens, position);
+            decorators.push(self.next_node(true, Some(tokens[position + 1..end].to_vec()), Some(0)).0);
+            position = self.skip_unnecessary(tokens.clone(), end);
+        }
 
-                            break;
-                        }
+        if !self.is_word(&tokens, position, "qayb") && !self.is_word(&tokens, position, "mardambe") {
+            let error = ParsedNode::Error {
+                line_num: self.line_number(&tokens, pos),
+                line: self.source_line(&tokens, pos),
+                arrow: "".to_owned(),
+                error: "KhaladHabQoraal: '@' kadib waxaa la filayay qayb".to_owned()
+            };
 
-                    }
-                    position += 1;
+            self.errors.push(error.clone());
+            return (error, self.line_end(&tokens, pos));
+        }
+
+        let function = if self.is_word(&tokens, position, "mardambe") {
+            self.get_async(tokens, position)
+        } else {
+            self.get_function(tokens, position)
+        };
+
+        return (
+            ParsedNode::Decorated {
+                decorators,
+                function: Box::new(function.0)
+            },
+            function.1
+        );
+    }
+
+    pub fn get_index_parts(
+        &mut self,
+        tokens: Vec<Token>
+    ) -> Vec<ParsedNode> {
+        //A single part is an index, while two or three colon separated parts are a slice
+        let mut parts: Vec<Vec<Token>> = vec![vec![]];
+        let mut depth: usize = 0;
+        for token in tokens {
+            match token {
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
                 }
-                Token::Comment(_) => {
-                    position += 1;
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
                 }
-                x => {
-                    if if_pos >= if_tokens.len() {
-                        if_tokens.push((vec![x], current_indent));
-                    } else {
-                        if_tokens[if_pos].0.push(x);
-                    }
-
-                    position += 1;
+                Token::Colon if depth == 0 => {
+                    parts.push(vec![]);
+                    continue;
                 }
+                _ => { }
             }
+
+            let last = parts.len() - 1;
+            parts[last].push(token);
         }
 
-        return (self.parse_conditions(if_tokens), position);
+        return parts.into_iter().map(|part| {
+            if self.skip_unnecessary(part.clone(), 0) >= part.len() {
+                return ParsedNode::Null;
+            }
+
+            return self.next_node(true, Some(part), Some(0)).0;
+        }).collect();
     }
 
-    fn sanitise_cond(
+    pub fn is_for_loop(
         &mut self,
-        conditions: Vec<(Vec<Token>, usize)>
-    ) -> Vec<CondStruct> {
-        if conditions.len() > 1 {
-            let top_indent = conditions[0].1;
-            let mut pos = 0;
-            let mut new_conds: Vec<CondStruct> = vec![];
-            while pos < conditions.len() {
-                let current = conditions[pos].clone();
-                let tok_vec = current.0.clone();
-                if tok_vec.len() > 1 && tok_vec[tok_vec.len() - 2] == Token::Colon && current.1 != top_indent {
-                    pos += 1;
-                    let mut tokens: Vec<(Vec<Token>, usize)> = vec![current.clone()];
-                    while pos < conditions.len() {
-                        let next = conditions[pos].clone();
-                        if next.1 == current.1 {
-                            break;
-                        } else {
-                            tokens.push(next);
-                            pos += 1;
-                        }
+        tokens: &[Token],
+        position: usize
+    ) -> bool {
+        //Loops start with one or more comma separated names before kastoo
+        let line_end = self.line_end(tokens, position);
+        for token in &tokens[position..line_end] {
+            match token {
+                Token::Word(word) => {
+                    if word.iter().collect::<String>() == "kastoo" {
+                        return true;
                     }
+                }
+                Token::Comma | Token::Whitespace(_) => { }
+                _ => {
+                    return false;
+                }
+            }
+        }
 
-                    let last = tokens[0].0.split_last().unwrap().1;
-                    let has_loc = last.iter().rev().position(|v| v == Token::Ignore);
-                    if has_loc.is_some() {
-                        let loc = last.len() - has_loc.unwrap() - 1;
-                        let normal = last.iter().enumerate().filter(|(i, _)| i < &loc).map(|(_, v)| v);
-                        let scope = last.iter().enumerate().filter(|(i, _)| i > &loc).map(|(_, v)| v);
+        return false;
+    }
 
-                        let mut normal_tok: Vec<Token> = vec![];
-                        for token in normal {
-                            normal_tok.push(token.clone());
-                        }
+    pub fn get_for_loop(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let position = loc;
+        let line_end = self.line_end(&tokens, position);
 
-                        if normal_tok.len() > 0 {
-                            new_conds.push(CondStruct::Normal((normal_tok, tokens[0].1)));
-                        }
+        let name_end = tokens[position..line_end].iter().position(|pos| pos == Token::Word("kastoo".chars().collect()));
+        if name_end.is_none() {
+            return (self.syntax_error(&tokens, position, "KhaladHabQoraal: waxaa la filayay 'kastoo'"), line_end);
+        }
 
-                        let mut scope_tok: Vec<Token> = vec![];
-                        for token in scope {
-                            scope_tok.push(token.clone());
-                        }
+        let name_end = position + name_end.unwrap();
+        let iter_end = tokens[name_end..line_end].iter().position(|pos| pos == Token::Word("kujira".chars().collect()));
+        if iter_end.is_none() {
+            return (self.syntax_error(&tokens, position, "KhaladHabQoraal: 'kastoo' kadib waxaa la filayay 'kujira'"), line_end);
+        }
 
-                        tokens[0].0 = scope_tok;
-                        new_conds.push(CondStruct::Scope(tokens));
-                    } else {
-                        let mut new_if: Vec<Token> = vec![];
-                        let mut new_vec: Vec<Token> = vec![];
-                        let mut cond_ended = false;
-                        for (mut token, _) in tokens {
-                            if !cond_ended {
-                                for tok in token {
-                                    if tok == Token::Colon {
-                                        cond_ended = true;
-                                    }
-
-                                    new_if.push(tok);
-                                }
-                            } else {
-                                new_vec.append(&mut token);
-                            }
-                        }
+        let iter_end = name_end + iter_end.unwrap();
+        let colon = self.block_colon(&tokens, iter_end, line_end);
+        if colon.is_none() {
+            return (self.syntax_error(&tokens, position, "KhaladHabQoraal: 'kujira' kadib waxaa la filayay ':'"), line_end);
+        }
 
-                        new_conds.push(CondStruct::Normal((new_if, top_indent.clone())));
-                        new_conds.push(CondStruct::Normal((new_vec, top_indent.clone() + 2)));
-                    }
-                } else {
-                    new_conds.push(CondStruct::Normal(current));
-                    pos += 1;
+        let colon = colon.unwrap();
+
+        let mut names: Vec<String> = vec![];
+        for token in tokens.iter().enumerate().filter(|(i, _)| i >= &position && i < &name_end).map(|(_, v)| v) {
+            if let Token::Word(word) = token {
+                names.push(word.iter().collect::<String>());
+            }
+        }
+
+        let mut iterable: Vec<Token> = vec![];
+        for token in tokens.iter().enumerate().filter(|(i, _)| i > &name_end && i < &iter_end).map(|(_, v)| v) {
+            if let Token::Whitespace(_) = token {
+                continue;
+            }
+            iterable.push(token.to_owned());
+        }
+
+        let parsed_iterable = self.next_node(
+            true,
+            Some(iterable),
+            Some(0)
+        ).0;
+
+        let block = self.get_block(&tokens, position, colon);
+        let body = self.parse_block(&tokens, position, colon, block.0);
+
+        return (ParsedNode::ForLoop {
+            vars: names,
+            iterable: Box::new(parsed_iterable),
+            body,
+            asynchronous: false
+        }, block.1)
+    }
+
+    //mardambe goes in front of a qayb, or of a kastoo loop over a kamidmid
+    pub fn get_async(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let position = self.skip_unnecessary(tokens.clone(), loc + 1);
+        if self.is_word(&tokens, position, "qayb") {
+            let parsed = self.get_function(tokens, position);
+            match parsed.0 {
+                ParsedNode::Function { name, params, body, attributes, closure, .. } => {
+                    return (ParsedNode::Function {
+                        name,
+                        params,
+                        body,
+                        attributes,
+                        asynchronous: true,
+                        closure
+                    }, parsed.1);
+                }
+                other => {
+                    return (other, parsed.1);
                 }
             }
+        }
 
-            return new_conds;
+        if position < tokens.len() && self.is_for_loop(&tokens, position) {
+            let parsed = self.get_for_loop(tokens, position);
+            match parsed.0 {
+                ParsedNode::ForLoop { vars, iterable, body, .. } => {
+                    return (ParsedNode::ForLoop {
+                        vars,
+                        iterable,
+                        body,
+                        asynchronous: true
+                    }, parsed.1);
+                }
+                other => {
+                    return (other, parsed.1);
+                }
+            }
         }
 
-        let mut cond_vec: Vec<CondStruct> = vec![];
+        let error = ParsedNode::Error {
+            line_num: self.line_number(&tokens, loc),
+            line: self.source_line(&tokens, loc),
+            arrow: "".to_owned(),
+            error: "KhaladHabQoraal: 'mardambe' kadib waxaa la filayay qayb ama kastoo".to_owned()
+        };
+
+        self.errors.push(error.clone());
+        return (error, self.line_end(&tokens, loc));
+    }
 
-        for cond in conditions {
-            cond_vec.push(CondStruct::Normal(cond));
+    pub fn get_params(
+        &mut self,
+        tokens: Vec<Token>
+    ) -> Vec<ParsedNode> {
+        let mut params: Vec<ParsedNode> = vec![];
+        for param in self.split_items(tokens, Token::Comma) {
+            //Defaults are written as name=value and parse as assignments
+            let node = self.next_node(true, Some(param), Some(0)).0;
+            if let ParsedNode::Variable { .. } = node {
+                params.push(node);
+            }
         }
 
-        return cond_vec;
+        return params;
     }
 
-    pub fn parse_conditions(
+    pub fn get_function(
         &mut self,
-        conditions: Vec<(Vec<Token>, usize)>
-        ) -> ParsedNode {
-        let new_conditions = self.sanitise_cond(conditions.clone());
-        let mut parsed_conditions: Vec<(Vec<Vec<ParsedNode>>, Vec<ParsedNode>)> = Vec::new();
-
-        let if_indent = conditions[0].1;
-        for value in (&new_conditions).to_owned() {
-            let mut con: Option<(Vec<Token>, usize)> = None;
-            let mut scope: Option<Vec<(Vec<Token>, usize)>> = None;
-            let mut normal = true;
-            match value {
-                CondStruct::Scope(val) => {
-                    scope = Some(val.to_owned());
-                    normal = false;
-                }
-                CondStruct::Normal(val) => {
-                    con = Some(val.to_owned());
-                }
-            }
-
-            if normal && con.is_some() && con.clone().unwrap().1 == if_indent {
-                let cond = con.unwrap();
-                let mut parsed_cond: Vec<Vec<ParsedNode>> = Vec::new();
-                let or_divisions: Vec<Vec<Vec<Token>>> = self.get_or_separated((&cond.0).to_owned());
-                for or_list in or_divisions {
-                    let mut combined: Vec<ParsedNode> = Vec::new();
-                    for and_cond in or_list {
-
-                        let assign = and_cond.iter().rev().position(|r| r == Token::Assign);
-                        let mut cond_type = Token::Ignore;
-                        if assign.is_some() {
-                            let assign_index = and_cond.len() - assign.unwrap() - 1;
-                            let prev_token = (&and_cond[assign_index - 1]).to_owned();
-
-                            match prev_token {
-                                Token::Assign => {
-                                    cond_type = Token::Equal;
-                                }
-                                Token::Less => {
-                                    cond_type = Token::LessOrEqual;
-                                }
-                                Token::Greater => {
-                                    cond_type = Token::GreaterOrEqual;
-                                }
-                                _ => { }
-                            }
-                        }
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let line_end = self.line_end(&tokens, loc);
+        let name_pos = self.skip_unnecessary(tokens.clone(), loc + 1);
+        let name = match tokens.get(name_pos) {
+            Some(Token::Word(word)) if name_pos < line_end => word.iter().collect::<String>(),
+            _ => {
+                return (self.syntax_error(&tokens, loc, "KhaladHabQoraal: 'qayb' kadib waxaa la filayay magac"), line_end);
+            }
+        };
 
-                        if cond_type != Token::Ignore {
-                            let assign_index = and_cond.len() - assign.unwrap() - 1;
-                            let mut comp_one: Vec<Token> = Vec::new();
-                            let mut comp_two: Vec<Token> = Vec::new();
-                            for (index, token) in and_cond.iter().enumerate() {
-                                if index < assign_index - 1 {
-                                    comp_one.push(token.clone());
-                                } else if index > assign_index {
-                                    comp_two.push(token.clone());
-                                }
-                            }
+        let open = self.skip_unnecessary(tokens.clone(), name_pos + 1);
+        if open >= line_end || tokens[open] != Token::OpenParen {
+            return (self.syntax_error(&tokens, loc, &format!("KhaladHabQoraal: '{}' kadib waxaa la filayay '('", name)), line_end);
+        }
+
+        let close = self.closing_bracket(&tokens, open);
+        let colon = self.block_colon(&tokens, close, line_end);
+        if close >= line_end || colon.is_none() {
+            return (self.syntax_error(&tokens, loc, &format!("KhaladHabQoraal: '{}(...)' kadib waxaa la filayay ':'", name)), line_end);
+        }
+
+        let colon = colon.unwrap();
+        let params = self.get_params(tokens[open + 1..close].to_vec());
+        let block = self.get_block(&tokens, loc, colon);
+        let body = self.parse_block(&tokens, loc, colon, block.0);
+
+        return (ParsedNode::Function {
+            name,
+            params,
+            body,
+            attributes: Attributes::default(),
+            asynchronous: false,
+            closure: vec![]
+        }, block.1);
+    }
+
+    pub fn get_lambda(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let line_end = self.line_end(&tokens, loc);
+        let colon = self.block_colon(&tokens, loc, line_end);
+        if colon.is_none() {
+            return (self.syntax_error(&tokens, loc, "KhaladHabQoraal: 'laamda' kadib waxaa la filayay ':'"), line_end);
+        }
+
+        let colon = colon.unwrap();
+        if self.skip_unnecessary(tokens.clone(), colon + 1) >= line_end {
+            return (self.syntax_error(&tokens, loc, "KhaladHabQoraal: 'laamda' kadib waxaa la filayay tibaax"), line_end);
+        }
+
+        let params = self.get_params(tokens[loc + 1..colon].to_vec());
+        let body = self.next_node(true, Some(tokens[colon + 1..line_end].to_vec()), Some(0)).0;
+
+        return (ParsedNode::Lambda {
+            function: Box::new(ParsedNode::Function {
+                name: "laamda".to_owned(),
+                params,
+                body: vec![body],
+                attributes: Attributes::default(),
+                asynchronous: false,
+                closure: vec![]
+            })
+        }, line_end);
+    }
+
+    pub fn get_assert(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let line_end = self.line_end(&tokens, loc);
+        let statement: Vec<Token> = tokens[loc + 1..line_end].to_vec();
+        let parts = self.split_items(statement, Token::Comma);
+
+        let mut condition: Vec<Vec<ParsedNode>> = vec![];
+        let mut message: Option<Box<ParsedNode>> = None;
+        if !parts.is_empty() {
+            condition = self.get_condition(parts[0].clone());
+        }
+
+        if parts.len() > 1 {
+            message = Some(Box::new(self.next_node(true, Some(parts[1].clone()), Some(0)).0));
+        }
+
+        return (ParsedNode::Assert {
+            condition,
+            message,
+            line_num: self.line_number(&tokens, loc),
+            line: self.source_line(&tokens, loc)
+        }, line_end);
+    }
+
+    pub fn get_match(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let line_end = self.line_end(&tokens, loc);
+        let colon = self.block_colon(&tokens, loc, line_end).unwrap_or(line_end);
+        let subject = self.next_node(true, Some(tokens[loc + 1..colon].to_vec()), Some(0)).0;
+
+        let block = self.get_block(&tokens, loc, colon);
+        let arm_tokens = block.0;
+        let mut arms: Vec<(ParsedNode, Vec<Vec<ParsedNode>>, Vec<ParsedNode>)> = vec![];
+        let mut parser = Parser::new(arm_tokens.clone());
+        parser.line_offset = self.line_number(&tokens, colon + 1) - 1;
+        let mut position = parser.skip_unnecessary(arm_tokens.clone(), 0);
+
+        if position >= arm_tokens.len() {
+            self.parse_block(&tokens, loc, colon, arm_tokens.clone());
+        }
 
-                            let parsed_one = self.get_parsed_comp(comp_one);
-                            let parsed_two = self.get_parsed_comp(comp_two);
-                            let comparison  = ParsedNode::Comparison {
-                                operator: cond_type,
-                                left: Box::new(parsed_one.clone()),
-                                right: Box::new(parsed_two.clone())
-                            };
-
-                            combined.push(comparison);
-                        } else {
-                            for token in and_cond {
-                                match token {
-                                    Token::Word(word) => {
-                                        let word_str = word.to_vec().iter().collect::<String>();
-                                        if &word_str == "Run" {
-                                            combined.push(
-                                                ParsedNode::Bool { val: true }
-                                            );
-                                        } else if &word_str == "Been" {
-                                            combined.push(
-                                                ParsedNode::Bool { val: false }
-                                            );
-                                        } else if &word_str == "Waxba" {
-                                            combined.push(
-                                                ParsedNode::Null
-                                            );
-                                        }
-                                    }
-                                    _ => { }
-                                }
-                            }
-                        }
-                    }
+        while position < arm_tokens.len() {
+            let arm_end = parser.line_end(&arm_tokens, position);
+            let arm_colon = parser.block_colon(&arm_tokens, position, arm_end);
+            if arm_colon.is_none() {
+                break;
+            }
 
-                    if parsed_cond.len() > 0 && parsed_cond[0].len() == 0 {
-                        parsed_cond[0] = combined;
-                    } else {
-                        parsed_cond.push(combined);
-                    }
-                }
+            let arm_colon = arm_colon.unwrap();
+            let header = arm_tokens[position..arm_colon].to_vec();
+            let mut pattern = ParsedNode::Ignore;
+            let mut guard: Vec<Vec<ParsedNode>> = vec![];
 
-                parsed_conditions.push((parsed_cond, Vec::new()));
-            } else {
-                if !normal && scope.is_some() {
-                    let parsed = self.parse_conditions(scope.unwrap());
-                    let last = parsed_conditions.len() - 1;
-                    parsed_conditions[last].1.push(parsed);
-                    continue;
+            if !parser.is_else(&arm_tokens, position) {
+                let parts = parser.split_items(header, Token::Word("hadduu".chars().collect()));
+                if !parts.is_empty() {
+                    pattern = parser.get_pattern(parts[0].clone());
                 }
 
-                let cond = con.unwrap();
-                let mut position = 0;
-
-                while position < cond.0.len() {
-                    let slice = cond.0.as_slice()[position..].to_vec();
-                    let node_pos = self.next_node(true, Some(slice), Some(0));
-                    let node = node_pos.0;
-                    position += node_pos.1;
-                    let last = parsed_conditions.len() - 1;
-                    parsed_conditions[last].1.push(node);
+                if parts.len() > 1 {
+                    guard = parser.get_condition(parts[1].clone());
                 }
             }
+
+            let arm_block = parser.get_block(&arm_tokens, position, arm_colon);
+            let body = parser.parse_block(&arm_tokens, position, arm_colon, arm_block.0);
+            arms.push((pattern, guard, body));
+            position = parser.skip_unnecessary(arm_tokens.clone(), arm_block.1);
         }
 
-        return ParsedNode::IfChain { blocks: parsed_conditions };
+        self.errors.append(&mut parser.errors);
+        return (ParsedNode::Match {
+            subject: Box::new(subject),
+            arms
+        }, block.1);
     }
 
-    pub fn get_or_separated(
+    pub fn get_pattern(
         &mut self,
         tokens: Vec<Token>
-        ) -> Vec<Vec<Vec<Token>>> {
-        let or_split = tokens
-            .split(|&ref v| v == Token::Word(vec!['a', 'm', 'a']));
+    ) -> ParsedNode {
+        let trimmed: Vec<Token> = tokens.into_iter()
+            .filter(|v| !matches!(v, Token::Whitespace(_) | Token::Comment(_)))
+            .collect();
 
-        let mut splits: Vec<Vec<Vec<Token>>> = Vec::new();
-        for or in or_split {
-            let and_split = or
-                .split(|v| v == Token::Word(vec!['i', 'y', 'o']));
+        if trimmed.is_empty() {
+            return ParsedNode::Ignore;
+        }
 
-            let mut normal_and_split: Vec<Vec<Token>> = Vec::new();
-            for and in and_split {
-                normal_and_split.push(and.to_owned());
+        if trimmed.len() == 1 {
+            if let Token::Word(word) = &trimmed[0] {
+                let word_str = word.iter().collect::<String>();
+                if &word_str == "_" {
+                    return ParsedNode::Ignore;
+                } else if Inbuilt::is_type_name(&word_str) {
+                    return ParsedNode::FunctionCall {
+                        name: word_str,
+                        params: vec![]
+                    };
+                } else if !KEYWORDS.contains(&(word_str.as_str())) {
+                    return ParsedNode::Variable {
+                        name: word_str,
+                        exists: false,
+                        add_sub: 0,
+                        value: None
+                    };
+                }
             }
-
-            splits.push(normal_and_split);
         }
 
-        return splits;
-    }
+        let last = trimmed.len() - 1;
+        let is_sequence = (trimmed[0] == Token::OpenBrack || trimmed[0] == Token::OpenParen) && self.closing_bracket(&trimmed, 0) == last;
+        if is_sequence {
+            let mut items: Vec<ParsedNode> = vec![];
+            for item in self.split_items(trimmed[1..last].to_vec(), Token::Comma) {
+                items.push(self.get_pattern(item));
+            }
 
-    pub fn get_parsed_comp(
-        &mut self,
-        comp: Vec<Token>
-        ) -> Vec<ParsedNode> {
-        let mut parsed_comp: Vec<ParsedNode> = Vec::new();
-        let mut iter = comp.iter().enumerate();
+            if trimmed[0] == Token::OpenBrack {
+                return ParsedNode::List { items };
+            }
 
-        while let Some(comp_part) = iter.next() {
-            let index = comp_part.0;
-            let token = comp_part.1;
-            match token {
-                Token::Word(word) => {
-                    let word_str = word.iter().collect::<String>();
-                    if !KEYWORDS.contains(&(word_str.as_str())) {
-                        let mut next = index + 1;
-                        loop {
-                            match comp[next] {
-                                Token::Whitespace(_) | Token::Comment(_) => {
-                                    next += 1;
-                                }
-                                _ => { break; }
-                            }
-                        }
+            return ParsedNode::Tuple { items };
+        }
 
-                        if Self::OPERATIONS.contains(&comp[next]) {
-                            let parsed = self.get_num_or_parse(
-                                true,
-                                Some(comp.clone()),
-                                Some(index)
-                            );
-                            parsed_comp.push(parsed.0);
-                            iter.nth(parsed.1 - index - 1);
-                            continue;
-                        }
+        return self.next_node(true, Some(trimmed), Some(0)).0;
+    }
 
-                        parsed_comp.push(
-                            ParsedNode::Variable {
-                                name: word_str.to_owned(),
-                                exists: true,
-                                add_sub: 0,
-                                value: None
-                            }
-                        );
+    pub fn get_with(
+        &mut self,
+        tokens: Vec<Token>,
+        loc: usize
+    ) -> (ParsedNode, usize) {
+        let line_end = self.line_end(&tokens, loc);
+        let colon = self.block_colon(&tokens, loc, line_end).unwrap_or(line_end);
+        let header: Vec<Token> = tokens.iter()
+            .enumerate()
+            .filter(|(i, _)| i > &loc && i < &colon)
+            .map(|(_, v)| v.clone())
+            .collect();
+
+        let mut managers: Vec<(ParsedNode, Option<String>)> = vec![];
+        for item in self.split_items(header, Token::Comma) {
+            let alias = item.iter().position(|v| v == Token::Word("sida".chars().collect()));
+            let expression: Vec<Token> = item[..alias.unwrap_or(item.len())].to_vec();
+            let mut name: Option<String> = None;
+
+            if let Some(alias) = alias {
+                for token in &item[alias + 1..] {
+                    if let Token::Word(word) = token {
+                        name = Some(word.iter().collect::<String>());
                     }
                 }
-                Token::Int(_) | Token::Float(_) => {
-                    let parsed = self.get_num_or_parse(
-                        true,
-                        Some(comp.clone()),
-                        Some(index)
-                        );
-                    parsed_comp.push(parsed.0);
-                    iter.nth(parsed.1 - index - 1);
-                    continue;
-                }
-                _ => { }
             }
+
+            managers.push((
+                self.next_node(true, Some(expression), Some(0)).0,
+                name
+            ));
         }
 
-        return parsed_comp;
+        let block = self.get_block(&tokens, loc, colon);
+        let body = self.parse_block(&tokens, loc, colon, block.0);
+        return (ParsedNode::With {
+            managers,
+            body
+        }, block.1);
     }
 
-    pub fn next_node(
+    pub fn get_try(
         &mut self,
-        custom: bool,
-        token_list: Option<Vec<Token>>,
-        pos: Option<usize>
+        tokens: Vec<Token>,
+        loc: usize
     ) -> (ParsedNode, usize) {
-        let mut tokens = (&self.tokens).to_owned();
-        let mut position = (&self.position).to_owned();
-
-        if custom && token_list.is_some() && pos.is_some() {
-            tokens = token_list.unwrap();
-            position = pos.unwrap();
-        }
-
-        position = self.skip_unnecessary(tokens.clone(), position);
-        let mut next = position + 1;
+        let indent_level = self.line_indent(&tokens, loc);
+        let line_end = self.line_end(&tokens, loc);
+        let colon = self.block_colon(&tokens, loc, line_end).unwrap_or(line_end);
+        let block = self.get_block(&tokens, loc, colon);
+        let body = self.parse_block(&tokens, loc, colon, block.0);
+        let mut position = block.1;
+
+        let mut handlers: Vec<(Vec<String>, Option<String>, Vec<ParsedNode>)> = vec![];
+        let mut finally: Vec<ParsedNode> = vec![];
         loop {
-            if next >= tokens.len() {
-                next -= 1;
+            let next = self.skip_unnecessary(tokens.clone(), position);
+            if next >= tokens.len() || self.line_indent(&tokens, next) != indent_level {
                 break;
             }
 
-            match &tokens[next] {
-                Token::Whitespace(_) | Token::Comment(_) => {
-                    next += 1;
-                },
-                _ => { break; }
+            let is_handler = self.is_word(&tokens, next, "qabo");
+            let is_finally = self.is_finally(&tokens, next);
+            if !is_handler && !is_finally {
+                break;
             }
-        }
-
-        if position >= tokens.len() {
-            return (ParsedNode::Ignore, position);
-        }
-
-        let mut node = ParsedNode::Ignore;
-        match &tokens[position] {
-            Token::Word(word) => {
-                let word_str = word.to_vec().iter().collect::<String>();
-
-                if &tokens[next] == Token::OpenParen {
-                    let func = self.get_function_call(word.to_vec(), tokens.clone(), position.clone());
-                    node = func.0;
-                    position = func.1;
-                } else if &word_str == "hadduu" {
-                    let parsed = self.get_if_parsed(tokens.clone(), position);
-                    position = parsed.1;
-                    node = parsed.0;
-                } else if &word_str == "Run" { 
-                    let value = if &tokens[next] == Token::Word(vec!['a', 'h', 'e', 'y', 'n']) { false } else { true };
-                    node = ParsedNode::Bool { val: value };
-                    position += 1;
-
-                    if !value {
-                        position = next;
-                    }
-                } else if &word_str == "Been" {
-                    let value = if &tokens[next] == Token::Word(vec!['a', 'h', 'e', 'y', 'n']) { false } else { true };
-                    node = ParsedNode::Bool { val: value };
-                    position += 1;
-
-                    if !value {
-                        position = next;
-                    }
-                } else if &word_str == "Waxba" {
-                    node = ParsedNode::Null;
-                    position += 1;
-                } else if &word_str == "gudub" {
-                    node = ParsedNode::Continue;
-                    position += 1;
-                } else if &word_str == "jooji" {
-                    node = ParsedNode::Break;
-                    position += 1;
-                } else if self.is_assignment(tokens.clone(), position) {
-                    let assigned = self.get_assignment(tokens, position);
-                    position = assigned.1;
-                    node = assigned.0;
-                } else if !KEYWORDS.contains(&(word_str.as_str())) {
-                    if next < tokens.len() {
-                        if &tokens[next] == Token::Word(vec!['k', 'a', 's', 't', 'o', 'o']) {
-                            let parsed_loop = self.get_for_loop(tokens, position);
-                            if !custom {
-                                self.position = parsed_loop.1;
-                            }
 
-                            return parsed_loop;
-                        } else if Self::OPERATIONS.contains(&tokens[next]) {
-                           let parsed = self.get_num_or_parse(custom, Some(tokens), Some(position));
+            let line_end = self.line_end(&tokens, next);
+            let colon = self.block_colon(&tokens, next, line_end).unwrap_or(line_end);
+            let block = self.get_block(&tokens, next, colon);
+            let parsed = self.parse_block(&tokens, next, colon, block.0);
+            position = block.1;
 
-                           if !custom {
-                               self.position = parsed.1;
-                           }
+            if is_finally {
+                finally = parsed;
+                break;
+            }
 
-                           return parsed;
-                        }
+            //qabo [Kind or (Kind, Kind)] [sida name]:
+            let mut kinds: Vec<String> = vec![];
+            let mut name: Option<String> = None;
+            let mut alias = false;
+            for token in &tokens[next + 1..colon] {
+                if let Token::Word(word) = token {
+                    let word = word.iter().collect::<String>();
+                    if word == "sida" {
+                        alias = true;
+                    } else if alias {
+                        name = Some(word);
+                    } else {
+                        kinds.push(word);
                     }
-                    node = ParsedNode::Variable {
-                        name: word_str,
-                        exists: true,
-                        add_sub: 0,
-                        value: None
-                    };
-                    position += 1;
-                } else {
-                    position += 1;
                 }
-            },
-            Token::Int(_) => {
-                node = self.get_num_or_parse(custom, Some(tokens), Some(position)).0;
-            },
-            Token::Float(_) => {
-                node = self.get_num_or_parse(false, None, None).0;
-            },
-            Token::Speech(val) => {
-                position += 1;
-                node = ParsedNode::Str { val: val.into_iter().collect::<String>() }
-            },
-            _ => { position += 1 }
+            }
+
+            handlers.push((kinds, name, parsed));
         }
 
-        if !custom {
-            if self.position < position {
-                self.position = position;
-            }
+        if handlers.is_empty() && finally.is_empty() {
+            let error = ParsedNode::Error {
+                line_num: self.line_number(&tokens, loc),
+                line: self.source_line(&tokens, loc),
+                arrow: "".to_owned(),
+                error: "KhaladHabQoraal: 'tijaabi' kadib waxaa la filayay 'qabo' ama 'ugu-dambeyn'".to_owned()
+            };
+
+            self.errors.push(error);
         }
 
-        return (node, position);
+        return (ParsedNode::Try {
+            body,
+            handlers,
+            finally
+        }, position);
     }
 
-    pub fn get_for_loop(
+    pub fn assign_index(
         &mut self,
-        tokens: Vec<Token>,
-        loc: usize
-    ) -> (ParsedNode, usize) {
-        let mut position = loc;
-        //TODO: Check line indentation, lest the for loop consumes all code
-        //let mut indent_level = 0;
-
-        //if position > 1 {
-            //match (&tokens[position - 1]).to_owned() {
-                //Token::Whitespace(space) => {
-                    //if space[space.len() - 1] != '\n' {
-                        //let pos = space.iter().position(|&n| n == '\n').unwrap();
-                        //indent_level = space.len() - (pos + 1);
-                    //}
-                //},
-                //_ => { }
-            //}
-        //}
-
-        //TODO: Unwrap may error, but this should be handled and treated as a syntax error
-        let colon = tokens.iter().position(|pos| pos == Token::Colon).unwrap();
-        let name_end = tokens.iter().position(|pos| pos == Token::Word("kastoo".chars().collect())).unwrap();
-        let iter_end = tokens.iter().position(|pos| pos == Token::Word("kujira".chars().collect())).unwrap();
-
-
-        let mut name =  "".to_string();
-        for token in tokens.iter().enumerate().filter(|(i, _)| i >= &position && i < &name_end).map(|(_, v)| v) {
+        tokens: &[Token]
+    ) -> Option<usize> {
+        //Only an = outside of brackets assigns, f(fure=x) is a keyword argument
+        let mut depth: usize = 0;
+        for (position, token) in tokens.iter().enumerate() {
             match token {
-                Token::Word(word) => {
-                    name += &word.iter().collect::<String>();
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth += 1;
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Assign if depth == 0 => {
+                    return Some(position);
                 }
                 _ => { }
             }
         }
 
-        let mut iterable: Vec<Token> = vec![];
-        for token in tokens.iter().enumerate().filter(|(i, _)| i > &name_end && i < &iter_end).map(|(_, v)| v) {
-            match token {
-                Token::Whitespace(_) => {
-                    continue;
+        return None;
+    }
+
+    //Where the brackets of x[...] open, when that is what's being assigned to
+    pub fn index_target(
+        &mut self,
+        tokens: &[Token],
+        equal: usize
+    ) -> Option<usize> {
+        let mut end = equal;
+        while end > 0 && matches!(tokens[end - 1], Token::Whitespace(_)) {
+            end -= 1;
+        }
+
+        if end == 0 || tokens[end - 1] != Token::CloseBrack {
+            return None;
+        }
+
+        let mut depth: usize = 0;
+        let mut position = end;
+        while position > 0 {
+            position -= 1;
+            match tokens[position] {
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    depth += 1;
+                }
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return if position > 0 { Some(position) } else { None };
+                    }
                 }
                 _ => { }
             }
-            iterable.push(token.to_owned());
         }
 
-        let parsed_iterable = self.next_node(
-            true,
-            Some(iterable),
-            Some(0)
-        ).0;
-
-        let mut new_tokens: Vec<Token> = vec![];
-        position += colon;
-        for (_, token) in tokens.iter().enumerate().filter(|(i, _)| i > &colon) {
-            new_tokens.push(token.clone());
-        }
+        return None;
+    }
 
-        let mut new_pos = 0;
-        let mut body: Vec<ParsedNode> = vec![];
-        while new_pos < new_tokens.len() {
-            let node = self.next_node(
-                true,
-                Some(new_tokens.clone()),
-                Some(new_pos)
-            );
-            body.push(node.0);
-            new_pos += node.1.clone();
+    //f(...)[...] = value, the one way a line starting with a call assigns
+    pub fn is_index_assignment(
+        &mut self,
+        tokens: &[Token],
+        position: usize
+    ) -> bool {
+        if !self.is_assignment(tokens.to_vec(), position) {
+            return false;
         }
 
-        return (ParsedNode::ForLoop {
-            var: name,
-            iterable: Box::new(parsed_iterable),
-            body: body
-        }, position + new_pos)
+        let line_end = self.line_end(tokens, position);
+        let equal = self.assign_index(&tokens[position..line_end]).unwrap();
+        return self.index_target(&tokens[position..line_end], equal).is_some();
     }
 
     pub fn is_assignment(
@@ -1009,14 +2476,16 @@ impl Parser {
         tokens: Vec<Token>,
         position: usize
     ) -> bool {
-        let sliced_tokens = &tokens[position..tokens.len()];
-        let pos = sliced_tokens.iter().position(|token| token == Token::Assign);
+        let line_end = self.line_end(&tokens, position);
+        let sliced_tokens = &tokens[position..line_end];
+        let pos = self.assign_index(sliced_tokens);
 
-        if pos.is_some() {
-            let loc = pos.unwrap();
+        if let Some(pos) = pos {
+            let loc = pos;
             if loc < sliced_tokens.len() - 1 {
                 let next = &sliced_tokens[loc + 1];
-                if next != Token::Assign {
+                let prev = if loc > 0 { &sliced_tokens[loc - 1] } else { &Token::Ignore };
+                if next != Token::Assign && ![Token::Less, Token::Greater].contains(prev) {
                     return true;
                 }
             }
@@ -1030,45 +2499,85 @@ impl Parser {
         tokens: Vec<Token>,
         position: usize
     ) -> (ParsedNode, usize) {
-        let sliced_tokens = &tokens[position..tokens.len()];
-        let equal = sliced_tokens.iter().position(|pos| pos == Token::Assign);
+        let line_end = self.line_end(&tokens, position);
+        let sliced_tokens = &tokens[position..line_end];
+        let equal = self.assign_index(sliced_tokens);
 
         let mut add_sub = 0;
-        if equal.is_some() {
+        if let Some(equal) = equal {
 
-            match &tokens[&equal.unwrap() - 1] {
-                Token::Plus => {
-                    add_sub = 1;
-                }
-                Token::Minus => {
-                    add_sub = 2;
+            if equal > 0 {
+                match &sliced_tokens[equal - 1] {
+                    Token::Plus => {
+                        add_sub = 1;
+                    }
+                    Token::Minus => {
+                        add_sub = 2;
+                    }
+                    _ => { }
                 }
-                _ => { }
             }
 
-            let name_map = sliced_tokens.iter().enumerate().filter(|(i, _)| i < &equal.unwrap()).map(|(_, v)| v);
+            let name_map = sliced_tokens.iter().enumerate().filter(|(i, _)| *i < equal).map(|(_, v)| v);
 
             let mut name: String = "".to_owned();
             for token in name_map {
-                match token {
-                    Token::Word(word) => {
-                        name = word.iter().collect::<String>();
-                    }
-                    _ => { }
+                if let Token::Word(word) = token {
+                    name = word.iter().collect::<String>();
                 }
             }
 
-            let value_map = sliced_tokens.iter().enumerate().filter(|(i, _)| i > &equal.unwrap()).map(|(_, v)| v);
+            let value_start = self.skip_unnecessary(tokens.clone(), position + equal + 1);
+            if self.is_word(&tokens, value_start, "markuu") {
+                let parsed = self.get_match(tokens.clone(), value_start);
+                return (
+                    ParsedNode::Variable {
+                        name,
+                        exists: false,
+                        add_sub,
+                        value: Some(Box::new(parsed.0))
+                    },
+                    parsed.1
+                );
+            }
+
+            let value_map = sliced_tokens.iter().enumerate().filter(|(i, _)| *i > equal).map(|(_, v)| v);
             let mut value: Vec<Token> = Vec::new();
             for v in value_map {
                 value.push(v.clone());
             }
 
+            //x[...] = value changes one item of x rather than replacing it
+            let open = self.index_target(sliced_tokens, equal);
+            if let (Some(open), 0) = (open, add_sub) {
+                let close = self.closing_bracket(sliced_tokens, open);
+                let object: Vec<Token> = sliced_tokens[..open].to_vec();
+                let object = if object.len() == 1 && name == object[0].text() {
+                    ParsedNode::Variable {
+                        name,
+                        exists: true,
+                        add_sub: 0,
+                        value: None
+                    }
+                } else {
+                    self.next_node(true, Some(object), Some(0)).0
+                };
+
+                return (
+                    ParsedNode::SetIndex {
+                        object: Box::new(object),
+                        parts: self.get_index_parts(sliced_tokens[open + 1..close].to_vec()),
+                        value: Box::new(self.next_node(true, Some(value), Some(0)).0)
+                    },
+                    line_end
+                );
+            }
+
             return (
                 ParsedNode::Variable {
-                    name: name,
+                    name,
                     exists: false,
-                    add_sub: add_sub,
+                    add_sub,
                     value: Some(
                         Box::new(self.next_node(
                             true,
@@ -1077,24 +2586,103 @@ impl Parser {
                         ).0)
                     )
                 },
-                position + sliced_tokens.len()
+                line_end
             );
         }
 
         return (ParsedNode::Ignore, position + 1);
     }
 
+    //A string or bracket left open would take the rest of the code with it, so it's reported before parsing
+    pub fn unclosed(
+        &mut self,
+        tokens: &[Token]
+    ) -> Option<ParsedNode> {
+        let mut open: Vec<usize> = vec![];
+        let mut problem: Option<(usize, String)> = None;
+        for (position, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Speech(val) => {
+                    let quoted = if val.first() == Some(&'b') { &val[1..] } else { &val[..] };
+                    let length = quoted.len();
+                    if length < 2 || quoted[length - 1] != quoted[0] || quoted[length - 2] == '\\' {
+                        problem = Some((position, "KhaladHabQoraal: qoraalka lama xirin".to_owned()));
+                        break;
+                    }
+                }
+                Token::OpenParen | Token::OpenBrack | Token::OpenBrace => {
+                    open.push(position);
+                }
+                Token::CloseParen | Token::CloseBrack | Token::CloseBrace => {
+                    let matching = open.pop().map(|start| match tokens[start] {
+                        Token::OpenParen => Token::CloseParen,
+                        Token::OpenBrack => Token::CloseBrack,
+                        _ => Token::CloseBrace
+                    });
+
+                    if matching.as_ref() != Some(token) {
+                        problem = Some((position, format!("KhaladHabQoraal: '{}' lama filayn", token.text())));
+                        break;
+                    }
+                }
+                _ => { }
+            }
+        }
+
+        if problem.is_none() {
+            problem = open.last().map(|&start| (start, format!("KhaladHabQoraal: '{}' lama xirin", tokens[start].text())));
+        }
+
+        //The bracket's line is shown on its own, since an open bracket carries its statement to the end
+        let (position, error) = problem?;
+        let line_num = self.line_number(tokens, position);
+        let source: String = tokens.iter().map(|token| token.text()).collect();
+        let line = source.lines().nth(line_num - self.line_offset - 1).unwrap_or("").trim().to_owned();
+        return Some(ParsedNode::Error {
+            line_num,
+            line,
+            arrow: "".to_owned(),
+            error
+        });
+    }
+
     pub fn parse(&mut self) -> Vec<ParsedNode> {
         let mut parsed: Vec<ParsedNode> = vec![];
-        let mut prev: usize = 0;
+        let tokens = self.tokens.clone();
+        if let Some(error) = self.unclosed(&tokens) {
+            return vec![error];
+        }
+        let mut prev: Option<usize> = None;
         while self.position < self.tokens.len() {
-            //TODO: Find out what keeps making it loop infinitely  in if statements
-            if self.position > 0 && self.position == prev {
+            //A statement that doesn't move the position on would be parsed forever
+            if prev == Some(self.position) {
                 break;
             }
 
-            prev = self.position;
-            parsed.push(self.next_node(false, None, None).0);
+            prev = Some(self.position);
+
+            //Every statement is preceded by the line it starts on, which the debugger stops at
+            let mut start = self.position;
+            while start < tokens.len() {
+                match tokens[start] {
+                    Token::Whitespace(_) | Token::Comment(_) => { start += 1; }
+                    _ => { break; }
+                }
+            }
+
+            let line_num = self.line_number(&tokens, start);
+            self.statement = start;
+            let node = self.next_node(false, None, None).0;
+            if node != ParsedNode::Ignore {
+                parsed.push(ParsedNode::Line { line_num });
+            }
+
+            parsed.push(node);
+        }
+
+        //A malformed block means none of the code should run
+        if !self.errors.is_empty() {
+            return vec![self.errors[0].clone()];
         }
 
         return parsed;
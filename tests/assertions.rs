@@ -0,0 +1,31 @@
+mod common;
+
+use common::{run, run_with};
+
+#[test]
+fn failed_assertions_raise_with_the_line() {
+    let out = run("x = 1\nxaqiiji x == 2, \"x waa inuu 2 yahay\"\nqor(\"lama gaaro\")\n");
+    assert_eq!(out.stdout, "Sadarka 2:\n    xaqiiji x == 2, \"x waa inuu 2 yahay\"\nKhaladXaqiijin: x waa inuu 2 yahay\n");
+    assert_eq!(out.status, 1);
+}
+
+#[test]
+fn passing_assertions_carry_on() {
+    let out = run("xaqiiji 1 == 1\nqor(\"waa la gaaray\")\n");
+    assert_eq!(out.stdout, "waa la gaaray\n");
+    assert_eq!(out.status, 0);
+}
+
+#[test]
+fn caught_assertions_exit_cleanly() {
+    let out = run("tijaabi:\n    xaqiiji Been\nqabo KhaladXaqiijin:\n    qor(\"la qabtay\")\n");
+    assert_eq!(out.stdout, "la qabtay\n");
+    assert_eq!(out.status, 0);
+}
+
+#[test]
+fn assertions_can_be_turned_off() {
+    let out = run_with("xaqiiji Been\nqor(\"waa la gaaray\")\n", "", &["--aanxaqiijin"]);
+    assert_eq!(out.stdout, "waa la gaaray\n");
+    assert_eq!(out.status, 0);
+}
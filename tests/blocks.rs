@@ -0,0 +1,27 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn dhaaf_does_nothing() {
+    let code = "qayb waxba_ma_qabato():\n    dhaaf\nqor(waxba_ma_qabato())\nx kastoo [1, 2] kujira:\n    dhaaf\nqor(x)\n";
+    assert_eq!(output(code), "Waxba\n2\n");
+}
+
+#[test]
+fn missing_blocks_are_indentation_errors() {
+    let out = run("hadduu Run:\nqor(1)\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.contains("KhaladBeegmid: 'hadduu' kadib waxaa la filayay qayb gudaha ah"));
+    assert!(!out.stdout.starts_with("1\n"));
+}
+
+#[test]
+fn a_missing_colon_is_a_syntax_error() {
+    let out = run("hadduu x\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.ends_with("KhaladHabQoraal: 'hadduu' kadib waxaa la filayay ':'\n"));
+
+    let code = "tijaabi:\n    samee(\"hadduu x\")\nqabo KhaladHabQoraal sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "KhaladHabQoraal: 'hadduu' kadib waxaa la filayay ':'\n");
+}
@@ -0,0 +1,20 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn views_share_the_bytes_of_their_array() {
+    let code = "b = kooxdhibco(b\"abc\")\nm = xasuusaragti(b)\nb[0] = 65\nqor(m[0], m.dhibcokadhig())\nm[1] = 66\nqor(b)\n";
+    assert_eq!(output(code), "65 b'Abc'\nkooxdhibco(b'ABc')\n");
+}
+
+#[test]
+fn copies_of_a_byte_array_are_the_same_array() {
+    assert_eq!(output("b = kooxdhibco(b\"ab\")\nc = b\nc[1] = 66\nqor(b)\nqor(kooxdhibco(b))\n"), "kooxdhibco(b'aB')\nkooxdhibco(b'aB')\n");
+}
+
+#[test]
+fn equal_numbers_are_the_same_key() {
+    let code = "d = {1: \"koow\"}\nqor(d[1.0], d[Run])\nd[1.0] = \"mid\"\nqor(d)\nqor({1, 1.0, Run}, {1: \"a\", 1.0: \"b\"})\n";
+    assert_eq!(output(code), "koow koow\n{1: 'mid'}\n{1} {1: 'b'}\n");
+}
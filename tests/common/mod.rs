@@ -0,0 +1,57 @@
+//Each test file uses only some of these helpers, and explicit returns match the rest of the codebase
+#![allow(dead_code, clippy::needless_return)]
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Output {
+    pub stdout: String,
+    pub status: i32
+}
+
+//A fresh path in the temp directory, so tests running side by side don't share files
+pub fn temp_path(name: &str) -> PathBuf {
+    let count = COUNTER.fetch_add(1, Ordering::SeqCst);
+    return env::temp_dir().join(format!("geel-{}-{}-{}", std::process::id(), count, name));
+}
+
+pub fn run_with(code: &str, input: &str, args: &[&str]) -> Output {
+    let path = temp_path("test.gl");
+    fs::write(&path, code).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_geel"))
+        .args(args)
+        .arg("-k")
+        .arg(&path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    return Output {
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        status: output.status.code().unwrap_or(-1)
+    };
+}
+
+pub fn run(code: &str) -> Output {
+    return run_with(code, "", &[]);
+}
+
+//Runs the code and returns what it printed, checking it finished without an error
+pub fn output(code: &str) -> String {
+    let out = run(code);
+    assert_eq!(out.status, 0, "the program failed:\n{}", out.stdout);
+    return out.stdout;
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn comparisons_are_values() {
+    assert_eq!(output("qor(3 > 2, 2 > 3)\nx = 2 <= 2\nqor(x, 1 != 1)\n"), "Run Been\nRun Been\n");
+}
+
+#[test]
+fn strings_compare_by_their_text() {
+    assert_eq!(output("qor(\"a\" == \"b\", \"a\" == \"a\", \"a\" != \"b\", \"abc\" < \"abd\")\n"), "Been Run Run Run\n");
+}
+
+#[test]
+fn whole_numbers_compare_exactly() {
+    assert_eq!(output("qor(9007199254740993 == 9007199254740992, 1 == 1.0)\n"), "Been Run\n");
+}
+
+#[test]
+fn complex_numbers_compare_for_equality() {
+    let code = "z = 3 + 4j\nqor(z == 3 + 4j, z == z, z != z)\nw = 2 + 0j\nqor(w == 2, z == 5)\nhadduu z == 3 + 4j:\n    qor(\"haa\")\n";
+    assert_eq!(output(code), "Run Run Been\nRun Been\nhaa\n");
+}
+
+#[test]
+fn complex_numbers_have_no_order() {
+    let out = run("z = 1j\nqor(z < z)\n");
+    assert!(out.stdout.ends_with("NoocKhaldan: '<' laguma isticmaali karo 'kakan' iyo 'kakan'\n"), "{}", out.stdout);
+    assert_eq!(out.status, 1);
+}
+
+#[test]
+fn dividing_by_zero_raises() {
+    let code = "tijaabi:\n    qor(1 / 0)\nqabo KhaladEberUQeybin:\n    qor(\"tobanle\")\ntijaabi:\n    qor(1 / 0j)\nqabo KhaladEberUQeybin:\n    qor(\"kakan\")\n";
+    assert_eq!(output(code), "tobanle\nkakan\n");
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn lists_with_filters_and_nested_loops() {
+    let code = "qor([x * 2 x kastoo faraq(1, 5) kujira])\nqor([x x kastoo faraq(0, 10) kujira hadduu x % 3 == 0])\nqor([(a, b) a kastoo [1, 2] kujira b kastoo \"xy\" kujira])\n";
+    assert_eq!(output(code), "[2, 4, 6, 8]\n[0, 3, 6, 9]\n[(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]\n");
+}
+
+#[test]
+fn dicts_and_sets() {
+    let code = "qor({x: x * x x kastoo faraq(0, 3) kujira})\nqor({x % 2 x kastoo faraq(0, 5) kujira})\n";
+    assert_eq!(output(code), "{0: 0, 1: 1, 2: 4}\n{0, 1}\n");
+}
+
+#[test]
+fn generators_are_used_up_once() {
+    assert_eq!(output("g = (x + 1 x kastoo [1, 2, 3] kujira)\nqor(iskudar(g), iskudar(g))\n"), "9 0\n");
+}
+
+#[test]
+fn the_loop_variable_stays_inside() {
+    let out = run("t = [x x kastoo [1] kujira]\nqor(x)\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.ends_with("KhaladMagceed: magaca 'x' lama qeexin\n"));
+}
+
+#[test]
+fn broken_comprehensions_report_their_own_line() {
+    let out = run("x = 1\ny = 2\nt = [x kastoo [1, 2]]\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.contains("Sadarka 3:\n    t = [x kastoo [1, 2]]\nKhaladHabQoraal: waxaa la filayay '[tibaax magac kastoo ... kujira]'"), "{}", out.stdout);
+}
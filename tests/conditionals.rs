@@ -0,0 +1,25 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn conditional_expressions_in_assignments_arguments_and_celi() {
+    let code = "x = 5\ny = \"weyn\" hadduu x > 3 haddii-kale \"yar\"\nqor(y)\nqayb f(n):\n    celi \"tog\" hadduu n % 2 == 0 haddii-kale \"kisi\"\nqor(f(3), f(4))\n";
+    assert_eq!(output(code), "weyn\nkisi tog\n");
+}
+
+#[test]
+fn conditionals_chain_and_bind_looser_than_iyo_and_ama() {
+    let code = "qor(1 hadduu Been haddii-kale 2 hadduu Run haddii-kale 3)\nqor(Run ama Been hadduu Been haddii-kale 7)\nqor(Been iyo Run hadduu Run haddii-kale 7)\n";
+    assert_eq!(output(code), "2\n7\nBeen\n");
+}
+
+#[test]
+fn only_the_chosen_side_runs() {
+    assert_eq!(output("qor(1 hadduu Run haddii-kale 1 / 0)\n"), "1\n");
+}
+
+#[test]
+fn conditionals_inside_comprehensions() {
+    assert_eq!(output("qor([n hadduu n > 1 haddii-kale 0 n kastoo [1, 2] kujira])\n"), "[0, 2]\n");
+}
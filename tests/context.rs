@@ -0,0 +1,41 @@
+mod common;
+
+use common::{output, run, temp_path};
+use std::fs;
+
+const MANAGER: &str = "qayb maamule():\n    celi 0\nqayb gal():\n    qor(\"gal\")\n    celi 5\nqayb kabax(qaab, fariin):\n    qor(\"bax\", qaab)\n    celi qaab == \"KhaladEberUQeybin\"\nsifobadal(maamule, \"gal\", gal)\nsifobadal(maamule, \"bax\", kabax)\n";
+
+#[test]
+fn files_close_when_the_body_fails() {
+    let path = temp_path("context.txt");
+    fs::write(&path, "x").unwrap();
+    let code = format!("tijaabi:\n    isticmaal fur(\"{}\") sida f:\n        1/0\nqabo KhaladXisaabeed:\n    qor(\"qabtay\")\nf.akhri()\n", path.display());
+    let out = run(&code);
+    assert!(out.stdout.starts_with("qabtay\n"));
+    assert_eq!(out.status, 1);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn managers_exit_in_reverse() {
+    let code = format!("{}isticmaal maamule sida a, maamule sida b:\n    qor(a, b)\n", MANAGER);
+    assert_eq!(output(&code), "gal\ngal\n5 5\nbax Waxba\nbax Waxba\n");
+}
+
+#[test]
+fn exit_hooks_see_the_error() {
+    let code = format!("{}tijaabi:\n    isticmaal maamule:\n        tirobuuxin(\"x\")\nqabo NoocKhaldan:\n    qor(\"qabtay\")\n", MANAGER);
+    assert_eq!(output(&code), "gal\nbax NoocKhaldan\nqabtay\n");
+}
+
+#[test]
+fn exit_hooks_can_swallow_errors() {
+    let code = format!("{}isticmaal maamule:\n    1/0\nqor(\"wali\")\n", MANAGER);
+    assert_eq!(output(&code), "gal\nbax KhaladEberUQeybin\nwali\n");
+}
+
+#[test]
+fn returns_pass_through_exit_hooks() {
+    let code = format!("{}qayb f():\n    isticmaal maamule:\n        celi 7\nqor(f())\n", MANAGER);
+    assert_eq!(output(&code), "gal\nbax Waxba\n7\n");
+}
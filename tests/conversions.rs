@@ -0,0 +1,21 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn tirodhan_reads_bases_and_truncates() {
+    let code = "qor(tirodhan(\"ff\", 16), tirodhan(\"0b101\", 0), tirodhan(\" 42 \"), tirodhan(3.9), tirodhan(-3.9))\n";
+    assert_eq!(output(code), "255 5 42 3 -3\n");
+}
+
+#[test]
+fn bad_text_raises_khaladqiimeyn() {
+    let code = "tijaabi:\n    tirodhan(\"geel\")\nqabo KhaladQiimeyn sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "KhaladQiimeyn: tirodhan() qoraal khaldan oo saldhig 10 ah: 'geel'\n");
+}
+
+#[test]
+fn tobanle_bool_and_qoraal_convert() {
+    let code = "qor(tobanle(\"1.5\"), tobanle(2), tobanle(\"inf\"))\nqor(bool(0), bool(\"\"), bool([1]), bool())\nqor(qoraal(1.0), qoraal([1, \"a\"]))\n";
+    assert_eq!(output(code), "1.5 2.0 inf\nBeen Been Run Been\n1.0 [1, 'a']\n");
+}
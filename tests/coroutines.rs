@@ -0,0 +1,67 @@
+mod common;
+
+use common::{output, run};
+
+const WORKER: &str = "mardambe qayb shaqee(magac, muddo):\n    sug hurdo(muddo)\n    qor(magac)\n    celi magac\n";
+
+#[test]
+fn calling_a_mardambe_qayb_runs_nothing_until_awaited() {
+    let code = format!("{}c = shaqee(\"a\", 0)\nqor(nooc(c))\nqor(wareeg(c))\n", WORKER);
+    assert_eq!(output(&code), "<nooc 'mardambe'>\na\na\n");
+}
+
+#[test]
+fn tasks_finish_in_the_order_their_sleeps_end() {
+    let code = format!("{}mardambe qayb ugu_weyn():\n    a = hawl(shaqee(\"a\", 0.05))\n    b = hawl(shaqee(\"b\", 0.01))\n    qor(sug a, sug b)\nwareeg(ugu_weyn())\n", WORKER);
+    assert_eq!(output(&code), "b\na\na b\n");
+}
+
+#[test]
+fn gather_keeps_the_order_it_was_given() {
+    let code = format!("{}mardambe qayb ugu_weyn():\n    celi sug wadajir(shaqee(\"dambe\", 0.02), shaqee(\"hore\", 0.01))\nqor(wareeg(ugu_weyn()))\n", WORKER);
+    assert_eq!(output(&code), "hore\ndambe\n['dambe', 'hore']\n");
+}
+
+#[test]
+fn errors_in_a_task_reach_whoever_awaits_it() {
+    let code = "mardambe qayb fashil():\n    sug hurdo(0)\n    celi 1/0\nmardambe qayb ugu_weyn():\n    tijaabi:\n        sug hawl(fashil())\n    qabo KhaladEberUQeybin:\n        qor(\"qabtay\")\nwareeg(ugu_weyn())\n";
+    assert_eq!(output(code), "qabtay\n");
+}
+
+#[test]
+fn tasks_left_running_are_cancelled() {
+    let code = "mardambe qayb weligeed():\n    tijaabi:\n        sug hurdo(10)\n    qabo HawlLaJoojiyey:\n        qor(\"la joojiyay\")\nmardambe qayb ugu_weyn():\n    hawl(weligeed())\n    sug hurdo(0)\n    qor(\"dhammaad\")\nwareeg(ugu_weyn())\n";
+    assert_eq!(output(code), "dhammaad\nla joojiyay\n");
+}
+
+#[test]
+fn timers_call_functions_later() {
+    let code = "mardambe qayb ugu_weyn():\n    kadib(0.02, qor, \"dambe\")\n    kadib(0.01, qor, \"hore\")\n    sug hurdo(0.03)\nwareeg(ugu_weyn())\n";
+    assert_eq!(output(code), "hore\ndambe\n");
+}
+
+#[test]
+fn async_loops_await_each_item() {
+    let code = "qayb tiriye():\n    celi 0\nsifobadal(tiriye, \"xad\", kamidmid([10, 20]))\nmardambe qayb xiga():\n    sug hurdo(0)\n    celi sug kawad(tiriye.xad)\nsifobadal(tiriye, \"kawad\", xiga)\nmardambe qayb ugu_weyn():\n    mardambe x kastoo tiriye kujira:\n        qor(x)\n    it = kamidmid(\"ab\")\n    qor(sug kawad(it), sug kawad(it), sug kawad(it, \"dhammaad\"))\nwareeg(ugu_weyn())\n";
+    assert_eq!(output(code), "10\n20\na b dhammaad\n");
+}
+
+#[test]
+fn sug_needs_a_running_coroutine() {
+    let out = run("sug hurdo(0)\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.contains("KhaladHabQoraal"));
+}
+
+#[test]
+fn a_task_picks_back_up_where_it_paused() {
+    let code = "x = 1\nqayb f():\n    qor(\"f\")\n    celi 10\nmardambe qayb beddel():\n    caalami x\n    x = 2\nmardambe qayb shaqo():\n    qor(f(), x, sug hurdo(0.01, \"sug\"), x)\n    i kastoo faraq(0, 3) kujira:\n        hadduu i == 1:\n            sug hurdo(0)\n            qor(\"hal\")\n    tijaabi:\n        sug hurdo(0)\n        1/0\n    qabo KhaladEberUQeybin:\n        sug hurdo(0)\n        qor(\"qabtay\")\n    ugu-dambeyn:\n        sug hurdo(0)\n        qor(\"dambeyn\")\n    qor([sug hurdo(0, n * 2) n kastoo [1, 2] kujira])\nmardambe qayb ugu_weyn():\n    a = hawl(shaqo())\n    hawl(beddel())\n    sug a\nwareeg(ugu_weyn())\n";
+    assert_eq!(output(code), "f\n10 1 sug 2\nhal\nqabtay\ndambeyn\n[2, 4]\n");
+}
+
+#[test]
+fn sug_only_works_in_a_mardambe_qayb() {
+    let out = run("qayb caadi():\n    sug hurdo(0)\nmardambe qayb ugu_weyn():\n    caadi()\nwareeg(ugu_weyn())\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.contains("KhaladHabQoraal: sug waxaa lagu isticmaali karaa qayb mardambe ah gudaheed oo keliya"), "{}", out.stdout);
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::run_with;
+
+const PROGRAM: &str = "a = 3\nbartaanbaar()\nb = a * 2\nqor(b)\nc = b + 1\nqor(c)\n";
+
+#[test]
+fn bartaanbaar_stops_and_takes_commands() {
+    let out = run_with(PROGRAM, "q a + 1\nt\nd\nw\n", &[]);
+    assert_eq!(out.status, 0);
+    assert_eq!(out.stdout, "-> 3    b = a * 2\n(bartaanbaar) 4\n(bartaanbaar) -> 4    qor(b)\n(bartaanbaar) a = 3\nb = 6\n(bartaanbaar) 6\n7\n");
+}
+
+#[test]
+fn debug_flag_stops_at_the_start_and_at_breakpoints() {
+    let out = run_with(PROGRAM, "j 5\nw\nw\nq b\nw\n", &["--debug"]);
+    assert_eq!(out.status, 0);
+    assert_eq!(out.stdout, "-> 1    a = 3\n(bartaanbaar) Bartaanbaar waxaa la dhigay sadarka 5\n\
+                            (bartaanbaar) -> 3    b = a * 2\n(bartaanbaar) 6\n\
+                            -> 5    c = b + 1\n(bartaanbaar) 6\n(bartaanbaar) 7\n");
+}
+
+#[test]
+fn quitting_stops_the_program() {
+    let out = run_with(PROGRAM, "ba\n", &[]);
+    assert_eq!(out.status, 1);
+    assert_eq!(out.stdout, "-> 3    b = a * 2\n(bartaanbaar) ");
+}
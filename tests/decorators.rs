@@ -0,0 +1,30 @@
+mod common;
+
+use common::{output, run};
+
+const DECORATORS: &str = "qayb diiwaan(f):\n    qor(\"la diiwaangeliyay\", f.magac)\n    celi f\nqayb laban(f):\n    qayb g(x):\n        celi f(x) * 2\n    celi g\nqayb ku(n):\n    qayb qurxin(f):\n        qayb g(x):\n            celi f(x) * n\n        celi g\n    celi qurxin\n";
+
+#[test]
+fn the_nearest_decorator_is_applied_first() {
+    let code = format!("{}@laban\n@diiwaan\nqayb mid(x):\n    celi x + 1\nqor(mid(5))\n", DECORATORS);
+    assert_eq!(output(&code), "la diiwaangeliyay mid\n12\n");
+}
+
+#[test]
+fn decorators_can_be_made_by_a_call() {
+    let code = format!("{}@ku(3)\n@laban\nqayb mid(x):\n    celi x + 1\nqor(mid(5), mid.magac)\n", DECORATORS);
+    assert_eq!(output(&code), "36 g\n");
+}
+
+#[test]
+fn decorators_wrap_mardambe_qayb() {
+    let code = format!("{}@diiwaan\nmardambe qayb a():\n    celi 1\nqor(nooc(a()))\n", DECORATORS);
+    assert_eq!(output(&code), "la diiwaangeliyay a\n<nooc 'mardambe'>\n");
+}
+
+#[test]
+fn an_unknown_decorator_is_an_error() {
+    let out = run("@waxmajiro\nqayb b():\n    celi 2\n");
+    assert_eq!(out.status, 1);
+    assert!(out.stdout.ends_with("KhaladMagceed: magaca 'waxmajiro' lama qeexin\n"));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn errors_stop_the_program() {
+    let out = run("qor(1)\ndherer(5)\nqor(2)\n");
+    assert_eq!(out.stdout, "1\nSadarka 2:\n    dherer(5)\nNoocKhaldan: shayga 'tirodhan' dherer ma leh\n");
+}
+
+#[test]
+fn errors_in_functions_point_at_their_line() {
+    let out = run("qayb f():\n    celi dherer(5)\nf()\n");
+    assert!(out.stdout.starts_with("Sadarka 2:\n    celi dherer(5)\n"), "{}", out.stdout);
+}
+
+#[test]
+fn qabo_catches_the_kind_it_names() {
+    let code = "tijaabi:\n    tirodhan(\"abc\")\n    qor(\"lama gaaro\")\nqabo NoocKhaldan:\n    qor(\"nooc\")\nqabo KhaladQiimeyn sida e:\n    qor(e)\nqor(\"kadib\")\n";
+    assert_eq!(output(code), "KhaladQiimeyn: tirodhan() qoraal khaldan oo saldhig 10 ah: 'abc'\nkadib\n");
+}
+
+#[test]
+fn qabo_catches_child_kinds() {
+    let code = "tijaabi:\n    [1][5]\nqabo KhaladRaadin:\n    qor(\"raadin\")\ntijaabi:\n    qor(y)\nqabo Khalad:\n    qor(\"khalad\")\ntijaabi:\n    qor(y)\nqabo:\n    qor(\"dhammaan\")\n";
+    assert_eq!(output(code), "raadin\nkhalad\ndhammaan\n");
+}
+
+#[test]
+fn qabo_takes_several_kinds() {
+    let code = "tijaabi:\n    qor(y)\nqabo (NoocKhaldan, KhaladMagceed):\n    qor(\"la qabtay\")\n";
+    assert_eq!(output(code), "la qabtay\n");
+}
+
+#[test]
+fn uncaught_kinds_pass_through() {
+    let out = run("tijaabi:\n    qor(y)\nqabo NoocKhaldan:\n    qor(\"nooc\")\nqor(\"lama gaaro\")\n");
+    assert_eq!(out.stdout, "Sadarka 2:\n    qor(y)\nKhaladMagceed: magaca 'y' lama qeexin\n");
+}
+
+#[test]
+fn ugu_dambeyn_always_runs() {
+    let code = "qayb f():\n    tijaabi:\n        celi 1\n    ugu-dambeyn:\n        qor(\"f\")\nqor(f())\nx kastoo faraq(0, 3) kujira:\n    tijaabi:\n        hadduu x == 1:\n            jooji\n    ugu-dambeyn:\n        qor(x)\n";
+    assert_eq!(output(code), "f\n1\n0\n1\n");
+
+    let out = run("tijaabi:\n    qor(y)\nugu-dambeyn:\n    qor(\"dambeyn\")\nqor(\"lama gaaro\")\n");
+    assert!(out.stdout.starts_with("dambeyn\nSadarka 2:"), "{}", out.stdout);
+}
+
+#[test]
+fn syntax_errors_from_qiimee_are_caught() {
+    let code = "tijaabi:\n    qiimee(\"x = 1\")\nqabo KhaladHabQoraal:\n    qor(\"habqoraal\")\n";
+    assert_eq!(output(code), "habqoraal\n");
+}
+
+#[test]
+fn tijaabi_needs_a_handler() {
+    let out = run("tijaabi:\n    qor(1)\nqor(2)\n");
+    assert!(out.stdout.contains("KhaladHabQoraal: 'tijaabi' kadib waxaa la filayay 'qabo' ama 'ugu-dambeyn'"), "{}", out.stdout);
+}
+
+#[test]
+fn unfinished_equations_raise_instead_of_panicking() {
+    let code = "tijaabi:\n    qiimee(\"1 +\")\nqabo KhaladHabQoraal:\n    qor(\"habqoraal\")\n";
+    assert_eq!(output(code), "habqoraal\n");
+}
+
+#[test]
+fn broken_code_given_to_samee_is_caught() {
+    let code = "kuwan = ['x kastoo [1]:', 'f = laamda x', '\"abc', '(1', '[1, 2', 'qor(', 'qor(1))']\nk kastoo kuwan kujira:\n    tijaabi:\n        samee(k)\n        qor(\"ok\")\n    qabo KhaladHabQoraal sida e:\n        qor(e)\n";
+    assert_eq!(output(code), "KhaladHabQoraal: 'kastoo' kadib waxaa la filayay 'kujira'\nKhaladHabQoraal: 'laamda' kadib waxaa la filayay ':'\nKhaladHabQoraal: qoraalka lama xirin\nKhaladHabQoraal: '(' lama xirin\nKhaladHabQoraal: '[' lama xirin\nKhaladHabQoraal: '(' lama xirin\nKhaladHabQoraal: ')' lama filayn\n");
+}
+
+#[test]
+fn unclosed_brackets_report_their_own_line() {
+    let out = run("qor(1)\nx = [1,\n  2\nqor(x)\n");
+    assert!(out.stdout.contains("Sadarka 2:\n    x = [1,\nKhaladHabQoraal: '[' lama xirin"), "{}", out.stdout);
+}
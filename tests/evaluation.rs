@@ -0,0 +1,20 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn qiimee_sees_the_program_and_given_names() {
+    let code = "x = 4\nqor(qiimee(\"1 + 2 * 3\"), qiimee(\"x * 2\"), qiimee(\"a + 1\", {\"a\": 5}))\n";
+    assert_eq!(output(code), "7 8 6\n");
+}
+
+#[test]
+fn samee_runs_statements() {
+    assert_eq!(output("x = 4\nsamee(\"y = x + 1\")\nqor(y)\n"), "5\n");
+}
+
+#[test]
+fn dhis_builds_reusable_code() {
+    let code = "k = dhis(\"z = 10\", hab=\"samee\")\nqor(k)\nsamee(k)\nq = dhis(\"z + 1\", \"<tijaabo>\", \"qiimee\")\nqor(qiimee(q), qiimee(q))\n";
+    assert_eq!(output(code), "<koodh '<qoraal>'>\n11 11\n");
+}
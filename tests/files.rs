@@ -0,0 +1,37 @@
+mod common;
+
+use common::{output, run, temp_path};
+use std::fs;
+
+#[test]
+fn written_lines_read_back_one_by_one() {
+    let path = temp_path("lines.txt");
+    let code = format!("f = fur(\"{0}\", \"w\")\nf.qor(\"koow\")\nf.xir()\nisticmaal fur(\"{0}\") sida g:\n    x kastoo g kujira:\n        qor(x)\n", path.display());
+    assert_eq!(output(&code), "koow\n");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn text_mode_reads_characters_not_bytes() {
+    let path = temp_path("chars.txt");
+    fs::write(&path, "abé€z").unwrap();
+    let code = format!("f = fur(\"{}\")\nqor(f.akhri(3))\nqor(f.akhri(1))\nf.xir()\n", path.display());
+    assert_eq!(output(&code), "abé\n€\n");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_files_raise() {
+    let path = temp_path("missing.txt");
+    let code = format!("tijaabi:\n    fur(\"{}\")\nqabo KhaladKaydLamaHelin:\n    qor(\"lama helin\")\n", path.display());
+    assert_eq!(output(&code), "lama helin\n");
+}
+
+#[test]
+fn reading_a_closed_file_fails() {
+    let path = temp_path("closed.txt");
+    fs::write(&path, "x").unwrap();
+    let out = run(&format!("f = fur(\"{}\")\nf.xir()\nf.akhri()\n", path.display()));
+    assert_eq!(out.status, 1);
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn laamda_params_stay_together_in_calls() {
+    let code = "qor([v v kastoo kushaqee(laamda x, y: x + y, [1, 2], [3, 4]) kujira])\nf = laamda a, b=2: a * b\nqor(f(3), f(3, b=3))\n";
+    assert_eq!(output(code), "[4, 6]\n6 9\n");
+}
+
+#[test]
+fn assignments_in_a_qayb_stay_local() {
+    let code = "n = 1\nqayb f(a):\n    b = a * 2\n    n = 5\n    celi b + n\nqor(f(1), n)\n";
+    assert_eq!(output(code), "7 1\n");
+
+    let out = run("qayb f():\n    b = 2\nf()\nqor(b)\n");
+    assert!(out.stdout.ends_with("KhaladMagceed: magaca 'b' lama qeexin\n"), "{}", out.stdout);
+}
+
+#[test]
+fn caalami_writes_to_the_globals() {
+    let code = "tiro = 0\nqayb kordhi():\n    caalami tiro\n    tiro += 1\nkordhi()\nkordhi()\nqor(tiro)\n";
+    assert_eq!(output(code), "2\n");
+}
+
+#[test]
+fn recursion_keeps_each_call_separate() {
+    let code = "qayb fib(n):\n    hadduu n < 2:\n        celi n\n    a = fib(n - 1)\n    b = fib(n - 2)\n    celi a + b\nqor(fib(10))\n";
+    assert_eq!(output(code), "55\n");
+}
+
+#[test]
+fn laamda_sees_the_call_it_runs_in() {
+    let code = "qayb h(k):\n    celi [v v kastoo kushaqee(laamda x: x * k, [1, 2]) kujira]\nqor(h(3))\n";
+    assert_eq!(output(code), "[3, 6]\n");
+}
+
+#[test]
+fn kushaqee_stops_at_the_shortest_sequence() {
+    assert_eq!(output("qor([y y kastoo kushaqee(laamda a, b: a + b, [1, 2], [10, 20, 30]) kujira])\n"), "[11, 22]\n");
+}
+
+#[test]
+fn kasooc_keeps_what_passes() {
+    let code = "qor([y y kastoo kasooc(laamda x: x > 1, [0, 1, 2, 3]) kujira])\nqor([y y kastoo kasooc(Waxba, [0, 1, \"\", \"a\"]) kujira])\n";
+    assert_eq!(output(code), "[2, 3]\n[1, 'a']\n");
+}
+
+#[test]
+fn kulli_and_midkasta_handle_empty_sequences() {
+    let code = "qor(kulli([1, 2]), kulli([1, 0]), kulli([]))\nqor(midkasta([0, \"\"]), midkasta([0, 3]), midkasta([]))\n";
+    assert_eq!(output(code), "Run Been Run\nBeen Run Been\n");
+}
+
+#[test]
+fn nested_qayb_keeps_the_enclosing_variables() {
+    let code = "qayb h(k):\n    qayb inner(x):\n        celi x * k\n    celi inner\nf = h(5)\nqor(f(2), h(3)(4))\nqayb sameeye(k):\n    celi laamda x: x + k\nqor(sameeye(10)(1))\n";
+    assert_eq!(output(code), "10 12\n11\n");
+
+    let code = "qayb outer():\n    qayb fact(n):\n        hadduu n < 2:\n            celi 1\n        celi n * fact(n - 1)\n    celi fact(5)\nqor(outer())\n";
+    assert_eq!(output(code), "120\n");
+}
+
+#[test]
+fn calls_can_be_chained() {
+    let code = "qayb f():\n    celi laamda x: x * 2\nqor(f()(2), f()(2) * 3)\nqor([f][0]()(4))\n";
+    assert_eq!(output(code), "4 12\n8\n");
+}
+
+#[test]
+fn unknown_names_in_an_equation_raise() {
+    let code = "tijaabi:\n    qor(y + 1)\nqabo KhaladMagceed sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "KhaladMagceed: magaca 'y' lama qeexin\n");
+}
+
+#[test]
+fn unfinished_qayb_and_laamda_are_syntax_errors() {
+    let out = run("qayb\n");
+    assert!(out.stdout.ends_with("KhaladHabQoraal: 'qayb' kadib waxaa la filayay magac\n"), "{}", out.stdout);
+    assert_eq!(out.status, 1);
+
+    let out = run("x = 1\nf = laamda x\n");
+    assert_eq!(out.stdout, "Sadarka 2:\n    f = laamda x\nKhaladHabQoraal: 'laamda' kadib waxaa la filayay ':'\n");
+    assert_eq!(out.status, 1);
+
+    let out = run("laamda\n");
+    assert!(out.stdout.ends_with("KhaladHabQoraal: 'laamda' kadib waxaa la filayay ':'\n"), "{}", out.stdout);
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn builtins_keywords_and_exceptions_have_docs() {
+    let code = "caawimaad(dherer)\ncaawimaad(\"kastoo\")\ncaawimaad(\"KhaladMaJiro\")\n";
+    let expected = "Caawimaad ku saabsan dherer:\n\ndherer(shay)\n    Wuxuu celiyaa tirada shayada ku jira.\n\
+                    Caawimaad ku saabsan kastoo:\n\nkastoo\n    x kastoo taxane kujira: wuxuu qaybta u fuliyaa shay kasta.\n\
+                    Caawimaad ku saabsan KhaladMaJiro:\n\nKhaladMaJiro(KhaladMagceed)\n    Doorsoome gudaha ah ayaa la isticmaalay ka hor inta aan qiime la siin.\n";
+    assert_eq!(output(code), expected);
+}
+
+#[test]
+fn qayb_docstrings_are_captured() {
+    let code = "qayb salaan(magac):\n    \"Salaan qof.\"\n    celi magac\nqayb f():\n    celi 1\ncaawimaad(salaan)\ncaawimaad(f)\n";
+    let expected = "Caawimaad ku saabsan salaan:\n\nsalaan(magac)\n    Salaan qof.\nCaawimaad ku saabsan f:\n\nf()\n    Qaybtan caawimaad ma leh.\n";
+    assert_eq!(output(code), expected);
+}
+
+#[test]
+fn unknown_names_say_so() {
+    assert_eq!(output("caawimaad(\"waxmajiro\")\n"), "Lama helin caawimaad ku saabsan 'waxmajiro'.\n");
+}
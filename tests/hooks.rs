@@ -0,0 +1,44 @@
+mod common;
+
+use common::{output, run};
+
+const POINT: &str = "qayb dhibic(x, y):\n    qayb p():\n        celi Waxba\n    sifobadal(p, \"x\", x)\n    sifobadal(p, \"y\", y)\n    qayb isugeyn(tan):\n        celi dhibic(x + tan.x, y + tan.y)\n    qayb lamid(tan):\n        celi x == tan.x iyo y == tan.y\n    qayb kayar(tan):\n        celi x + y < tan.x + tan.y\n    qayb hel(i):\n        celi [x, y][i]\n    qayb tirada():\n        celi 2\n    qayb walba():\n        celi [x, y]\n    qayb runbaa():\n        celi x != 0 ama y != 0\n    qayb muuq():\n        celi \"dhibic\"\n    sifobadal(p, \"isugeyn\", isugeyn)\n    sifobadal(p, \"lamid\", lamid)\n    sifobadal(p, \"kayar\", kayar)\n    sifobadal(p, \"hel\", hel)\n    sifobadal(p, \"dherer\", tirada)\n    sifobadal(p, \"celceli\", walba)\n    sifobadal(p, \"bool\", runbaa)\n    sifobadal(p, \"qoraal\", muuq)\n    celi p\na = dhibic(1, 2)\nb = dhibic(3, 4)\n";
+
+#[test]
+fn addition_asks_the_left_value() {
+    let code = format!("{}c = a + b + a\nqor(c.x, c.y)\nd = a + (b + a)\nqor(d.x * 2 + 1, d.y)\n", POINT);
+    assert_eq!(output(&code), "5 8\n11 8\n");
+}
+
+#[test]
+fn comparisons_ask_either_side() {
+    let code = format!("{}qor(a == dhibic(1, 2), a != b, dhibic(3, 4) == b)\nqor(a < b, a > b, b > a, a <= dhibic(1, 2), b >= a)\n", POINT);
+    assert_eq!(output(&code), "Run Run Run\nRun Been Run Run Run\n");
+}
+
+#[test]
+fn indexing_length_and_loops_use_hooks() {
+    let code = format!("{}qor(a[0], a[1], dherer(a))\nm kastoo b kujira:\n    qor(m)\nqor([k * 2 k kastoo b kujira], soocan(b))\n", POINT);
+    assert_eq!(output(&code), "1 2 2\n3\n4\n[6, 8] [3, 4]\n");
+}
+
+#[test]
+fn truth_and_text_use_hooks() {
+    let code = format!("{}qor(bool(a), bool(dhibic(0, 0)), kulli([a, b]))\nqor(a, qoraal(b), [qoraal(a)])\n", POINT);
+    assert_eq!(output(&code), "Run Been Run\ndhibic dhibic ['dhibic']\n");
+}
+
+#[test]
+fn numbers_cannot_be_added_to_hooked_values() {
+    let out = run(&format!("{}c = 1 + a\n", POINT));
+    assert!(out.stdout.contains("NoocKhaldan: '+' laguma isticmaali karo 'qayb'"), "{}", out.stdout);
+    assert_eq!(out.status, 1);
+}
+
+#[test]
+fn hooks_must_answer_with_the_right_type() {
+    let code = format!("{}sifobadal(a, \"dherer\", laamda: \"laba\")\ndherer(a)\n", POINT);
+    let out = run(&code);
+    assert!(out.stdout.contains("NoocKhaldan: dherer waa inuu celiyaa tirodhan, ma aha 'qoraal'"), "{}", out.stdout);
+    assert_eq!(out.status, 1);
+}
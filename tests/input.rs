@@ -0,0 +1,17 @@
+mod common;
+
+use common::run_with;
+
+#[test]
+fn weydii_prints_the_prompt_and_reads_a_line() {
+    let out = run_with("x = weydii(\"Magac? \")\nqor(\"Salaan\", x)\nqor(weydii())\n", "Cali\nGeel\n", &[]);
+    assert_eq!(out.status, 0);
+    assert_eq!(out.stdout, "Magac? Salaan Cali\nGeel\n");
+}
+
+#[test]
+fn running_out_of_input_raises() {
+    let out = run_with("tijaabi:\n    weydii()\nqabo KhaladQoraalDhamaa:\n    qor(\"dhammaad\")\n", "", &[]);
+    assert_eq!(out.status, 0);
+    assert_eq!(out.stdout, "\ndhammaad\n");
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn each_function_has_its_own_attributes() {
+    let code = "f = laamda x: x\ng = laamda x: x\nsifobadal(f, \"calaamad\", 1)\nqor(sifomaleeyahay(f, \"calaamad\"), sifomaleeyahay(g, \"calaamad\"))\nh = f\nqor(h.calaamad)\n";
+    assert_eq!(output(code), "Run Been\n1\n");
+}
+
+#[test]
+fn every_call_makes_a_new_laamda() {
+    let code = "qayb samee():\n    celi laamda: 1\na = samee()\nb = samee()\nsifobadal(a, \"x\", 2)\nqor(sifomaleeyahay(b, \"x\"))\nqor(sifosheeg(a))\n";
+    assert_eq!(output(code), "Been\n['magac', 'x']\n");
+}
+
+#[test]
+fn ids_follow_the_function_not_its_text() {
+    let ids = output("f = laamda x: x\ng = laamda x: x\nh = f\nqor(lambarlagugarto(f))\nqor(lambarlagugarto(h))\nqor(lambarlagugarto(g))\n");
+    let ids: Vec<&str> = ids.lines().collect();
+    assert_eq!(ids[0], ids[1]);
+    assert_ne!(ids[0], ids[2]);
+}
+
+#[test]
+fn numbers_list_their_attributes() {
+    assert_eq!(output("qor(sifosheeg(5))\nx = 5\nqor(x.dhab, x.male)\n"), "['dhab', 'lammaane', 'male']\n5 0\n");
+}
+
+#[test]
+fn the_type_of_a_function_cant_be_called() {
+    let out = run("qayb f():\n    celi 1\nt = nooc(f)\nqor(t)\nt()\n");
+    assert_eq!(out.stdout, "<nooc 'qayb'>\nSadarka 5:\n    t()\nNoocKhaldan: shay cusub oo nooca 'qayb' ah lama samayn karo\n");
+    assert_eq!(out.status, 1);
+}
+
+#[test]
+fn types_compare_by_name_and_convert_when_called() {
+    let code = "qor(nooc(1) == nooc(2), nooc(1) == nooc(\"a\"), nooc(1) == tirodhan)\nqor(nooc(Waxba), nooc(nooc(1)), tirodhan)\nqor(nooc(1)(\"5\") + 1, nooc(nooc(1))(\"x\"))\n";
+    assert_eq!(output(code), "Run Been Run\n<nooc 'Waxba'> <nooc 'nooc'> <nooc 'tirodhan'>\n6 <nooc 'qoraal'>\n");
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{output, run};
+
+#[test]
+fn loops_over_strings_tuples_and_dicts() {
+    let code = "x kastoo \"ab\" kujira:\n    qor(x)\nx kastoo (1, 2) kujira:\n    qor(x)\nx kastoo {\"a\": 1} kujira:\n    qor(x)\n";
+    assert_eq!(output(code), "a\nb\n1\n2\na\n");
+}
+
+#[test]
+fn loops_over_generators() {
+    let code = "g = (y * 2 y kastoo faraq(0, 3) kujira)\nx kastoo g kujira:\n    qor(x)\n";
+    assert_eq!(output(code), "0\n2\n4\n");
+}
+
+#[test]
+fn loops_unpack_pairs() {
+    let code = "k, v kastoo [(\"a\", 1), (\"b\", 2)] kujira:\n    qor(k, v)\n";
+    assert_eq!(output(code), "a 1\nb 2\n");
+}
+
+#[test]
+fn non_iterables_raise() {
+    let out = run("x kastoo 5 kujira:\n    qor(x)\nqor(\"lama gaaro\")\n");
+    assert_eq!(out.stdout, "Sadarka 1:\n    x kastoo 5 kujira:\nNoocKhaldan: shayga 'tirodhan' lama celcelin karo\n");
+
+    let out = run("[x x kastoo Waxba kujira]\nqor(\"lama gaaro\")\n");
+    assert!(out.stdout.ends_with("NoocKhaldan: shayga 'Waxba' lama celcelin karo\n"), "{}", out.stdout);
+
+    let out = run("k, v kastoo [1] kujira:\n    qor(k)\nqor(\"lama gaaro\")\n");
+    assert!(out.stdout.ends_with("NoocKhaldan: shayga 'tirodhan' lama kala furfuri karo\n"), "{}", out.stdout);
+}
+
+#[test]
+fn iterators_run_out_after_one_pass() {
+    let code = "r = rogan([1, 2, 3])\nx kastoo r kujira:\n    qor(x)\nx kastoo r kujira:\n    qor(\"mar labaad\", x)\n";
+    assert_eq!(output(code), "3\n2\n1\n");
+}
+
+#[test]
+fn tiri_and_iskuxer_pull_from_their_sources_lazily() {
+    let code = "t = tiri(rogan([\"a\", \"b\"]), 1)\nqor([y y kastoo t kujira])\nqor([y y kastoo t kujira])\ng = (x * 2 x kastoo [1, 2, 3] kujira)\nqor([y y kastoo iskuxer(g, g) kujira])\n";
+    assert_eq!(output(code), "[(1, 'b'), (2, 'a')]\n[]\n[(2, 4)]\n");
+}
+
+#[test]
+fn iterators_have_no_length() {
+    let out = run("qor(dherer(tiri([1])))\n");
+    assert!(out.stdout.ends_with("NoocKhaldan: shayga 'tiri' dherer ma leh\n"), "{}", out.stdout);
+    assert_eq!(out.status, 1);
+}
+
+#[test]
+fn tiri_start_too_big_raises() {
+    let code = "tijaabi:\n    tiri([1], 99999999999999999999999)\nqabo KhaladWeynaan sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "KhaladWeynaan: tiri() bilow aad buu u weyn yahay\n");
+}
@@ -0,0 +1,20 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn arms_are_tried_in_order() {
+    let code = "qayb kala_saar(x):\n    markuu x:\n        0:\n            celi \"Eber\"\n        faraq(1, 10):\n            celi \"Yar\"\n        [a, b]:\n            celi b\n        n hadduu n > 100:\n            celi \"Weyn\"\n        haddii-kale:\n            celi \"Kale\"\nqor(kala_saar(0), kala_saar(5), kala_saar([1, 2]), kala_saar(500), kala_saar(50))\n";
+    assert_eq!(output(code), "Eber Yar 2 Weyn Kale\n");
+}
+
+#[test]
+fn markuu_gives_the_value_of_its_arm() {
+    let code = "y = markuu \"geel\":\n    tirodhan: \"Tirodhan\"\n    _: \"Wax kale\"\nz = markuu 3:\n    tirodhan: \"Tirodhan\"\n    _: \"Wax kale\"\nqor(y, z)\n";
+    assert_eq!(output(code), "Wax kale Tirodhan\n");
+}
+
+#[test]
+fn nothing_runs_without_a_matching_arm() {
+    assert_eq!(output("markuu 7:\n    1:\n        qor(\"hal\")\nqor(\"dhammaad\")\n"), "dhammaad\n");
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn caalamiyaasha_writes_through() {
+    let code = "caalamiyaasha()[\"z\"] = 7\nqor(z)\ng = caalamiyaasha()\ng[\"w\"] = 1\nqor(w, g[\"z\"])\n";
+    assert_eq!(output(code), "7\n1 7\n");
+}
+
+#[test]
+fn caalamiyaasha_sees_later_globals() {
+    let code = "g = caalamiyaasha()\nx = 3\nqor(g[\"x\"], dherer(g))\nqor(g)\n";
+    assert_eq!(output(code), "3 2\n{'g': {...}, 'x': 3}\n");
+}
+
+#[test]
+fn caalamiyaasha_reaches_the_globals_from_a_call() {
+    let code = "qayb f(a):\n    caalamiyaasha()[\"c\"] = a\nf(5)\nqor(c)\n";
+    assert_eq!(output(code), "5\n");
+}
+
+#[test]
+fn xeradaan_shows_the_locals_of_a_call() {
+    let code = "n = 1\nqayb f(a):\n    b = 2\n    qor(xeradaan())\nf(5)\n";
+    assert_eq!(output(code), "{'a': 5, 'b': 2}\n");
+}
+
+#[test]
+fn samee_runs_in_a_given_caalamiyaasha() {
+    let code = "samee(\"m = 4\", caalamiyaasha())\nqor(m)\nd = {}\nsamee(\"k = 1\", d)\nqor(d)\n";
+    assert_eq!(output(code), "4\n{}\n");
+}
+
+#[test]
+fn nested_items_are_stored_back() {
+    let code = "d = {\"a\": {\"b\": 1}}\nd[\"a\"][\"b\"] = 3\nqor(d)\nl = [[1, 2], [3]]\nl[0][1] = 9\nqor(l)\n";
+    assert_eq!(output(code), "{'a': {'b': 3}}\n[[1, 9], [3]]\n");
+}
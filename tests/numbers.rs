@@ -0,0 +1,58 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn floats_can_leave_out_the_leading_zero() {
+    assert_eq!(output("qor(.5)\nqor(.25 * 4)\n"), "0.5\n1.0\n");
+}
+
+#[test]
+fn the_result_type_follows_the_operands() {
+    assert_eq!(output("x = 2.0\nqor(1 + 2, 4 / 2, 7 / 2, x * 2, 2 ** -1, 10 % 3)\n"), "3 2.0 3.5 4.0 0.5 1\n");
+}
+
+#[test]
+fn double_star_is_a_power() {
+    assert_eq!(output("qor(2 ** 3)\nqor(2 ^ 3)\n"), "8\n8\n");
+}
+
+#[test]
+fn whole_numbers_past_two_to_the_53_stay_exact() {
+    let code = "qor(iskudar([9007199254740993, 0]))\nqor(qiimahasugan(-9007199254740993))\nqor(ugubadnaan(9007199254740993, 9007199254740992))\nqor(dhufocelcelis(3, 40))\n";
+    assert_eq!(output(code), "9007199254740993\n9007199254740993\n9007199254740993\n12157665459056928801\n");
+}
+
+#[test]
+fn floats_in_a_sum_make_a_float() {
+    assert_eq!(output("qor(iskudar([1, 2.5]))\nqor(iskudar([1, 2], 0.5))\n"), "3.5\n3.5\n");
+}
+
+#[test]
+fn extremes_need_an_argument() {
+    let code = "tijaabi:\n    ugubadnaan()\nqabo NoocKhaldan sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "NoocKhaldan: ugubadnaan() ugu yaraan 1 shay buu qaataa, laakiin 0 shay baa la siiyay\n");
+}
+
+#[test]
+fn rounding_takes_any_number_of_places() {
+    assert_eq!(output("qor(tirobuuxin(1.5, 99999999999), tirobuuxin(1234.5, -2), tirobuuxin(2.5, -99999999999))\n"), "1.5 1200.0 0.0\n");
+}
+
+#[test]
+fn unknown_keywords_are_rejected() {
+    let code = "tijaabi:\n    ugubadnaan([1, -5], key=qiimahasugan)\nqabo NoocKhaldan sida e:\n    qor(e)\ntijaabi:\n    soocan([3, 1, 2], reverse=Run)\nqabo NoocKhaldan sida e:\n    qor(e)\n";
+    assert_eq!(output(code), "NoocKhaldan: ugubadnaan() ma yaqaan shayga 'key'\nNoocKhaldan: soocan() ma yaqaan shayga 'reverse'\n");
+}
+
+#[test]
+fn complex_numbers_mix_with_ints_and_floats() {
+    let code = "z = 3 + 4j\nqor(z, qiimahasugan(z), z.dhab, z.male, z.lammaane())\nqor(z * 2, z + 1.5, (1 + 2j) * (3 - 1j), kakan(1, 2), kakan(\"1+2j\"))\n";
+    assert_eq!(output(code), "(3+4j) 5.0 3.0 4.0 (3-4j)\n(6+8j) (4.5+4j) (5+5j) (1+2j) (1+2j)\n");
+}
+
+#[test]
+fn attributes_can_be_part_of_a_sum() {
+    let code = "z = 3 + 4j\nw = z.male * 2 + 1\nqor(w, 1 + z.dhab, z.male - z.dhab)\n";
+    assert_eq!(output(code), "9.0 4.0 1.0\n");
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn escapes_in_string_literals_are_decoded() {
+    assert_eq!(output("qor(\"a\\tb\\nc\")\nqor(dherer(\"a\\nb\"))\nqor(\"say \\\"haa\\\"\")\n"), "a\tb\nc\n3\nsay \"haa\"\n");
+}
+
+#[test]
+fn qor_takes_a_separator_and_an_end() {
+    assert_eq!(output("qor(1, 2, 3, kala=\", \", dhammaad=\".\\n\")\nqor(\"x\", \"y\", kala=\"\")\n"), "1, 2, 3.\nxy\n");
+}
+
+#[test]
+fn muuqaal_quotes_strings_and_qor_does_not() {
+    let code = "qor(muuqaal(\"geel\"), muuqaal(1.0), muuqaal([1, \"a\", Waxba]))\nqor(\"geel\", [\"geel\"], {\"a\": (1,)})\n";
+    assert_eq!(output(code), "'geel' 1.0 [1, 'a', Waxba]\ngeel ['geel'] {'a': (1,)}\n");
+}
+
+#[test]
+fn quotes_are_picked_around_what_the_string_holds() {
+    assert_eq!(output("qor(muuqaal(\"it's\"), qoraalkadhig(\"café\"))\n"), "\"it's\" 'caf\\xe9'\n");
+}